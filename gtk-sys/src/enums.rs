@@ -1225,6 +1225,34 @@ pub enum TreeModelFlags {
     ListOnly,
 }
 
+/// An enum for determining where a dropped row goes.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum TreeViewDropPosition {
+    /// dropped row is inserted before
+    Before,
+    /// dropped row is inserted after
+    After,
+    /// dropped row becomes a child or is inserted before
+    IntoOrBefore,
+    /// dropped row becomes a child or is inserted after
+    IntoOrAfter
+}
+
+/// The TargetFlags enumeration is used to specify constraints on a target entry.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum TargetFlags {
+    /// If this is set, the target will only be selected for drags within a single application.
+    SameApp     = 1 << 0,
+    /// If this is set, the target will only be selected for drags within a single widget.
+    SameWidget  = 1 << 1,
+    /// If this is set, the target will not be selected for drags within a single application.
+    OtherApp    = 1 << 2,
+    /// If this is set, the target will not be selected for drags withing a single widget.
+    OtherWidget = 1 << 3
+}
+
 /// An enum for determining where a dropped item goes.
 #[repr(C)]
 #[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
@@ -1396,3 +1424,38 @@ pub enum SizeGroupMode {
     /// group affects both horizontal and vertical requisition
     Both
 }
+
+/// Flags used to define the behaviour of a GApplication.
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum ApplicationFlags {
+    /// Default
+    FlagsNone = 0,
+    /// Run as a service. In this mode, registration fails if the service is already running, and the application will initially
+    /// wait up to 10 seconds for an initial activation message to arrive.
+    IsService = 1 << 0,
+    /// Don't try to become the primary instance.
+    IsLauncher = 1 << 1,
+    /// This application handles opening files (in the primary instance).
+    HandlesOpen = 1 << 2,
+    /// This application handles command line arguments (in the primary instance).
+    HandlesCommandLine = 1 << 3,
+    /// Send the environment of the launching process to the primary instance.
+    SendEnvironment = 1 << 4,
+    /// Make no attempts to do any of the typical single-instance application negotiation, as if the application had no id.
+    NonUnique = 1 << 5
+}
+
+/// Types of user actions that may be blocked by gtk_application_inhibit().
+#[repr(C)]
+#[derive(Clone, PartialEq, PartialOrd, Debug, Copy)]
+pub enum ApplicationInhibitFlags {
+    /// Inhibit ending the user session by logging out or by shutting down the computer
+    Logout = 1 << 0,
+    /// Inhibit user switching
+    Switch = 1 << 1,
+    /// Inhibit suspending the session or computer
+    Suspend = 1 << 2,
+    /// Inhibit the session being marked as idle (and possibly locked)
+    Idle = 1 << 3
+}
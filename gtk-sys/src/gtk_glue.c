@@ -13,6 +13,42 @@ void glue_signal_connect(void *g_object, char *signal, void (*func)(void*, void*
     g_signal_connect(G_OBJECT(g_object), signal, G_CALLBACK(func), user_param);
 }
 
+GType glue_container_child_property_type(GtkContainer* container, const gchar* property_name) {
+    GParamSpec* pspec = gtk_container_class_find_child_property(G_OBJECT_GET_CLASS(container), property_name);
+
+    return pspec == NULL ? G_TYPE_INVALID : G_PARAM_SPEC_VALUE_TYPE(pspec);
+}
+
+GType glue_object_type(GObject* object) {
+    return G_OBJECT_TYPE(object);
+}
+
+GType glue_object_property_type(GObject* object, const gchar* property_name) {
+    GParamSpec* pspec = g_object_class_find_property(G_OBJECT_GET_CLASS(object), property_name);
+
+    return pspec == NULL ? G_TYPE_INVALID : G_PARAM_SPEC_VALUE_TYPE(pspec);
+}
+
+static void glue_menu_position_below_widget(GtkMenu* menu, gint* x, gint* y, gboolean* push_in, gpointer user_data) {
+    GtkWidget* widget = GTK_WIDGET(user_data);
+    GtkAllocation allocation;
+
+    gtk_widget_get_allocation(widget, &allocation);
+    gdk_window_get_origin(gtk_widget_get_window(widget), x, y);
+    if (!gtk_widget_get_has_window(widget)) {
+        *x += allocation.x;
+        *y += allocation.y;
+    }
+    *y += allocation.height;
+    *push_in = TRUE;
+}
+
+/* Pops menu up below widget, which is kept alive while the menu is shown */
+void glue_menu_popup_at_widget(GtkMenu* menu, GtkWidget* widget, guint button, guint32 activate_time) {
+    gtk_menu_popup_for_device(menu, NULL, NULL, NULL, glue_menu_position_below_widget, g_object_ref(widget),
+                              g_object_unref, button, activate_time);
+}
+
 GType get_gtype(int x) {
     printf("value : %d\n", x);
     return G_TYPE_MAKE_FUNDAMENTAL(x);
@@ -46,6 +82,10 @@ GtkWindow* cast_GtkWindow(GtkWidget* widget) {
     return GTK_WINDOW(widget);
 }
 
+gboolean is_GtkContainer(GtkWidget* widget) {
+    return GTK_IS_CONTAINER(widget);
+}
+
 GtkContainer* cast_GtkContainer(GtkWidget* widget) {
     return GTK_CONTAINER(widget);
 }
@@ -163,6 +203,14 @@ GtkMenuItem* cast_GtkMenuItem(GtkWidget* widget) {
     return GTK_MENU_ITEM(widget);
 }
 
+GtkRadioMenuItem* cast_GtkRadioMenuItem(GtkWidget* widget) {
+    return GTK_RADIO_MENU_ITEM(widget);
+}
+
+GtkImageMenuItem* cast_GtkImageMenuItem(GtkWidget* widget) {
+    return GTK_IMAGE_MENU_ITEM(widget);
+}
+
 GtkToolbar* cast_GtkToolbar(GtkWidget* widget) {
     return GTK_TOOLBAR(widget);
 }
@@ -218,6 +266,10 @@ GtkTreeSortable* cast_GtkTreeSortable(GtkTreeModel* tree_model) {
     return GTK_TREE_SORTABLE(tree_model);
 }
 
+gboolean is_GtkTreeSortable(GtkTreeModel* tree_model) {
+    return GTK_IS_TREE_SORTABLE(tree_model);
+}
+
 GtkEditable* cast_GtkEditable(GtkWidget* widget) {
     return GTK_EDITABLE(widget);
 }
@@ -250,6 +302,10 @@ GtkListStore* cast_GtkListStore(GObject* obj) {
     return GTK_LIST_STORE(obj);
 }
 
+gboolean is_GtkListStore(GtkTreeModel* tree_model) {
+    return GTK_IS_LIST_STORE(tree_model);
+}
+
 GtkTreeModel* cast_GtkTreeModelFromTreeStore(GtkTreeStore* tree_store) {
     return GTK_TREE_MODEL(tree_store);
 }
@@ -262,6 +318,10 @@ GtkTreeStore* cast_GtkTreeStore(GObject *object) {
     return GTK_TREE_STORE(object);
 }
 
+gboolean is_GtkTreeStore(GtkTreeModel* tree_model) {
+    return GTK_IS_TREE_STORE(tree_model);
+}
+
 GtkTreeModel* cast_GtkTreeModel(GObject *object) {
     return GTK_TREE_MODEL(object);
 }
@@ -588,6 +648,10 @@ GtkEventBox* cast_GtkEventBox(GtkWidget* widget) {
     return GTK_EVENT_BOX(widget);
 }
 
+GtkApplicationWindow* cast_GtkApplicationWindow(GtkWidget* widget) {
+    return GTK_APPLICATION_WINDOW(widget);
+}
+
 /* MAC OS dylib
 gcc -I/usr/local/include/gtk-3.0 -I/usr/local/include/glib-2.0 -I/usr/local/include/gobject-introspection-1.0 -I/usr/local/Cellar/glib/2.38.1/lib/glib-2.0/include/ -I/usr/local/Cellar/pango/1.36.0/include/pango-1.0/ -I/usr/local/Cellar/cairo/1.12.16/include/cairo/ -I/usr/local/Cellar/gdk-pixbuf/2.30.0/include/gdk-pixbuf-2.0/ -I/usr/local/Cellar/atk/2.10.0/include/atk-1.0/ -lglib-2.0 -lgtk-3.0 -lgobject-2.0 -dynamiclib -o libgtk_glue.dylib -dy gtk_glue.c
 */
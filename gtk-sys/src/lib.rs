@@ -211,6 +211,8 @@ pub struct C_GtkTreeStore;
 #[repr(C)]
 pub struct C_GtkTreePath;
 #[repr(C)]
+pub struct C_GtkTreeRowReference;
+#[repr(C)]
 pub struct C_GtkTreeIter {
     pub stamp: c_int,
     pub user_data: *mut c_void,
@@ -218,6 +220,16 @@ pub struct C_GtkTreeIter {
     pub user_data3: *mut c_void
 }
 #[repr(C)]
+pub struct C_GtkTargetEntry {
+    pub target: *mut c_char,
+    pub flags: c_uint,
+    pub info: c_uint
+}
+#[repr(C)]
+pub struct C_GtkSelectionData;
+#[repr(C)]
+pub struct C_GtkClipboard;
+#[repr(C)]
 pub struct C_GtkCellEditable;
 #[repr(C)]
 pub struct C_GtkCellRendererText;
@@ -242,10 +254,34 @@ pub struct C_GtkSeparatorToolItem;
 #[repr(C)]
 pub struct C_GtkMenu;
 #[repr(C)]
+pub struct C_GtkMenuBar;
+#[repr(C)]
+pub struct C_GtkRadioMenuItem;
+#[repr(C)]
+pub struct C_GtkImageMenuItem;
+#[repr(C)]
+pub struct C_GtkAccelGroup;
+#[repr(C)]
+pub struct C_GtkAccelMap;
+#[repr(C)]
+pub struct C_GtkAccelKey {
+    pub accel_key: c_uint,
+    pub accel_mods: gdk_ffi::enums::modifier_type::ModifierType,
+    pub accel_flags: c_uint
+}
+#[repr(C)]
 pub struct C_GMenuModel;
 #[repr(C)]
+pub struct C_GMenu;
+#[repr(C)]
+pub struct C_GMenuItem;
+#[repr(C)]
 pub struct C_GClosure;
 #[repr(C)]
+pub struct C_GKeyFile;
+#[repr(C)]
+pub struct C_GtkTreeSortable;
+#[repr(C)]
 pub struct C_GtkColorChooser;
 #[repr(C)]
 pub struct C_GtkEditable;
@@ -326,9 +362,49 @@ pub struct C_GtkSocket;
 pub struct C_GtkEventBox;
 #[repr(C)]
 pub struct C_GtkTooltip;
+#[repr(C)]
+pub struct C_GtkStyleContext;
+#[repr(C)]
+pub struct C_GApplication;
+#[repr(C)]
+pub struct C_GtkApplication;
+#[repr(C)]
+pub struct C_GtkApplicationWindow;
+#[repr(C)]
+pub struct C_GFile;
+#[repr(C)]
+pub struct C_GVariant;
+#[repr(C)]
+pub struct C_GVariantType;
+#[repr(C)]
+pub struct C_GAction;
+#[repr(C)]
+pub struct C_GActionGroup;
+#[repr(C)]
+pub struct C_GActionMap;
+#[repr(C)]
+pub struct C_GSimpleAction;
+#[repr(C)]
+pub struct C_GSimpleActionGroup;
 
 //pub type GtkTreeModelForeachFunc = fn(model: *mut C_GtkTreeModel, path: *mut C_GtkTreePath, iter: *mut C_GtkTreeIter, data: gpointer) -> Gboolean;
 
+pub type GDestroyNotify = Option<extern "C" fn(data: gpointer)>;
+pub type GtkCallback = Option<extern "C" fn(widget: *mut C_GtkWidget, data: gpointer)>;
+// ihint is a GSignalInvocationHint*
+pub type GSignalEmissionHook = Option<extern "C" fn(ihint: gpointer, n_param_values: c_uint, param_values: *const C_GValue,
+    data: gpointer) -> Gboolean>;
+pub type GtkTreeViewRowSeparatorFunc = Option<extern "C" fn(model: *mut C_GtkTreeModel, iter: *mut C_GtkTreeIter, data: gpointer) -> Gboolean>;
+pub type GtkTreeViewSearchEqualFunc = Option<extern "C" fn(model: *mut C_GtkTreeModel, column: c_int, key: *const c_char,
+    iter: *mut C_GtkTreeIter, search_data: gpointer) -> Gboolean>;
+pub type GtkFlowBoxSortFunc = Option<extern "C" fn(child1: *mut C_GtkFlowBoxChild, child2: *mut C_GtkFlowBoxChild, user_data: gpointer) -> c_int>;
+pub type GtkFlowBoxFilterFunc = Option<extern "C" fn(child: *mut C_GtkFlowBoxChild, user_data: gpointer) -> Gboolean>;
+pub type GtkFlowBoxForeachFunc = Option<extern "C" fn(_box: *mut C_GtkFlowBox, child: *mut C_GtkFlowBoxChild, user_data: gpointer)>;
+pub type GtkListBoxSortFunc = Option<extern "C" fn(row1: *mut C_GtkListBoxRow, row2: *mut C_GtkListBoxRow, user_data: gpointer) -> c_int>;
+pub type GtkListBoxFilterFunc = Option<extern "C" fn(row: *mut C_GtkListBoxRow, user_data: gpointer) -> Gboolean>;
+pub type GtkListBoxUpdateHeaderFunc = Option<extern "C" fn(row: *mut C_GtkListBoxRow, before: *mut C_GtkListBoxRow, user_data: gpointer)>;
+pub type GtkListBoxForeachFunc = Option<extern "C" fn(list_box: *mut C_GtkListBox, row: *mut C_GtkListBoxRow, user_data: gpointer)>;
+
 extern "C" {
 
     //=========================================================================
@@ -341,6 +417,8 @@ extern "C" {
     pub fn gtk_main_iteration                  () -> Gboolean;
     pub fn gtk_main_iteration_do               (blocking: Gboolean) -> Gboolean;
     pub fn gtk_events_pending                  () -> Gboolean;
+    // event is a GdkEvent*
+    pub fn gtk_get_event_widget                (event: gpointer) -> *mut C_GtkWidget;
 
     //=========================================================================
     // GtkWindow
@@ -352,18 +430,49 @@ extern "C" {
     pub fn gtk_window_set_position             (window: *mut C_GtkWindow, position: enums::WindowPosition) -> ();
     pub fn gtk_window_set_decorated            (window: *mut C_GtkWindow, setting: Gboolean) -> ();
     pub fn gtk_window_set_titlebar             (window: *mut C_GtkWindow, titlebar: *mut C_GtkWidget) -> ();
-
-    // pub fn gtk_window_set_role(window: *const const C_GtkWindow, role: *const c_char) -> ();
+    pub fn gtk_window_list_toplevels           () -> *mut C_GList;
+    pub fn gtk_window_get_window_type          (window: *mut C_GtkWindow) -> enums::WindowType;
+
+    pub fn gtk_window_set_resizable            (window: *mut C_GtkWindow, resizable: Gboolean);
+    pub fn gtk_window_get_resizable            (window: *mut C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_set_modal                (window: *mut C_GtkWindow, modal: Gboolean);
+    pub fn gtk_window_get_modal                (window: *mut C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_set_transient_for        (window: *mut C_GtkWindow, parent: *mut C_GtkWindow);
+    pub fn gtk_window_get_transient_for        (window: *mut C_GtkWindow) -> *mut C_GtkWindow;
+    pub fn gtk_window_set_destroy_with_parent  (window: *mut C_GtkWindow, setting: Gboolean);
+    pub fn gtk_window_get_destroy_with_parent  (window: *mut C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_present                  (window: *mut C_GtkWindow);
+    pub fn gtk_window_present_with_time        (window: *mut C_GtkWindow, timestamp: u32);
+    pub fn gtk_window_resize                   (window: *mut C_GtkWindow, width: c_int, height: c_int);
+    pub fn gtk_window_move                     (window: *mut C_GtkWindow, x: c_int, y: c_int);
+    pub fn gtk_window_get_size                 (window: *mut C_GtkWindow, width: *mut c_int, height: *mut c_int);
+    pub fn gtk_window_get_position             (window: *mut C_GtkWindow, root_x: *mut c_int, root_y: *mut c_int);
+    pub fn gtk_window_maximize                 (window: *mut C_GtkWindow);
+    pub fn gtk_window_unmaximize               (window: *mut C_GtkWindow);
+    pub fn gtk_window_fullscreen               (window: *mut C_GtkWindow);
+    pub fn gtk_window_unfullscreen             (window: *mut C_GtkWindow);
+    pub fn gtk_window_iconify                  (window: *mut C_GtkWindow);
+    pub fn gtk_window_deiconify                (window: *mut C_GtkWindow);
+    pub fn gtk_window_set_keep_above           (window: *mut C_GtkWindow, setting: Gboolean);
+    pub fn gtk_window_set_keep_below           (window: *mut C_GtkWindow, setting: Gboolean);
+    pub fn gtk_window_set_icon                 (window: *mut C_GtkWindow, icon: *mut gdk_ffi::C_GdkPixbuf);
+    pub fn gtk_window_get_icon                 (window: *mut C_GtkWindow) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gtk_window_set_icon_name            (window: *mut C_GtkWindow, name: *const c_char);
+    pub fn gtk_window_get_icon_name            (window: *mut C_GtkWindow) -> *const c_char;
+    pub fn gtk_window_set_type_hint            (window: *mut C_GtkWindow, hint: gdk_ffi::enums::window_type_hint::WindowTypeHint);
+    pub fn gtk_window_get_type_hint            (window: *mut C_GtkWindow) -> gdk_ffi::enums::window_type_hint::WindowTypeHint;
+    pub fn gtk_window_is_active                (window: *mut C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_set_role                 (window: *mut C_GtkWindow, role: *const c_char);
+    pub fn gtk_window_get_role                 (window: *mut C_GtkWindow) -> *const c_char;
     // pub fn gtk_window_set_startup_id(window: *const const C_GtkWindow, startup_id: *const c_char) -> ();
-    // pub fn gtk_window_get_role(window: *const const C_GtkWindow) -> *const c_char;
-    // pub fn gtk_window_add_accel_group(window: *const const C_GtkWindow, accel_group: *GtkAccelGroup) -> ();
-    // pub fn gtk_window_remove_accel_group(window: *const const C_GtkWindow, accel_group: *GtkAccelGroup) -> ();
-    // pub fn gtk_window_activate_focus(window: *const const C_GtkWindow) -> Gboolean;
-    // pub fn gtk_window_set_focus(window: *const const C_GtkWindow, focus: *const const C_GtkWidget) -> ();
-    // pub fn gtk_window_get_focus(window: *const const C_GtkWindow) -> *const const C_GtkWidget;
-    // pub fn gtk_window_set_default(window: *const const C_GtkWindow, default_widget: *const const C_GtkWidget) -> ();
-    // pub fn gtk_window_get_default_widget(window: *const const C_GtkWindow) -> *const const C_GtkWidget;
-    // pub fn gtk_window_activate_default(window: *const const C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_add_accel_group          (window: *mut C_GtkWindow, accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_window_remove_accel_group       (window: *mut C_GtkWindow, accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_window_activate_focus           (window: *mut C_GtkWindow) -> Gboolean;
+    pub fn gtk_window_set_focus                (window: *mut C_GtkWindow, focus: *mut C_GtkWidget);
+    pub fn gtk_window_get_focus                (window: *mut C_GtkWindow) -> *mut C_GtkWidget;
+    pub fn gtk_window_set_default              (window: *mut C_GtkWindow, default_widget: *mut C_GtkWidget);
+    pub fn gtk_window_get_default_widget       (window: *mut C_GtkWindow) -> *mut C_GtkWidget;
+    pub fn gtk_window_activate_default         (window: *mut C_GtkWindow) -> Gboolean;
     // pub fn gtk_window_get_type() -> ();
 
     //=========================================================================
@@ -392,12 +501,14 @@ extern "C" {
     pub fn gtk_widget_show                     (widget: *mut C_GtkWidget);
     pub fn gtk_widget_show_now                 (widget: *mut C_GtkWidget);
     pub fn gtk_widget_show_all                 (widget: *mut C_GtkWidget);
+    pub fn gtk_widget_get_type                 () -> GType;
     pub fn gtk_widget_hide                     (widget: *mut C_GtkWidget);
     pub fn gtk_widget_map                      (widget: *mut C_GtkWidget);
     pub fn gtk_widget_unmap                    (widget: *mut C_GtkWidget);
     pub fn gtk_widget_realize                  (widget: *mut C_GtkWidget);
     pub fn gtk_widget_unrealize                (widget: *mut C_GtkWidget);
-    //pub fn gtk_widget_draw                     (widget: *mut C_GtkWidget, cr: *mut cairo_t);
+    // cr is a cairo_t*
+    pub fn gtk_widget_draw                     (widget: *mut C_GtkWidget, cr: gpointer);
     pub fn gtk_widget_queue_draw               (widget: *mut C_GtkWidget);
     pub fn gtk_widget_queue_resize             (widget: *mut C_GtkWidget);
     pub fn gtk_widget_queue_resize_no_redraw   (widget: *mut C_GtkWidget);
@@ -409,10 +520,11 @@ extern "C" {
     //pub fn gtk_widget_get_child_requisition    (widget: *mut C_GtkWidget, requisition: *mut C_GtkRequisition);
     //pub fn gtk_widget_size_allocate            (widget: *mut C_GtkWidget, allocation: *mut C_GtkAllocation);
     //pub fn gtk_widget_size_allocate_with_baseline(widget: *mut C_GtkWidget, allocation: *mut C_GtkAllocation, baseline: c_int);
-    //pub fn gtk_widget_add_accelerator          (widget: *mut C_GtkWidget, accel_signal: *const c_char, accel_group: *mut C_GtkAccelGroup,
-        //accel_key: c_uint, accel_mods: gdk::ModifierType, accel_flags: enums::AccelFlags);
-    //pub fn gtk_widget_remove_accelerator       (widget: *mut C_GtkWidget, accel_group: *mut C_GtkAccelGroup, accel_key: c_uint, accel_mods: gdk::ModifierType) -> Gboolean;
-    //pub fn gtk_widget_set_accel_path           (widget: *mut C_GtkWidget, accel_path: *const c_char, accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_widget_add_accelerator          (widget: *mut C_GtkWidget, accel_signal: *const c_char, accel_group: *mut C_GtkAccelGroup,
+        accel_key: c_uint, accel_mods: gdk_ffi::enums::modifier_type::ModifierType, accel_flags: c_uint);
+    pub fn gtk_widget_remove_accelerator       (widget: *mut C_GtkWidget, accel_group: *mut C_GtkAccelGroup, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_widget_set_accel_path           (widget: *mut C_GtkWidget, accel_path: *const c_char, accel_group: *mut C_GtkAccelGroup);
     //pub fn gtk_widget_list_accel_closures      (widget: *mut C_GtkWidget) -> *mut glib::GList;
     //pub fn gtk_widget_can_activate_accel       (widget: *mut C_GtkWidget, signal_id: c_uint) -> Gboolean;
     //pub fn gtk_widget_event                    (widget: *mut C_GtkWidget, event: *mut gdk::Event) -> Gboolean;
@@ -508,14 +620,14 @@ extern "C" {
     pub fn gtk_widget_get_has_tooltip          (widget: *mut C_GtkWidget) -> Gboolean;
     pub fn gtk_widget_set_has_tooltip          (widget: *mut C_GtkWidget, has_tooltip: Gboolean);
     pub fn gtk_widget_trigger_tooltip_query    (widget: *mut C_GtkWidget);
-    //pub fn gtk_widget_get_window               (widget: *mut C_GtkWidget) -> *mut C_GtkWindow;
+    pub fn gtk_widget_get_window               (widget: *mut C_GtkWidget) -> *mut gdk_ffi::C_GdkWindow;
     //pub fn gtk_widget_register_window          (widget: *mut C_GtkWidget, window: *mut C_GtkWindow);
     //pub fn gtk_widget_unregister_window        (widget: *mut C_GtkWidget, window: *mut C_GtkWindow);
     //pub fn gtk_cairo_should_draw_window        (cr: *mut cairo_t, window: *mut C_GtkWindow);
     //pub fn gtk_cairo_transform_to_window       (cr: *mut cairo_t, widget: *mut C_GtkWidget, window: *mut C_GtkWindow);
     pub fn gtk_widget_get_allocated_width      (widget: *mut C_GtkWidget) -> c_int;
     pub fn gtk_widget_get_allocated_height     (widget: *mut C_GtkWidget) -> c_int;
-    //pub fn gtk_widget_get_allocation           (widget: *mut C_GtkWidget, allocation: *mut C_GtkAllocation);
+    pub fn gtk_widget_get_allocation           (widget: *mut C_GtkWidget, allocation: *mut gdk_ffi::C_GdkRectangle);
     //pub fn gtk_widget_set_allocation           (widget: *mut C_GtkWidget, allocation: *const C_GtkAllocation);
     pub fn gtk_widget_get_allocated_baseline   (widget: *mut C_GtkWidget) -> c_int;
     pub fn gtk_widget_get_app_paintable        (widget: *mut C_GtkWidget) -> Gboolean;
@@ -555,7 +667,8 @@ extern "C" {
     pub fn gtk_widget_get_opacity              (widget: *mut C_GtkWidget) -> c_double;
     pub fn gtk_widget_set_opacity              (widget: *mut C_GtkWidget, opacity: c_double);
     //pub fn gtk_widget_get_path                 (widget: *mut C_GtkWidget) -> *mut C_GtkWidgetPath;
-    //pub fn gtk_widget_get_style_context        (widget: *mut C_GtkWidget) -> *mut C_GtkStyleContext;
+    pub fn gtk_widget_get_style_context        (widget: *mut C_GtkWidget) -> *mut C_GtkStyleContext;
+    pub fn gtk_widget_insert_action_group      (widget: *mut C_GtkWidget, name: *const c_char, group: *mut C_GActionGroup);
     pub fn gtk_widget_reset_style              (widget: *mut C_GtkWidget);
     //pub fn gtk_requisition_new                 () -> *mut C_GtkRequisition;
     //pub fn gtk_requisition_copy                (requisition: *const C_GtkRequisition) -> *mut C_GtkRequisition;
@@ -704,6 +817,14 @@ extern "C" {
     //pub type GtkEntryCompletionMatchFunc = Option<extern "C" fn(completion: *mut C_GtkEntryCompletion, key: *const c_char,
     //    iter: *mut C_GtkTreeIter, user_data: gpointer) -> Gboolean>;
 
+    //=========================================================================
+    // GtkStyleContext                                                   NOT OK
+    //=========================================================================
+    pub fn gtk_style_context_add_class         (context: *mut C_GtkStyleContext, class_name: *const c_char);
+    pub fn gtk_style_context_remove_class      (context: *mut C_GtkStyleContext, class_name: *const c_char);
+    pub fn gtk_style_context_has_class         (context: *mut C_GtkStyleContext, class_name: *const c_char) -> Gboolean;
+    pub fn gtk_style_context_list_classes      (context: *mut C_GtkStyleContext) -> *mut C_GList;
+
     //=========================================================================
     // GtkContainer                                                          OK
     //=========================================================================
@@ -714,6 +835,18 @@ extern "C" {
     pub fn gtk_container_check_resize          (container: *mut C_GtkContainer) -> ();
     pub fn gtk_container_get_border_width      (container: *mut C_GtkContainer) -> c_uint;
     pub fn gtk_container_set_border_width      (container: *mut C_GtkContainer, border_width: c_uint) -> ();
+    pub fn gtk_container_get_children          (container: *mut C_GtkContainer) -> *mut C_GList;
+    pub fn gtk_container_foreach               (container: *mut C_GtkContainer, callback: GtkCallback, callback_data: gpointer);
+    pub fn gtk_container_forall                (container: *mut C_GtkContainer, callback: GtkCallback, callback_data: gpointer);
+    pub fn gtk_container_child_get_property    (container: *mut C_GtkContainer, child: *mut C_GtkWidget, property_name: *const c_char,
+                                                value: *mut C_GValue);
+    pub fn gtk_container_child_set_property    (container: *mut C_GtkContainer, child: *mut C_GtkWidget, property_name: *const c_char,
+                                                value: *const C_GValue);
+    pub fn gtk_container_set_focus_chain       (container: *mut C_GtkContainer, focusable_widgets: *mut C_GList);
+    pub fn gtk_container_unset_focus_chain     (container: *mut C_GtkContainer);
+    pub fn gtk_container_get_focus_child       (container: *mut C_GtkContainer) -> *mut C_GtkWidget;
+    pub fn gtk_container_set_focus_child       (container: *mut C_GtkContainer, child: *mut C_GtkWidget);
+    pub fn gtk_container_child_type            (container: *mut C_GtkContainer) -> GType;
 
     //=========================================================================
     // GtkMisc                                                               OK
@@ -755,8 +888,8 @@ extern "C" {
     // GtkPopover                                                        NOT OK
     //=========================================================================
     pub fn gtk_popover_new                     (relative_to: *mut C_GtkWidget) -> *mut C_GtkWidget;
-    //pub fn gtk_popover_new_from_model          (relative_to: *mut C_GtkWidget, model: *mut C_GMenuModel) -> *mut C_GtkWidget;
-    //pub fn gtk_popover_bind_model              (popover: *mut C_GtkPopover, model: *mut C_GMenuModel, action_namespace: *const c_char);
+    pub fn gtk_popover_new_from_model          (relative_to: *mut C_GtkWidget, model: *mut C_GMenuModel) -> *mut C_GtkWidget;
+    pub fn gtk_popover_bind_model              (popover: *mut C_GtkPopover, model: *mut C_GMenuModel, action_namespace: *const c_char);
     pub fn gtk_popover_set_relative_to         (popover: *mut C_GtkPopover, relative_to: *mut C_GtkWidget);
     pub fn gtk_popover_get_relative_to         (popover: *mut C_GtkPopover) -> *mut C_GtkWidget;
     //pub fn gtk_popover_set_pointing_to         (popover: *mut C_GtkPopover, rect: *mut GdkRectangle);
@@ -803,7 +936,7 @@ extern "C" {
     pub fn gtk_tree_selection_get_selected     (selection: *mut C_GtkTreeSelection, model: *mut *mut C_GtkTreeModel,
         iter: *mut C_GtkTreeIter) -> Gboolean;
     //pub fn gtk_tree_selection_selected_foreach (selection: *mut C_GtkTreeSelection, func: GtkTreeSelectionForeachFunc, data: gpointer);
-    //pub fn gtk_tree_selection_get_selected_rows(selection: *mut C_GtkTreeSelection, model: *mut *mut C_GtkTreeModel) -> *mut GList;
+    pub fn gtk_tree_selection_get_selected_rows(selection: *mut C_GtkTreeSelection, model: *mut *mut C_GtkTreeModel) -> *mut C_GList;
     pub fn gtk_tree_selection_count_selected_rows(selection: *mut C_GtkTreeSelection) -> c_int;
     pub fn gtk_tree_selection_select_path      (selection: *mut C_GtkTreeSelection, path: *mut C_GtkTreePath);
     pub fn gtk_tree_selection_unselect_path    (selection: *mut C_GtkTreeSelection, path: *mut C_GtkTreePath);
@@ -913,6 +1046,16 @@ extern "C" {
     pub fn gtk_tree_iter_copy(iter: *mut C_GtkTreeIter) -> *mut C_GtkTreeIter;
     pub fn gtk_tree_iter_free(iter: *mut C_GtkTreeIter);
 
+    //=========================================================================
+    // GtkTreeRowReference                                                   OK
+    //=========================================================================
+    pub fn gtk_tree_row_reference_new          (model: *mut C_GtkTreeModel, path: *mut C_GtkTreePath) -> *mut C_GtkTreeRowReference;
+    pub fn gtk_tree_row_reference_get_model    (reference: *mut C_GtkTreeRowReference) -> *mut C_GtkTreeModel;
+    pub fn gtk_tree_row_reference_get_path     (reference: *mut C_GtkTreeRowReference) -> *mut C_GtkTreePath;
+    pub fn gtk_tree_row_reference_valid        (reference: *mut C_GtkTreeRowReference) -> Gboolean;
+    pub fn gtk_tree_row_reference_copy         (reference: *mut C_GtkTreeRowReference) -> *mut C_GtkTreeRowReference;
+    pub fn gtk_tree_row_reference_free         (reference: *mut C_GtkTreeRowReference);
+
     //=========================================================================
     // GtkCellRenderer                                                   NOT OK
     //=========================================================================
@@ -1135,7 +1278,7 @@ extern "C" {
     pub fn gtk_icon_view_select_path           (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath);
     pub fn gtk_icon_view_unselect_path         (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath);
     pub fn gtk_icon_view_path_is_selected      (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath) -> Gboolean;
-    //pub fn gtk_icon_view_get_selected_items    (icon_view: *mut C_GtkIconView) -> *mut GList;
+    pub fn gtk_icon_view_get_selected_items    (icon_view: *mut C_GtkIconView) -> *mut C_GList;
     pub fn gtk_icon_view_select_all            (icon_view: *mut C_GtkIconView);
     pub fn gtk_icon_view_unselect_all          (icon_view: *mut C_GtkIconView);
     pub fn gtk_icon_view_item_activated        (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath);
@@ -1152,10 +1295,10 @@ extern "C" {
     pub fn gtk_icon_view_get_tooltip_column    (icon_view: *mut C_GtkIconView) -> c_int;
     pub fn gtk_icon_view_get_item_row          (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath) -> c_int;
     pub fn gtk_icon_view_get_item_column       (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath) -> c_int;
-    //pub fn gtk_icon_view_enable_model_drag_source(icon_view: *mut C_GtkIconView, start_button_mask: gdk::ModifierType,
-    //    targets: *const C_GtkTargetEntry, n_targets: c_int, actions: GdkDragAction);
-    //pub fn gtk_icon_view_enable_model_drag_dest(icon_view: *mut C_GtkIconView, targets: *const C_GtkTargetEntry, n_targets: c_int,
-    //    actions: GdkDragAction);
+    pub fn gtk_icon_view_enable_model_drag_source(icon_view: *mut C_GtkIconView, start_button_mask: gdk_ffi::enums::modifier_type::ModifierType,
+        targets: *const C_GtkTargetEntry, n_targets: c_int, actions: gdk_ffi::enums::drag_action::DragAction);
+    pub fn gtk_icon_view_enable_model_drag_dest(icon_view: *mut C_GtkIconView, targets: *const C_GtkTargetEntry, n_targets: c_int,
+        actions: gdk_ffi::enums::drag_action::DragAction);
     pub fn gtk_icon_view_unset_model_drag_source(icon_view: *mut C_GtkIconView);
     pub fn gtk_icon_view_unset_model_drag_dest (icon_view: *mut C_GtkIconView);
     pub fn gtk_icon_view_set_reorderable       (icon_view: *mut C_GtkIconView, reorderable: Gboolean);
@@ -1164,7 +1307,7 @@ extern "C" {
     pub fn gtk_icon_view_get_drag_dest_item    (icon_view: *mut C_GtkIconView, path: *mut *mut C_GtkTreePath,
         pos: *mut enums::IconViewDropPosition);
     pub fn gtk_icon_view_get_dest_item_at_pos  (icon_view: *mut C_GtkIconView, drag_x: c_int, drag_y: c_int, path: *mut *mut C_GtkTreePath,
-        pos: *mut enums::IconViewDropPosition);
+        pos: *mut enums::IconViewDropPosition) -> Gboolean;
     //pub fn gtk_icon_view_create_drag_icon      (icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath) -> *mut cairo_surface_t;
 
     //pub type GtkIconViewForeachFunc = fn(icon_view: *mut C_GtkIconView, path: *mut C_GtkTreePath, data: gpointer);
@@ -1481,7 +1624,7 @@ extern "C" {
     pub fn gtk_combo_box_set_column_span_column(combo_box: *mut C_GtkComboBox, column_span: c_int);
     pub fn gtk_combo_box_get_active            (combo_box: *mut C_GtkComboBox) -> c_int;
     pub fn gtk_combo_box_set_active            (combo_box: *mut C_GtkComboBox, active: c_int);
-    pub fn gtk_combo_box_get_active_iter       (combo_box: *mut C_GtkComboBox) -> *mut C_GtkTreeIter;
+    pub fn gtk_combo_box_get_active_iter       (combo_box: *mut C_GtkComboBox, iter: *mut C_GtkTreeIter) -> Gboolean;
     pub fn gtk_combo_box_set_active_iter       (combo_box: *mut C_GtkComboBox, iter: *mut C_GtkTreeIter);
     pub fn gtk_combo_box_get_id_column         (combo_box: *mut C_GtkComboBox) -> c_int;
     pub fn gtk_combo_box_set_id_column         (combo_box: *mut C_GtkComboBox, id_column: c_int);
@@ -1494,8 +1637,8 @@ extern "C" {
     pub fn gtk_combo_box_popdown               (combo_box: *mut C_GtkComboBox);
     //pub fn gtk_combo_box_get_popup_accessible  (combo_box: *mut C_GtkComboBox) -> *mut C_AtkObject;
     //pub fn gtk_combo_box_get_row_separator_func(combo_box: *mut C_GtkComboBox) -> C_GtkTreeViewRowSeparatorFunc;
-    //pub fn gtk_combo_box_set_row_separator_func(combo_box: *mut C_GtkComboBox, func: C_GtkTreeViewRowSeparatorFunc,
-    //    data: gpointer, destroy: GDestroyNotify);
+    pub fn gtk_combo_box_set_row_separator_func(combo_box: *mut C_GtkComboBox, func: GtkTreeViewRowSeparatorFunc,
+        data: gpointer, destroy: GDestroyNotify);
     pub fn gtk_combo_box_get_focus_on_click    (combo_box: *mut C_GtkComboBox) -> Gboolean;
     pub fn gtk_combo_box_set_focus_on_click    (combo_box: *mut C_GtkComboBox, focus_on_click: Gboolean);
     pub fn gtk_combo_box_get_button_sensitivity(combo_box: *mut C_GtkComboBox) -> enums::SensitivityType;
@@ -1736,8 +1879,8 @@ extern "C" {
     pub fn gtk_menu_button_new                 () -> *mut C_GtkWidget;
     pub fn gtk_menu_button_set_popup           (menu_button: *mut C_GtkMenuButton, popup: *mut C_GtkWidget) -> ();
     // pub fn gtk_menu_button_get_popup           (menu_button: *const const C_GtkMenuButton) -> *const const C_GtkMenu;
-    // pub fn gtk_menu_button_set_menu_model      (menu_button: *const const C_GtkMenuButton, menu_model: *const const C_GMenuModel) -> ();
-    // pub fn gtk_menu_button_get_menu_model      (menu_button: *const const C_GtkMenuButton) -> C_GMenuModel;
+    pub fn gtk_menu_button_set_menu_model      (menu_button: *mut C_GtkMenuButton, menu_model: *mut C_GMenuModel);
+    pub fn gtk_menu_button_get_menu_model      (menu_button: *mut C_GtkMenuButton) -> *mut C_GMenuModel;
     pub fn gtk_menu_button_set_direction       (menu_button: *mut C_GtkMenuButton, direction: enums::ArrowType) -> ();
     pub fn gtk_menu_button_get_direction       (menu_button: *mut C_GtkMenuButton) -> enums::ArrowType;
     pub fn gtk_menu_button_set_align_widget    (menu_button: *mut C_GtkMenuButton, align_widget: *mut C_GtkWidget) -> ();
@@ -2548,8 +2691,14 @@ extern "C" {
     pub fn gtk_flow_box_get_selection_mode           (_box: *mut C_GtkFlowBox) -> enums::SelectionMode;
     pub fn gtk_flow_box_set_hadjustment              (_box: *mut C_GtkFlowBox, adjustment: *mut C_GtkAdjustment);
     pub fn gtk_flow_box_set_vadjustment              (_box: *mut C_GtkFlowBox, adjustment: *mut C_GtkAdjustment);
-    // void                  gtk_flow_box_selected_foreach             (_box: *const const C_GtkFlowBox, GtkFlowBoxForeachFunc func, gpointer           data);
-    // GList                *gtk_flow_box_get_selected_children        (_box: *const const C_GtkFlowBox);
+    pub fn gtk_flow_box_selected_foreach             (_box: *mut C_GtkFlowBox, func: GtkFlowBoxForeachFunc, data: gpointer);
+    pub fn gtk_flow_box_get_selected_children        (_box: *mut C_GtkFlowBox) -> *mut C_GList;
+    pub fn gtk_flow_box_set_filter_func              (_box: *mut C_GtkFlowBox, filter_func: GtkFlowBoxFilterFunc, user_data: gpointer,
+                                                      destroy: GDestroyNotify);
+    pub fn gtk_flow_box_invalidate_filter            (_box: *mut C_GtkFlowBox);
+    pub fn gtk_flow_box_set_sort_func                (_box: *mut C_GtkFlowBox, sort_func: GtkFlowBoxSortFunc, user_data: gpointer,
+                                                      destroy: GDestroyNotify);
+    pub fn gtk_flow_box_invalidate_sort              (_box: *mut C_GtkFlowBox);
 
     //=========================================================================
     // GtkFlowBoxChild                                                       OK
@@ -2574,8 +2723,19 @@ extern "C" {
     pub fn gtk_list_box_get_adjustment               (list_box: *mut C_GtkListBox) -> *mut C_GtkAdjustment;
     pub fn gtk_list_box_set_selection_mode           (list_box: *mut C_GtkListBox, mode: enums::SelectionMode);
     pub fn gtk_list_box_get_selection_mode           (list_box: *mut C_GtkListBox) -> enums::SelectionMode;
-    // pub fn gtk_list_box_invalidate_filter            (list_box: *const const C_GtkListBox);
-    // pub fn gtk_list_box_invalidate_sort              (list_box: *const const C_GtkListBox);
+    pub fn gtk_list_box_unselect_row                 (list_box: *mut C_GtkListBox, row: *mut C_GtkListBoxRow);
+    pub fn gtk_list_box_select_all                   (list_box: *mut C_GtkListBox);
+    pub fn gtk_list_box_unselect_all                 (list_box: *mut C_GtkListBox);
+    pub fn gtk_list_box_selected_foreach             (list_box: *mut C_GtkListBox, func: GtkListBoxForeachFunc, data: gpointer);
+    pub fn gtk_list_box_get_selected_rows            (list_box: *mut C_GtkListBox) -> *mut C_GList;
+    pub fn gtk_list_box_set_filter_func              (list_box: *mut C_GtkListBox, filter_func: GtkListBoxFilterFunc, user_data: gpointer,
+                                                      destroy: GDestroyNotify);
+    pub fn gtk_list_box_set_header_func              (list_box: *mut C_GtkListBox, update_header: GtkListBoxUpdateHeaderFunc,
+                                                      user_data: gpointer, destroy: GDestroyNotify);
+    pub fn gtk_list_box_set_sort_func                (list_box: *mut C_GtkListBox, sort_func: GtkListBoxSortFunc, user_data: gpointer,
+                                                      destroy: GDestroyNotify);
+    pub fn gtk_list_box_invalidate_filter            (list_box: *mut C_GtkListBox);
+    pub fn gtk_list_box_invalidate_sort              (list_box: *mut C_GtkListBox);
     pub fn gtk_list_box_invalidate_headers           (list_box: *mut C_GtkListBox);
     pub fn gtk_list_box_set_activate_on_single_click (list_box: *mut C_GtkListBox, simgle: Gboolean);
     pub fn gtk_list_box_get_activate_on_single_click (list_box: *mut C_GtkListBox) -> Gboolean;
@@ -2590,6 +2750,11 @@ extern "C" {
     pub fn gtk_list_box_row_get_header  (row: *mut C_GtkListBoxRow) -> *mut C_GtkWidget;
     pub fn gtk_list_box_row_set_header  (row: *mut C_GtkListBoxRow, header: *mut C_GtkWidget);
     pub fn gtk_list_box_row_get_index   (row: *mut C_GtkListBoxRow) -> c_int;
+    pub fn gtk_list_box_row_is_selected (row: *mut C_GtkListBoxRow) -> Gboolean;
+    pub fn gtk_list_box_row_set_activatable(row: *mut C_GtkListBoxRow, activatable: Gboolean);
+    pub fn gtk_list_box_row_get_activatable(row: *mut C_GtkListBoxRow) -> Gboolean;
+    pub fn gtk_list_box_row_set_selectable(row: *mut C_GtkListBoxRow, selectable: Gboolean);
+    pub fn gtk_list_box_row_get_selectable(row: *mut C_GtkListBoxRow) -> Gboolean;
 
     //=========================================================================
     // GtkActionBar                                                          OK
@@ -2868,6 +3033,13 @@ extern "C" {
     pub fn gtk_radio_button_new_with_mnemonic (group: *mut c_void, label: *const c_char) -> *mut C_GtkWidget;
     pub fn gtk_radio_button_join_group       (radio_button: *mut C_GtkRadioButton, group_source: *mut C_GtkRadioButton);
 
+    //=========================================================================
+    // GtkTreeSortable                                                   NOT OK
+    //=========================================================================
+    pub fn gtk_tree_sortable_get_sort_column_id(sortable: *mut C_GtkTreeSortable, sort_column_id: *mut c_int,
+        order: *mut enums::SortType) -> Gboolean;
+    pub fn gtk_tree_sortable_set_sort_column_id(sortable: *mut C_GtkTreeSortable, sort_column_id: c_int, order: enums::SortType);
+
     //=========================================================================
     // GtkTreeView                                                       NOT OK
     //=========================================================================
@@ -2927,38 +3099,48 @@ extern "C" {
     pub fn gtk_tree_view_get_model                     (tree_view: *mut C_GtkTreeView) -> *mut C_GtkTreeModel;
     pub fn gtk_tree_view_set_model                     (tree_view: *mut C_GtkTreeView, model: *mut C_GtkTreeModel);
     pub fn gtk_tree_view_get_selection                 (tree_view: *mut C_GtkTreeView) -> *mut C_GtkTreeSelection;
-    // pub fn gtk_tree_view_set_tooltip_row    (tree_view: *C_GtkTreeView, GtkTooltip        *tooltip, GtkTreePath       *path);
-    // pub fn gtk_tree_view_set_tooltip_cell   (tree_view: *C_GtkTreeView, GtkTooltip        *tooltip, GtkTreePath       *path, GtkTreeViewColumn *column, GtkCellRenderer   *cell);
+    pub fn gtk_tree_view_set_tooltip_row               (tree_view: *mut C_GtkTreeView, tooltip: *mut C_GtkTooltip, path: *mut C_GtkTreePath);
+    pub fn gtk_tree_view_set_tooltip_cell              (tree_view: *mut C_GtkTreeView, tooltip: *mut C_GtkTooltip, path: *mut C_GtkTreePath,
+        column: *mut C_GtkTreeViewColumn, cell: *mut C_GtkCellRenderer);
     // pub fn gtk_tree_view_get_tooltip_context(tree_view: *C_GtkTreeView, gint              *x, gint              *y, gboolean           keyboard_tip, GtkTreeModel     **model, GtkTreePath      **path, GtkTreeIter       *iter) -> Gboolean;
-    // pub fn gtk_tree_view_set_drag_dest_row             (tree_view: *C_GtkTreeView, GtkTreePath *path, GtkTreeViewDropPosition  pos);
-    // pub fn gtk_tree_view_get_drag_dest_row             (tree_view: *C_GtkTreeView, GtkTreePath **path, GtkTreeViewDropPosition *pos);
-    // pub fn gtk_tree_view_get_dest_row_at_pos           (tree_view: *C_GtkTreeView, gint drag_x, gint drag_y, GtkTreePath **path, GtkTreeViewDropPosition *pos) -> Gboolean;
+    pub fn gtk_tree_view_set_row_separator_func        (tree_view: *mut C_GtkTreeView, func: GtkTreeViewRowSeparatorFunc, data: gpointer,
+        destroy: GDestroyNotify);
+    pub fn gtk_tree_view_set_search_equal_func         (tree_view: *mut C_GtkTreeView, search_equal_func: GtkTreeViewSearchEqualFunc,
+        search_user_data: gpointer, search_destroy: GDestroyNotify);
+    pub fn gtk_tree_view_set_drag_dest_row             (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, pos: enums::TreeViewDropPosition);
+    pub fn gtk_tree_view_get_drag_dest_row             (tree_view: *mut C_GtkTreeView, path: *mut *mut C_GtkTreePath, pos: *mut enums::TreeViewDropPosition);
+    pub fn gtk_tree_view_get_dest_row_at_pos           (tree_view: *mut C_GtkTreeView, drag_x: c_int, drag_y: c_int, path: *mut *mut C_GtkTreePath,
+        pos: *mut enums::TreeViewDropPosition) -> Gboolean;
     pub fn gtk_tree_view_set_cursor                    (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, focus_column: *mut C_GtkTreeViewColumn, start_editing: Gboolean);
     // pub fn gtk_tree_view_set_cursor_on_cell            (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, focus_column: *mut GtkTreeViewColumn, focus_cell: *mut C_GtkCellRenderer, start_editing: Gboolean);
     pub fn gtk_tree_view_get_cursor                    (tree_view: *mut C_GtkTreeView, path: *mut *mut C_GtkTreePath, focus_column: *mut *mut C_GtkTreeViewColumn);
     // pub fn gtk_tree_view_get_bin_window                (tree_view: *mut C_GtkTreeView) -> *mut C_GdkWindow;
-    // pub fn gtk_tree_view_get_path_at_pos               (tree_view: *mut C_GtkTreeView, x: c_int, y: c_int, path: *mut *mut C_GtkTreePathh, column: *mut *mut C_GtkTreeViewColumn, cell_x: *mut c_int, cell_y: *mut c_int) -> Gboolean
-    // pub fn gtk_tree_view_get_cell_area                 (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, rect: *mut C_GdkRectangle);
-    // pub fn gtk_tree_view_get_background_area           (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, rect: *mut C_GdkRectangle);
+    pub fn gtk_tree_view_get_path_at_pos               (tree_view: *mut C_GtkTreeView, x: c_int, y: c_int, path: *mut *mut C_GtkTreePath,
+        column: *mut *mut C_GtkTreeViewColumn, cell_x: *mut c_int, cell_y: *mut c_int) -> Gboolean;
+    pub fn gtk_tree_view_get_cell_area                 (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, rect: *mut gdk_ffi::C_GdkRectangle);
+    pub fn gtk_tree_view_get_background_area           (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, rect: *mut gdk_ffi::C_GdkRectangle);
     // pub fn gtk_tree_view_get_visible_rect              (tree_view: *C_GtkTreeView, GdkRectangle              *visible_rect);
-    // pub fn gtk_tree_view_get_visible_range             (tree_view: *C_GtkTreeView, GtkTreePath              **start_path, GtkTreePath              **end_path) -> Gboolean
-    // pub fn gtk_tree_view_is_blank_at_pos               (tree_view: *C_GtkTreeView, gint x, gint y, GtkTreePath **path, GtkTreeViewColumn **column, gint *cell_x, gint *cell_y) -> Gboolean;
-    // pub fn gtk_tree_view_enable_model_drag_source      (tree_view: *C_GtkTreeView, GdkModifierType  start_button_mask, const GtkTargetEntry *targets, gint n_targets, GdkDragAction actions);
-    // pub fn gtk_tree_view_enable_model_drag_dest        (tree_view: *C_GtkTreeView, const GtkTargetEntry *targets, gint n_targets, GdkDragAction actions);
+    pub fn gtk_tree_view_get_visible_range             (tree_view: *mut C_GtkTreeView, start_path: *mut *mut C_GtkTreePath, end_path: *mut *mut C_GtkTreePath) -> Gboolean;
+    pub fn gtk_tree_view_is_blank_at_pos               (tree_view: *mut C_GtkTreeView, x: c_int, y: c_int, path: *mut *mut C_GtkTreePath,
+        column: *mut *mut C_GtkTreeViewColumn, cell_x: *mut c_int, cell_y: *mut c_int) -> Gboolean;
+    pub fn gtk_tree_view_enable_model_drag_source      (tree_view: *mut C_GtkTreeView, start_button_mask: gdk_ffi::enums::modifier_type::ModifierType,
+        targets: *const C_GtkTargetEntry, n_targets: c_int, actions: gdk_ffi::enums::drag_action::DragAction);
+    pub fn gtk_tree_view_enable_model_drag_dest        (tree_view: *mut C_GtkTreeView, targets: *const C_GtkTargetEntry, n_targets: c_int,
+        actions: gdk_ffi::enums::drag_action::DragAction);
     // pub fn gtk_tree_view_expand_to_path                (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath);
     pub fn gtk_tree_view_expand_row                    (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, open_all: Gboolean) -> Gboolean;
     pub fn gtk_tree_view_collapse_row                  (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath) -> Gboolean;
     // pub fn gtk_tree_view_row_expanded                  (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath) -> Gboolean;
-    // pub fn gtk_tree_view_scroll_to_cell                (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, use_align: Gboolean, row_align: c_float, col_align: c_float);
+    pub fn gtk_tree_view_scroll_to_cell                (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn, use_align: Gboolean, row_align: c_float, col_align: c_float);
     // pub fn gtk_tree_view_row_activated                 (tree_view: *mut C_GtkTreeView, path: *mut C_GtkTreePath, column: *mut C_GtkTreeViewColumn);
-    // pub fn gtk_tree_view_get_column                    (tree_view: *mut C_GtkTreeView, n: c_int) -> *mut C_GtkTreeViewColumn;
-    // pub fn gtk_tree_view_get_columns                   (tree_view: *mut C_GtkTreeView) -> *mut C_GList;
-    // pub fn gtk_tree_view_move_column_after             (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn, base_column: *mut GtkTreeViewColumn);
+    pub fn gtk_tree_view_get_column                    (tree_view: *mut C_GtkTreeView, n: c_int) -> *mut C_GtkTreeViewColumn;
+    pub fn gtk_tree_view_get_columns                   (tree_view: *mut C_GtkTreeView) -> *mut C_GList;
+    pub fn gtk_tree_view_move_column_after             (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn, base_column: *mut C_GtkTreeViewColumn);
     // pub fn gtk_tree_view_set_expander_column           (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn);
     // pub fn gtk_tree_view_get_expander_column           (tree_view: *mut C_GtkTreeView) -> *mut C_GtkTreeViewColumn;
     pub fn gtk_tree_view_append_column                 (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn) -> c_int;
-    // pub fn gtk_tree_view_remove_column                 (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn) -> c_int;
-    // pub fn gtk_tree_view_insert_column                 (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn, position: c_int) -> c_int;
+    pub fn gtk_tree_view_remove_column                 (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn) -> c_int;
+    pub fn gtk_tree_view_insert_column                 (tree_view: *mut C_GtkTreeView, column: *mut C_GtkTreeViewColumn, position: c_int) -> c_int;
 
     //=========================================================================
     // GtkTreeViewColumn
@@ -3061,6 +3243,105 @@ extern "C" {
     //=========================================================================
     pub fn gtk_separator_menu_item_new() -> *mut C_GtkWidget;
 
+    //=========================================================================
+    // GtkMenu
+    //=========================================================================
+    pub fn gtk_menu_new                       () -> *mut C_GtkWidget;
+    pub fn gtk_menu_new_from_model            (model: *mut C_GMenuModel) -> *mut C_GtkWidget;
+    pub fn gtk_menu_popup                     (menu: *mut C_GtkMenu, parent_menu_shell: *mut C_GtkWidget, parent_menu_item: *mut C_GtkWidget,
+        func: gpointer, data: gpointer, button: c_uint, activate_time: u32);
+    pub fn gtk_menu_popdown                   (menu: *mut C_GtkMenu);
+    pub fn gtk_menu_reposition                (menu: *mut C_GtkMenu);
+    pub fn gtk_menu_reorder_child             (menu: *mut C_GtkMenu, child: *mut C_GtkWidget, position: c_int);
+    pub fn gtk_menu_attach                    (menu: *mut C_GtkMenu, child: *mut C_GtkWidget, left_attach: c_uint, right_attach: c_uint,
+        top_attach: c_uint, bottom_attach: c_uint);
+    pub fn gtk_menu_attach_to_widget          (menu: *mut C_GtkMenu, attach_widget: *mut C_GtkWidget, detacher: gpointer);
+    pub fn gtk_menu_detach                    (menu: *mut C_GtkMenu);
+    pub fn gtk_menu_get_attach_widget         (menu: *mut C_GtkMenu) -> *mut C_GtkWidget;
+    pub fn gtk_menu_set_accel_group           (menu: *mut C_GtkMenu, accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_menu_get_accel_group           (menu: *mut C_GtkMenu) -> *mut C_GtkAccelGroup;
+    pub fn gtk_menu_set_accel_path            (menu: *mut C_GtkMenu, accel_path: *const c_char);
+    pub fn gtk_menu_get_accel_path            (menu: *mut C_GtkMenu) -> *const c_char;
+    pub fn gtk_menu_set_active                (menu: *mut C_GtkMenu, index: c_uint);
+    pub fn gtk_menu_get_active                (menu: *mut C_GtkMenu) -> *mut C_GtkWidget;
+    pub fn gtk_menu_set_monitor               (menu: *mut C_GtkMenu, monitor_num: c_int);
+    pub fn gtk_menu_get_monitor               (menu: *mut C_GtkMenu) -> c_int;
+    pub fn gtk_menu_set_reserve_toggle_size   (menu: *mut C_GtkMenu, reserve_toggle_size: Gboolean);
+    pub fn gtk_menu_get_reserve_toggle_size   (menu: *mut C_GtkMenu) -> Gboolean;
+
+    //=========================================================================
+    // GtkMenuBar
+    //=========================================================================
+    pub fn gtk_menu_bar_new                   () -> *mut C_GtkWidget;
+    pub fn gtk_menu_bar_new_from_model        (model: *mut C_GMenuModel) -> *mut C_GtkWidget;
+
+    //=========================================================================
+    // GtkRadioMenuItem
+    //=========================================================================
+    pub fn gtk_radio_menu_item_new            (group: *mut C_GSList) -> *mut C_GtkWidget;
+    pub fn gtk_radio_menu_item_new_with_label (group: *mut C_GSList, label: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_radio_menu_item_new_with_mnemonic(group: *mut C_GSList, label: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_radio_menu_item_new_from_widget(group: *mut C_GtkRadioMenuItem) -> *mut C_GtkWidget;
+    pub fn gtk_radio_menu_item_new_with_label_from_widget(group: *mut C_GtkRadioMenuItem, label: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_radio_menu_item_new_with_mnemonic_from_widget(group: *mut C_GtkRadioMenuItem, label: *const c_char) -> *mut C_GtkWidget;
+
+    //=========================================================================
+    // GtkImageMenuItem
+    //=========================================================================
+    pub fn gtk_image_menu_item_new            () -> *mut C_GtkWidget;
+    pub fn gtk_image_menu_item_new_with_label (label: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_image_menu_item_new_with_mnemonic(label: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_image_menu_item_new_from_stock (stock_id: *const c_char, accel_group: *mut C_GtkAccelGroup) -> *mut C_GtkWidget;
+    pub fn gtk_image_menu_item_set_image      (image_menu_item: *mut C_GtkImageMenuItem, image: *mut C_GtkWidget);
+    pub fn gtk_image_menu_item_get_image      (image_menu_item: *mut C_GtkImageMenuItem) -> *mut C_GtkWidget;
+    pub fn gtk_image_menu_item_set_always_show_image(image_menu_item: *mut C_GtkImageMenuItem, always_show: Gboolean);
+    pub fn gtk_image_menu_item_get_always_show_image(image_menu_item: *mut C_GtkImageMenuItem) -> Gboolean;
+    pub fn gtk_image_menu_item_set_accel_group(image_menu_item: *mut C_GtkImageMenuItem, accel_group: *mut C_GtkAccelGroup);
+
+    //=========================================================================
+    // GtkAccelGroup
+    //=========================================================================
+    pub fn gtk_accel_group_new                () -> *mut C_GtkAccelGroup;
+    pub fn gtk_accel_group_connect            (accel_group: *mut C_GtkAccelGroup, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType, accel_flags: c_uint, closure: *mut C_GClosure);
+    pub fn gtk_accel_group_connect_by_path    (accel_group: *mut C_GtkAccelGroup, accel_path: *const c_char, closure: *mut C_GClosure);
+    pub fn gtk_accel_group_disconnect_key     (accel_group: *mut C_GtkAccelGroup, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_accel_group_activate           (accel_group: *mut C_GtkAccelGroup, accel_quark: u32, acceleratable: *mut C_GObject,
+        accel_key: c_uint, accel_mods: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_accel_group_lock               (accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_accel_group_unlock             (accel_group: *mut C_GtkAccelGroup);
+    pub fn gtk_accel_group_get_is_locked      (accel_group: *mut C_GtkAccelGroup) -> Gboolean;
+    pub fn gtk_accel_groups_activate          (object: *mut C_GObject, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_accelerator_parse              (accelerator: *const c_char, accelerator_key: *mut c_uint,
+        accelerator_mods: *mut gdk_ffi::enums::modifier_type::ModifierType);
+    pub fn gtk_accelerator_name               (accelerator_key: c_uint, accelerator_mods: gdk_ffi::enums::modifier_type::ModifierType)
+        -> *mut c_char;
+    pub fn gtk_accelerator_get_label          (accelerator_key: c_uint, accelerator_mods: gdk_ffi::enums::modifier_type::ModifierType)
+        -> *mut c_char;
+    pub fn gtk_accelerator_valid              (keyval: c_uint, modifiers: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_accelerator_get_default_mod_mask() -> gdk_ffi::enums::modifier_type::ModifierType;
+    pub fn g_cclosure_new                     (callback_func: gpointer, user_data: gpointer,
+        destroy_data: Option<extern "C" fn(gpointer, *mut C_GClosure)>) -> *mut C_GClosure;
+    pub fn g_quark_from_string                (string: *const c_char) -> u32;
+
+    //=========================================================================
+    // GtkAccelMap
+    //=========================================================================
+    pub fn gtk_accel_map_get                  () -> *mut C_GtkAccelMap;
+    pub fn gtk_accel_map_add_entry            (accel_path: *const c_char, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType);
+    pub fn gtk_accel_map_lookup_entry         (accel_path: *const c_char, key: *mut C_GtkAccelKey) -> Gboolean;
+    pub fn gtk_accel_map_change_entry         (accel_path: *const c_char, accel_key: c_uint,
+        accel_mods: gdk_ffi::enums::modifier_type::ModifierType, replace: Gboolean) -> Gboolean;
+    pub fn gtk_accel_map_load                 (file_name: *const c_char);
+    pub fn gtk_accel_map_save                 (file_name: *const c_char);
+    pub fn gtk_accel_map_foreach              (data: gpointer, foreach_func: extern "C" fn(gpointer, *const c_char, c_uint,
+        gdk_ffi::enums::modifier_type::ModifierType, Gboolean));
+    pub fn gtk_accel_map_lock_path            (accel_path: *const c_char);
+    pub fn gtk_accel_map_unlock_path          (accel_path: *const c_char);
+
     //=========================================================================
     // GtkSeparatorMenuItem
     //=========================================================================
@@ -3114,8 +3395,8 @@ extern "C" {
     //=========================================================================
     pub fn gtk_actionable_get_action_name(actionable: *mut C_GtkActionable) -> *const c_char;
     pub fn gtk_actionable_set_action_name(actionable: *mut C_GtkActionable, action_name: *const c_char);
-    //pub fn gtk_actionable_get_action_target_value(actionable: *mut C_GtkActionable) -> *mut C_GVariant;
-    //pub fn gtk_actionable_set_action_target_value(actionable: *mut C_GtkActionable, target_value: *mut C_GVariant);
+    pub fn gtk_actionable_get_action_target_value(actionable: *mut C_GtkActionable) -> *mut C_GVariant;
+    pub fn gtk_actionable_set_action_target_value(actionable: *mut C_GtkActionable, target_value: *mut C_GVariant);
     //pub fn gtk_actionable_set_action_target(actionable: *mut C_GtkActionable, format_string: *const c_char, ...);
     pub fn gtk_actionable_set_detailed_action_name(actionable: *mut C_GtkActionable, detailed_action_name: *const c_char);
 
@@ -3171,6 +3452,165 @@ extern "C" {
     //pub fn gtk_socket_get_id               (socket: *mut C_GtkSocket) -> Window;
     //pub fn gtk_socket_get_plug_window      (socket: *mut C_GtkSocket) -> *mut C_GdkWindow;
 
+    //=========================================================================
+    // GApplication
+    //=========================================================================
+    pub fn g_application_run               (application: *mut C_GApplication, argc: c_int, argv: *mut *mut c_char) -> c_int;
+    pub fn g_application_quit              (application: *mut C_GApplication);
+    pub fn g_application_hold              (application: *mut C_GApplication);
+    pub fn g_application_release           (application: *mut C_GApplication);
+    pub fn g_application_activate          (application: *mut C_GApplication);
+    pub fn g_application_get_application_id(application: *mut C_GApplication) -> *const c_char;
+    pub fn g_application_get_is_remote     (application: *mut C_GApplication) -> Gboolean;
+    pub fn g_application_get_is_registered (application: *mut C_GApplication) -> Gboolean;
+    pub fn g_application_register          (application: *mut C_GApplication, cancellable: *mut c_void,
+        error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_file_get_uri                  (file: *mut C_GFile) -> *mut c_char;
+
+    //=========================================================================
+    // GVariant
+    //=========================================================================
+    pub fn g_variant_new_boolean           (value: Gboolean) -> *mut C_GVariant;
+    pub fn g_variant_new_int32             (value: i32) -> *mut C_GVariant;
+    pub fn g_variant_new_int64             (value: i64) -> *mut C_GVariant;
+    pub fn g_variant_new_uint32            (value: u32) -> *mut C_GVariant;
+    pub fn g_variant_new_double            (value: c_double) -> *mut C_GVariant;
+    pub fn g_variant_new_string            (string: *const c_char) -> *mut C_GVariant;
+    pub fn g_variant_get_boolean           (value: *mut C_GVariant) -> Gboolean;
+    pub fn g_variant_get_int32             (value: *mut C_GVariant) -> i32;
+    pub fn g_variant_get_int64             (value: *mut C_GVariant) -> i64;
+    pub fn g_variant_get_uint32            (value: *mut C_GVariant) -> u32;
+    pub fn g_variant_get_double            (value: *mut C_GVariant) -> c_double;
+    pub fn g_variant_get_string            (value: *mut C_GVariant, length: *mut c_ulong) -> *const c_char;
+    pub fn g_variant_get_type_string       (value: *mut C_GVariant) -> *const c_char;
+    pub fn g_variant_is_of_type            (value: *mut C_GVariant, type_: *const C_GVariantType) -> Gboolean;
+    pub fn g_variant_equal                 (one: *mut C_GVariant, two: *mut C_GVariant) -> Gboolean;
+    pub fn g_variant_print                 (value: *mut C_GVariant, type_annotate: Gboolean) -> *mut c_char;
+    pub fn g_variant_parse                 (type_: *const C_GVariantType, text: *const c_char, limit: *const c_char,
+        endptr: *mut *const c_char, error: *mut *mut C_GError) -> *mut C_GVariant;
+    pub fn g_variant_parse_error_quark     () -> u32;
+    pub fn g_error_new_literal             (domain: u32, code: c_int, message: *const c_char) -> *mut C_GError;
+    pub fn g_variant_ref                   (value: *mut C_GVariant) -> *mut C_GVariant;
+    pub fn g_variant_take_ref              (value: *mut C_GVariant) -> *mut C_GVariant;
+    pub fn g_variant_unref                 (value: *mut C_GVariant);
+    pub fn g_variant_type_string_is_valid  (type_string: *const c_char) -> Gboolean;
+    pub fn g_variant_type_dup_string       (type_: *const C_GVariantType) -> *mut c_char;
+
+    //=========================================================================
+    // GAction, GActionGroup and GActionMap
+    //=========================================================================
+    pub fn g_action_get_name               (action: *mut C_GAction) -> *const c_char;
+    pub fn g_action_get_parameter_type     (action: *mut C_GAction) -> *const C_GVariantType;
+    pub fn g_action_get_state_type         (action: *mut C_GAction) -> *const C_GVariantType;
+    pub fn g_action_get_enabled            (action: *mut C_GAction) -> Gboolean;
+    pub fn g_action_get_state              (action: *mut C_GAction) -> *mut C_GVariant;
+    pub fn g_action_change_state           (action: *mut C_GAction, value: *mut C_GVariant);
+    pub fn g_action_activate               (action: *mut C_GAction, parameter: *mut C_GVariant);
+    pub fn g_action_group_has_action       (action_group: *mut C_GActionGroup, action_name: *const c_char) -> Gboolean;
+    pub fn g_action_group_list_actions     (action_group: *mut C_GActionGroup) -> *mut *mut c_char;
+    pub fn g_action_group_activate_action  (action_group: *mut C_GActionGroup, action_name: *const c_char,
+        parameter: *mut C_GVariant);
+    pub fn g_action_group_change_action_state(action_group: *mut C_GActionGroup, action_name: *const c_char,
+        value: *mut C_GVariant);
+    pub fn g_action_map_lookup_action      (action_map: *mut C_GActionMap, action_name: *const c_char) -> *mut C_GAction;
+    pub fn g_action_map_add_action         (action_map: *mut C_GActionMap, action: *mut C_GAction);
+    pub fn g_action_map_remove_action      (action_map: *mut C_GActionMap, action_name: *const c_char);
+
+    //=========================================================================
+    // GSimpleAction
+    //=========================================================================
+    pub fn g_simple_action_get_type        () -> GType;
+    pub fn g_simple_action_new             (name: *const c_char, parameter_type: *const C_GVariantType) -> *mut C_GSimpleAction;
+    pub fn g_simple_action_new_stateful    (name: *const c_char, parameter_type: *const C_GVariantType,
+        state: *mut C_GVariant) -> *mut C_GSimpleAction;
+    pub fn g_simple_action_set_enabled     (simple: *mut C_GSimpleAction, enabled: Gboolean);
+    pub fn g_simple_action_set_state       (simple: *mut C_GSimpleAction, value: *mut C_GVariant);
+
+    //=========================================================================
+    // GSimpleActionGroup
+    //=========================================================================
+    pub fn g_simple_action_group_new       () -> *mut C_GSimpleActionGroup;
+
+    //=========================================================================
+    // GMenuModel
+    //=========================================================================
+    pub fn g_menu_model_is_mutable         (model: *mut C_GMenuModel) -> Gboolean;
+    pub fn g_menu_model_get_n_items        (model: *mut C_GMenuModel) -> c_int;
+    pub fn g_menu_model_get_item_attribute_value(model: *mut C_GMenuModel, item_index: c_int, attribute: *const c_char,
+        expected_type: *const C_GVariantType) -> *mut C_GVariant;
+    pub fn g_menu_model_get_item_link      (model: *mut C_GMenuModel, item_index: c_int, link: *const c_char) -> *mut C_GMenuModel;
+
+    //=========================================================================
+    // GMenu
+    //=========================================================================
+    pub fn g_menu_new                      () -> *mut C_GMenu;
+    pub fn g_menu_freeze                   (menu: *mut C_GMenu);
+    pub fn g_menu_insert                   (menu: *mut C_GMenu, position: c_int, label: *const c_char, detailed_action: *const c_char);
+    pub fn g_menu_prepend                  (menu: *mut C_GMenu, label: *const c_char, detailed_action: *const c_char);
+    pub fn g_menu_append                   (menu: *mut C_GMenu, label: *const c_char, detailed_action: *const c_char);
+    pub fn g_menu_insert_item              (menu: *mut C_GMenu, position: c_int, item: *mut C_GMenuItem);
+    pub fn g_menu_append_item              (menu: *mut C_GMenu, item: *mut C_GMenuItem);
+    pub fn g_menu_prepend_item             (menu: *mut C_GMenu, item: *mut C_GMenuItem);
+    pub fn g_menu_insert_section           (menu: *mut C_GMenu, position: c_int, label: *const c_char, section: *mut C_GMenuModel);
+    pub fn g_menu_prepend_section          (menu: *mut C_GMenu, label: *const c_char, section: *mut C_GMenuModel);
+    pub fn g_menu_append_section           (menu: *mut C_GMenu, label: *const c_char, section: *mut C_GMenuModel);
+    pub fn g_menu_insert_submenu           (menu: *mut C_GMenu, position: c_int, label: *const c_char, submenu: *mut C_GMenuModel);
+    pub fn g_menu_prepend_submenu          (menu: *mut C_GMenu, label: *const c_char, submenu: *mut C_GMenuModel);
+    pub fn g_menu_append_submenu           (menu: *mut C_GMenu, label: *const c_char, submenu: *mut C_GMenuModel);
+    pub fn g_menu_remove                   (menu: *mut C_GMenu, position: c_int);
+
+    //=========================================================================
+    // GMenuItem
+    //=========================================================================
+    pub fn g_menu_item_new                 (label: *const c_char, detailed_action: *const c_char) -> *mut C_GMenuItem;
+    pub fn g_menu_item_new_section         (label: *const c_char, section: *mut C_GMenuModel) -> *mut C_GMenuItem;
+    pub fn g_menu_item_new_submenu         (label: *const c_char, submenu: *mut C_GMenuModel) -> *mut C_GMenuItem;
+    pub fn g_menu_item_set_label           (menu_item: *mut C_GMenuItem, label: *const c_char);
+    pub fn g_menu_item_set_detailed_action (menu_item: *mut C_GMenuItem, detailed_action: *const c_char);
+    pub fn g_menu_item_set_action_and_target_value(menu_item: *mut C_GMenuItem, action: *const c_char,
+        target_value: *mut C_GVariant);
+    pub fn g_menu_item_set_attribute_value (menu_item: *mut C_GMenuItem, attribute: *const c_char, value: *mut C_GVariant);
+    pub fn g_menu_item_get_attribute_value (menu_item: *mut C_GMenuItem, attribute: *const c_char,
+        expected_type: *const C_GVariantType) -> *mut C_GVariant;
+    pub fn g_menu_item_set_link            (menu_item: *mut C_GMenuItem, link: *const c_char, model: *mut C_GMenuModel);
+    pub fn g_menu_item_get_link            (menu_item: *mut C_GMenuItem, link: *const c_char) -> *mut C_GMenuModel;
+    pub fn g_menu_item_set_section         (menu_item: *mut C_GMenuItem, section: *mut C_GMenuModel);
+    pub fn g_menu_item_set_submenu         (menu_item: *mut C_GMenuItem, submenu: *mut C_GMenuModel);
+
+    //=========================================================================
+    // GtkApplication
+    //=========================================================================
+    pub fn gtk_application_new             (application_id: *const c_char, flags: c_uint) -> *mut C_GtkApplication;
+    pub fn gtk_application_add_window      (application: *mut C_GtkApplication, window: *mut C_GtkWindow);
+    pub fn gtk_application_remove_window   (application: *mut C_GtkApplication, window: *mut C_GtkWindow);
+    pub fn gtk_application_get_windows     (application: *mut C_GtkApplication) -> *mut C_GList;
+    pub fn gtk_application_get_window_by_id(application: *mut C_GtkApplication, id: c_uint) -> *mut C_GtkWindow;
+    pub fn gtk_application_get_active_window(application: *mut C_GtkApplication) -> *mut C_GtkWindow;
+    pub fn gtk_application_inhibit         (application: *mut C_GtkApplication, window: *mut C_GtkWindow, flags: c_uint,
+        reason: *const c_char) -> c_uint;
+    pub fn gtk_application_uninhibit       (application: *mut C_GtkApplication, cookie: c_uint);
+    pub fn gtk_application_is_inhibited    (application: *mut C_GtkApplication, flags: c_uint) -> Gboolean;
+    pub fn gtk_application_get_app_menu    (application: *mut C_GtkApplication) -> *mut C_GMenuModel;
+    pub fn gtk_application_set_app_menu    (application: *mut C_GtkApplication, app_menu: *mut C_GMenuModel);
+    pub fn gtk_application_get_menubar     (application: *mut C_GtkApplication) -> *mut C_GMenuModel;
+    pub fn gtk_application_set_menubar     (application: *mut C_GtkApplication, menubar: *mut C_GMenuModel);
+    pub fn gtk_application_set_accels_for_action(application: *mut C_GtkApplication, detailed_action_name: *const c_char,
+        accels: *const *const c_char);
+    pub fn gtk_application_get_accels_for_action(application: *mut C_GtkApplication, detailed_action_name: *const c_char)
+        -> *mut *mut c_char;
+    pub fn gtk_application_list_action_descriptions(application: *mut C_GtkApplication) -> *mut *mut c_char;
+
+    //=========================================================================
+    // GtkApplicationWindow
+    //=========================================================================
+    pub fn gtk_application_window_new      (application: *mut C_GtkApplication) -> *mut C_GtkWidget;
+    pub fn gtk_application_window_set_show_menubar(window: *mut C_GtkApplicationWindow, show_menubar: Gboolean);
+    pub fn gtk_application_window_get_show_menubar(window: *mut C_GtkApplicationWindow) -> Gboolean;
+    pub fn gtk_application_window_get_id   (window: *mut C_GtkApplicationWindow) -> c_uint;
+    pub fn gtk_window_set_application      (window: *mut C_GtkWindow, application: *mut C_GtkApplication);
+    pub fn gtk_window_get_application      (window: *mut C_GtkWindow) -> *mut C_GtkApplication;
+    pub fn g_list_copy                     (list: *mut C_GList) -> *mut C_GList;
+
     //=========================================================================
     // GtkEventBox                                                       NOT OK
     //=========================================================================
@@ -3180,6 +3620,67 @@ extern "C" {
     pub fn gtk_event_box_set_visible_window(event_box: *mut C_GtkEventBox, visible_window: Gboolean);
     pub fn gtk_event_box_get_visible_window(event_box: *mut C_GtkEventBox) -> Gboolean;
 
+    //=========================================================================
+    // GtkOffscreenWindow
+    //=========================================================================
+    pub fn gtk_offscreen_window_new        () -> *mut C_GtkWidget;
+
+    //=========================================================================
+    // GtkSelectionData                                                  NOT OK
+    //=========================================================================
+    pub fn gtk_selection_data_set_text     (selection_data: *mut C_GtkSelectionData, str_: *const c_char, len: c_int) -> Gboolean;
+    pub fn gtk_selection_data_get_text     (selection_data: *const C_GtkSelectionData) -> *mut c_char;
+    pub fn gtk_selection_data_get_length   (selection_data: *const C_GtkSelectionData) -> c_int;
+    pub fn gtk_selection_data_set          (selection_data: *mut C_GtkSelectionData, type_: *mut c_void, format: c_int,
+        data: *const u8, length: c_int);
+    pub fn gtk_selection_data_get_data     (selection_data: *const C_GtkSelectionData) -> *const u8;
+    pub fn gtk_selection_data_get_target   (selection_data: *const C_GtkSelectionData) -> *mut c_void;
+    pub fn gtk_selection_data_get_format   (selection_data: *const C_GtkSelectionData) -> c_int;
+    pub fn gtk_selection_data_set_pixbuf   (selection_data: *mut C_GtkSelectionData, pixbuf: *mut gdk_ffi::C_GdkPixbuf) -> Gboolean;
+    pub fn gtk_selection_data_get_pixbuf   (selection_data: *const C_GtkSelectionData) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gdk_atom_intern                 (atom_name: *const c_char, only_if_exists: Gboolean) -> *mut c_void;
+    pub fn gdk_atom_name                   (atom: *mut c_void) -> *mut c_char;
+
+    //=========================================================================
+    // GtkClipboard
+    //=========================================================================
+    pub fn gtk_clipboard_get               (selection: *mut c_void) -> *mut C_GtkClipboard;
+    pub fn gtk_clipboard_set_with_data     (clipboard: *mut C_GtkClipboard, targets: *const C_GtkTargetEntry, n_targets: c_uint,
+        get_func: extern "C" fn(*mut C_GtkClipboard, *mut C_GtkSelectionData, c_uint, gpointer),
+        clear_func: extern "C" fn(*mut C_GtkClipboard, gpointer), user_data: gpointer) -> Gboolean;
+    pub fn gtk_clipboard_clear             (clipboard: *mut C_GtkClipboard);
+    pub fn gtk_clipboard_set_text          (clipboard: *mut C_GtkClipboard, text: *const c_char, len: c_int);
+    pub fn gtk_clipboard_set_image         (clipboard: *mut C_GtkClipboard, pixbuf: *mut gdk_ffi::C_GdkPixbuf);
+    pub fn gtk_clipboard_request_text      (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *const c_char, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_image     (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut gdk_ffi::C_GdkPixbuf, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_request_targets   (clipboard: *mut C_GtkClipboard,
+        callback: extern "C" fn(*mut C_GtkClipboard, *mut *mut c_void, c_int, gpointer), user_data: gpointer);
+    pub fn gtk_clipboard_wait_for_text     (clipboard: *mut C_GtkClipboard) -> *mut c_char;
+    pub fn gtk_clipboard_wait_for_image    (clipboard: *mut C_GtkClipboard) -> *mut gdk_ffi::C_GdkPixbuf;
+    pub fn gtk_clipboard_wait_for_targets  (clipboard: *mut C_GtkClipboard, targets: *mut *mut *mut c_void,
+        n_targets: *mut c_int) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_text_available(clipboard: *mut C_GtkClipboard) -> Gboolean;
+    pub fn gtk_clipboard_wait_is_image_available(clipboard: *mut C_GtkClipboard) -> Gboolean;
+    pub fn gtk_clipboard_set_can_store     (clipboard: *mut C_GtkClipboard, targets: *const C_GtkTargetEntry, n_targets: c_int);
+    pub fn gtk_clipboard_store             (clipboard: *mut C_GtkClipboard);
+    pub fn gtk_tree_set_row_drag_data      (selection_data: *mut C_GtkSelectionData, tree_model: *mut C_GtkTreeModel,
+        path: *mut C_GtkTreePath) -> Gboolean;
+    pub fn gtk_tree_get_row_drag_data      (selection_data: *mut C_GtkSelectionData, tree_model: *mut *mut C_GtkTreeModel,
+        path: *mut *mut C_GtkTreePath) -> Gboolean;
+
+    //=========================================================================
+    // Drag and Drop                                                     NOT OK
+    //=========================================================================
+    pub fn gtk_drag_finish                 (context: *mut gdk_ffi::C_GdkDragContext, success: Gboolean, del: Gboolean, time_: u32);
+
+    //=========================================================================
+    // GtkTooltip                                                        NOT OK
+    //=========================================================================
+    pub fn gtk_tooltip_set_markup          (tooltip: *mut C_GtkTooltip, markup: *const c_char);
+    pub fn gtk_tooltip_set_text            (tooltip: *mut C_GtkTooltip, text: *const c_char);
+
     //=========================================================================
     // Glue fixe code
     //=========================================================================
@@ -3189,7 +3690,83 @@ extern "C" {
                                  data: gpointer,
                                  destroy_data: Option<extern "C" fn(gpointer, *const C_GClosure)>,
                                  connect_flags: i32);
-
+    pub fn g_signal_stop_emission_by_name(instance: gpointer, detailed_signal: *const c_char);
+    pub fn g_signal_lookup(name: *const c_char, itype: GType) -> c_uint;
+    pub fn g_signal_add_emission_hook(signal_id: c_uint, detail: u32, hook_func: GSignalEmissionHook, hook_data: gpointer,
+                                      data_destroy: GDestroyNotify) -> c_ulong;
+    pub fn g_signal_remove_emission_hook(signal_id: c_uint, hook_id: c_ulong);
+    pub fn g_type_class_ref(_type: GType) -> gpointer;
+    pub fn g_type_class_unref(g_class: gpointer);
+    pub fn g_value_get_object(value: *const C_GValue) -> gpointer;
+    pub fn g_value_get_boxed(value: *const C_GValue) -> gpointer;
+    pub fn g_object_set_data(object: *mut C_GObject, key: *const c_char, data: gpointer);
+    pub fn g_object_get_data(object: *mut C_GObject, key: *const c_char) -> gpointer;
+    pub fn g_object_set_data_full(object: *mut C_GObject, key: *const c_char, data: gpointer, destroy: GDestroyNotify);
+    pub fn g_object_get_property(object: *mut C_GObject, property_name: *const c_char, value: *mut C_GValue);
+    pub fn g_strdup_value_contents(value: *const C_GValue) -> *mut c_char;
+    pub fn g_value_get_string(value: *const C_GValue) -> *const c_char;
+    pub fn g_value_transform(src_value: *const C_GValue, dest_value: *mut C_GValue) -> Gboolean;
+    // mode is a GNormalizeMode
+    pub fn g_utf8_normalize(str: *const c_char, len: isize, mode: c_int) -> *mut c_char;
+    pub fn g_utf8_casefold(str: *const c_char, len: isize) -> *mut c_char;
+    pub fn glue_object_type(object: *mut C_GObject) -> GType;
+    pub fn glue_object_property_type(object: *mut C_GObject, property_name: *const c_char) -> GType;
+    pub fn glue_container_child_property_type(container: *mut C_GtkContainer, property_name: *const c_char) -> GType;
+    pub fn glue_menu_popup_at_widget(menu: *mut C_GtkMenu, widget: *mut C_GtkWidget, button: c_uint, activate_time: u32);
+
+    //=========================================================================
+    // GKeyFile                                                          NOT OK
+    //=========================================================================
+    pub fn g_key_file_new                  () -> *mut C_GKeyFile;
+    pub fn g_key_file_free                 (key_file: *mut C_GKeyFile);
+    pub fn g_key_file_load_from_file       (key_file: *mut C_GKeyFile, file: *const c_char, flags: c_int, error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_key_file_load_from_data       (key_file: *mut C_GKeyFile, data: *const c_char, length: c_ulong, flags: c_int,
+        error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_key_file_to_data              (key_file: *mut C_GKeyFile, length: *mut c_ulong, error: *mut *mut C_GError) -> *mut c_char;
+    pub fn g_key_file_has_group            (key_file: *mut C_GKeyFile, group_name: *const c_char) -> Gboolean;
+    pub fn g_key_file_has_key              (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_key_file_remove_group         (key_file: *mut C_GKeyFile, group_name: *const c_char, error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_key_file_get_string           (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        error: *mut *mut C_GError) -> *mut c_char;
+    pub fn g_key_file_set_string           (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char, string: *const c_char);
+    pub fn g_key_file_get_string_list      (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        length: *mut c_ulong, error: *mut *mut C_GError) -> *mut *mut c_char;
+    pub fn g_key_file_set_string_list      (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        list: *const *const c_char, length: c_ulong);
+    pub fn g_key_file_get_boolean          (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        error: *mut *mut C_GError) -> Gboolean;
+    pub fn g_key_file_set_boolean          (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char, value: Gboolean);
+    pub fn g_key_file_get_integer          (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        error: *mut *mut C_GError) -> c_int;
+    pub fn g_key_file_set_integer          (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char, value: c_int);
+    pub fn g_key_file_get_double           (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char,
+        error: *mut *mut C_GError) -> c_double;
+    pub fn g_key_file_set_double           (key_file: *mut C_GKeyFile, group_name: *const c_char, key: *const c_char, value: c_double);
+
+
+    //=========================================================================
+    // GtkTesting
+    //=========================================================================
+    pub fn gtk_test_widget_send_key            (widget: *mut C_GtkWidget, keyval: c_uint, modifiers: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_test_widget_click               (widget: *mut C_GtkWidget, button: c_uint, modifiers: gdk_ffi::enums::modifier_type::ModifierType) -> Gboolean;
+    pub fn gtk_test_slider_set_perc            (widget: *mut C_GtkWidget, percentage: c_double);
+    pub fn gtk_test_slider_get_value           (widget: *mut C_GtkWidget) -> c_double;
+    pub fn gtk_test_spin_button_click          (spinner: *mut C_GtkSpinButton, button: c_uint, upwards: Gboolean) -> Gboolean;
+    pub fn gtk_test_text_set                   (widget: *mut C_GtkWidget, string: *const c_char);
+    pub fn gtk_test_text_get                   (widget: *mut C_GtkWidget) -> *mut c_char;
+    pub fn gtk_test_find_label                 (widget: *mut C_GtkWidget, label_pattern: *const c_char) -> *mut C_GtkWidget;
+    pub fn gtk_test_find_sibling               (base_widget: *mut C_GtkWidget, widget_type: GType) -> *mut C_GtkWidget;
+    pub fn gtk_test_find_widget                (widget: *mut C_GtkWidget, label_pattern: *const c_char, widget_type: GType) -> *mut C_GtkWidget;
+    pub fn gtk_test_widget_wait_for_draw       (widget: *mut C_GtkWidget);
+    pub fn gdk_test_simulate_key               (window: *mut gdk_ffi::C_GdkWindow, x: c_int, y: c_int, keyval: c_uint,
+        modifiers: gdk_ffi::enums::modifier_type::ModifierType, key_pressrelease: gdk_ffi::enums::event_type::EventType) -> Gboolean;
+    pub fn gdk_test_simulate_button            (window: *mut gdk_ffi::C_GdkWindow, x: c_int, y: c_int, button: c_uint,
+        modifiers: gdk_ffi::enums::modifier_type::ModifierType, button_pressrelease: gdk_ffi::enums::event_type::EventType) -> Gboolean;
+    pub fn gdk_test_render_sync                (window: *mut gdk_ffi::C_GdkWindow);
+    pub fn gdk_unicode_to_keyval               (wc: u32) -> c_uint;
+    pub fn g_get_monotonic_time                () -> i64;
+    pub fn g_usleep                            (microseconds: c_ulong);
 
     // Not useful to implement but functions are declared at least...
     //=========================================================================
@@ -3202,9 +3779,9 @@ extern "C" {
     //pub fn gtk_builder_add_callback_symbol     (builder: *mut C_GtkBuilder, callback_name: *const c_char, callback_symbol: GCallback);
     //pub fn gtk_builder_add_callback_symbols    (builder: *mut C_GtkBuilder, callback_name: *const c_char, first_callback_symbol: GCallback, ...);
     //pub fn gtk_builder_lookup_callback_symbol  (builder: *mut C_GtkBuilder, callback_name: *const c_char) -> GCallback;
-    //pub fn gtk_builder_add_from_file           (builder: *mut C_GtkBuilder, file_name: *const c_char, error: *mut *mut C_GError) -> c_uint;
+    pub fn gtk_builder_add_from_file           (builder: *mut C_GtkBuilder, file_name: *const c_char, error: *mut *mut C_GError) -> c_uint;
     //pub fn gtk_builder_add_from_resource       (builder: *mut C_GtkBuilder, resource_name: *const c_char, error: *mut *mut C_GError) -> c_uint;
-    //pub fn gtk_builder_add_from_string         (builder: *mut C_GtkBuilder, buffer: *const c_char, length: c_long, error: *mut *mut C_GError) -> c_uint;
+    pub fn gtk_builder_add_from_string         (builder: *mut C_GtkBuilder, buffer: *const c_char, length: c_long, error: *mut *mut C_GError) -> c_uint;
     //pub fn gtk_builder_add_objects_from_file   (builder: *mut C_GtkBuilder, file_name: *const c_char, object_ids: *mut *mut c_char, error: *mut *mut C_GError) -> c_uint;
     //pub fn gtk_builder_add_objects_from_string (builder: *mut C_GtkBuilder, buffer: *const c_char, length: c_long, object_ids: *mut *mut c_char, error: *mut *mut C_GError) -> c_uint;
     //pub fn gtk_builder_add_objects_from_resource(builder: *mut C_GtkBuilder, resource_name: *const c_char, object_ids: *mut *mut c_char, error: *mut *mut C_GError) -> c_uint;
@@ -3241,6 +3818,7 @@ extern "C" {
     pub fn cast_GtkBin(widget: *mut C_GtkWidget) -> *mut C_GtkBin;
     pub fn cast_GtkButton(widget: *mut C_GtkWidget) -> *mut C_GtkButton;
     pub fn cast_GtkContainer(widget: *mut C_GtkWidget) -> *mut C_GtkContainer;
+    pub fn is_GtkContainer(widget: *mut C_GtkWidget) -> Gboolean;
     pub fn cast_GtkFrame(widget: *mut C_GtkWidget) -> *mut C_GtkFrame;
     pub fn cast_GtkLabel(widget: *mut C_GtkWidget) -> *mut C_GtkLabel;
     pub fn cast_GtkMisc(widget: *mut C_GtkWidget) -> *mut C_GtkMisc;
@@ -3326,6 +3904,10 @@ extern "C" {
     pub fn cast_GtkCellRenderer(widget: *mut C_GtkWidget) -> *mut C_GtkCellRenderer;
     pub fn cast_GtkMenuShell(widget: *mut C_GtkWidget) -> *mut C_GtkMenuShell;
     pub fn cast_GtkMenuItem(widget: *mut C_GtkWidget) -> *mut C_GtkMenuItem;
+    pub fn cast_GtkMenu(widget: *mut C_GtkWidget) -> *mut C_GtkMenu;
+    pub fn cast_GtkMenuBar(widget: *mut C_GtkWidget) -> *mut C_GtkMenuBar;
+    pub fn cast_GtkRadioMenuItem(widget: *mut C_GtkWidget) -> *mut C_GtkRadioMenuItem;
+    pub fn cast_GtkImageMenuItem(widget: *mut C_GtkWidget) -> *mut C_GtkImageMenuItem;
     pub fn cast_GtkCheckMenuItem(widget: *mut C_GtkWidget) -> *mut C_GtkCheckMenuItem;
     pub fn cast_GtkViewport(widget: *mut C_GtkWidget) -> *mut C_GtkViewport;
     pub fn cast_GtkStatusbar(widget: *mut C_GtkWidget) -> *mut C_GtkStatusbar;
@@ -3343,13 +3925,17 @@ extern "C" {
     pub fn cast_GtkTreeModelFromListStore(store: *mut C_GtkListStore) -> *mut C_GtkTreeModel;
     pub fn cast_GtkListStoreFromTreeModel(store: *mut C_GtkTreeModel) -> *mut C_GtkListStore;
     pub fn cast_GtkListStore(store: *mut C_GObject) -> *mut C_GtkListStore;
+    pub fn is_GtkListStore(model: *mut C_GtkTreeModel) -> Gboolean;
     pub fn cast_GtkTreeModelFromTreeStore(store: *mut C_GtkTreeStore) -> *mut C_GtkTreeModel;
     pub fn cast_GtkTreeStoreFromTreeModel(store: *mut C_GtkTreeModel) -> *mut C_GtkTreeStore;
     pub fn cast_GtkComboBox(widget: *mut C_GtkWidget) -> *mut C_GtkComboBox;
     pub fn cast_GtkPopover(widget: *mut C_GtkWidget) -> *mut C_GtkPopover;
     pub fn cast_GtkComboBoxText(widget: *mut C_GtkWidget) -> *mut C_GtkComboBoxText;
     pub fn cast_GtkTreeStore(obj: *mut C_GObject) -> *mut C_GtkTreeStore;
+    pub fn is_GtkTreeStore(model: *mut C_GtkTreeModel) -> Gboolean;
     pub fn cast_GtkTreeModel(obj: *mut C_GObject) -> *mut C_GtkTreeModel;
+    pub fn cast_GtkTreeSortable(model: *mut C_GtkTreeModel) -> *mut C_GtkTreeSortable;
+    pub fn is_GtkTreeSortable(model: *mut C_GtkTreeModel) -> Gboolean;
     pub fn cast_GtkTextMark(widget: *mut C_GObject) -> *mut C_GtkTextMark;
     pub fn cast_GtkPlacesSidebar(widget: *mut C_GtkWidget) -> *mut C_GtkPlacesSidebar;
     pub fn cast_GtkToolPalette(widget: *mut C_GtkWidget) -> *mut C_GtkToolPalette;
@@ -3360,4 +3946,5 @@ extern "C" {
     pub fn cast_GtkFontChooserWidget(widget: *mut C_GtkWidget) -> *mut C_GtkFontChooserWidget;
     pub fn cast_GtkSocket(widget: *mut C_GtkWidget) -> *mut C_GtkSocket;
     pub fn cast_GtkEventBox(widget: *mut C_GtkWidget) -> *mut C_GtkEventBox;
+    pub fn cast_GtkApplicationWindow(widget: *mut C_GtkWidget) -> *mut C_GtkApplicationWindow;
 }
@@ -46,6 +46,10 @@ pub fn GTK_CONTAINER(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkContainer
     unsafe { ffi::cast_GtkContainer(widget) }
 }
 
+pub fn GTK_IS_CONTAINER(widget: *mut ffi::C_GtkWidget) -> bool {
+    unsafe { ::glib::to_bool(ffi::is_GtkContainer(widget)) }
+}
+
 pub fn GTK_FRAME(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkFrame {
     unsafe { ffi::cast_GtkFrame(widget) }
 }
@@ -404,6 +408,18 @@ pub fn GTK_CHECK_MENU_ITEM(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkChec
     unsafe { ffi::cast_GtkCheckMenuItem(widget) }
 }
 
+pub fn GTK_MENU(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkMenu {
+    unsafe { ffi::cast_GtkMenu(widget) }
+}
+
+pub fn GTK_RADIO_MENU_ITEM(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkRadioMenuItem {
+    unsafe { ffi::cast_GtkRadioMenuItem(widget) }
+}
+
+pub fn GTK_IMAGE_MENU_ITEM(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkImageMenuItem {
+    unsafe { ffi::cast_GtkImageMenuItem(widget) }
+}
+
 pub fn GTK_VIEWPORT(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkViewport {
     unsafe { ffi::cast_GtkViewport(widget) }
 }
@@ -464,6 +480,10 @@ pub fn GTK_LIST_STORE(model: *mut ::glib::ffi::C_GObject) -> *mut ffi::C_GtkList
     unsafe { ffi::cast_GtkListStore(model) }
 }
 
+pub fn GTK_IS_LIST_STORE(model: *mut ffi::C_GtkTreeModel) -> bool {
+    unsafe { ::glib::to_bool(ffi::is_GtkListStore(model)) }
+}
+
 pub fn GTK_TREE_MODEL_FROM_TREE_STORE(store: *mut ffi::C_GtkTreeStore) -> *mut ffi::C_GtkTreeModel {
     unsafe { ffi::cast_GtkTreeModelFromTreeStore(store) }
 }
@@ -476,10 +496,22 @@ pub fn GTK_TREE_STORE(obj: *mut ::glib::ffi::C_GObject) -> *mut ffi::C_GtkTreeSt
     unsafe { ffi::cast_GtkTreeStore(obj) }
 }
 
+pub fn GTK_IS_TREE_STORE(model: *mut ffi::C_GtkTreeModel) -> bool {
+    unsafe { ::glib::to_bool(ffi::is_GtkTreeStore(model)) }
+}
+
 pub fn GTK_TREE_MODEL(obj: *mut ::glib::ffi::C_GObject) -> *mut ffi::C_GtkTreeModel {
     unsafe { ffi::cast_GtkTreeModel(obj) }
 }
 
+pub fn GTK_TREE_SORTABLE(model: *mut ffi::C_GtkTreeModel) -> *mut ffi::C_GtkTreeSortable {
+    unsafe { ffi::cast_GtkTreeSortable(model) }
+}
+
+pub fn GTK_IS_TREE_SORTABLE(model: *mut ffi::C_GtkTreeModel) -> bool {
+    unsafe { ::glib::to_bool(ffi::is_GtkTreeSortable(model)) }
+}
+
 pub fn GTK_COMBO_BOX(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkComboBox {
     unsafe { ffi::cast_GtkComboBox(widget) }
 }
@@ -537,4 +569,8 @@ pub fn GTK_SOCKET(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkSocket {
 
 pub fn GTK_EVENT_BOX(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkEventBox {
     unsafe { ffi::cast_GtkEventBox(widget) }
+}
+
+pub fn GTK_APPLICATION_WINDOW(widget: *mut ffi::C_GtkWidget) -> *mut ffi::C_GtkApplicationWindow {
+    unsafe { ffi::cast_GtkApplicationWindow(widget) }
 }
\ No newline at end of file
@@ -0,0 +1,301 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Description of a live widget tree, for debugging and snapshot tests
+
+use glib::translate::{from_glib_full, from_glib_none, ToGlib, ToGlibPtr};
+use glib::{Type, Value};
+use glib_ffi;
+use gdk;
+use ffi;
+use cast::G_OBJECT;
+use {Container, ContainerTrait, Widget, WidgetTrait};
+
+/// The properties described when a widget has them.
+const KEY_PROPERTIES: [&'static str; 6] = ["label", "text", "title", "active", "value", "orientation"];
+
+/// The state of a widget and of its children
+pub struct WidgetInfo {
+    pub type_name: String,
+    /// None if no name was set, GTK then uses the type name.
+    pub name: Option<String>,
+    pub visible: bool,
+    pub sensitive: bool,
+    pub allocation: gdk::Rectangle,
+    pub style_classes: Vec<String>,
+    /// The key properties the widget has, with their value as text.
+    pub properties: Vec<(String, String)>,
+    pub children: Vec<WidgetInfo>
+}
+
+impl WidgetInfo {
+    /// Describes `widget` and, recursively, its non-internal children.
+    pub fn new<T: WidgetTrait>(widget: &T) -> WidgetInfo {
+        let type_name = get_type_name(widget);
+        let name = widget.get_name().and_then(|name| if name == type_name { None } else { Some(name) });
+
+        WidgetInfo {
+            name: name,
+            visible: widget.get_visible(),
+            sensitive: widget.get_sensitive(),
+            allocation: widget.get_allocation(),
+            style_classes: widget.get_style_classes(),
+            properties: KEY_PROPERTIES.iter()
+                .filter_map(|&property| get_property(widget, property).map(|value| (property.to_owned(), value)))
+                .collect(),
+            children: get_children(widget).iter().map(|child| WidgetInfo::new(child)).collect(),
+            type_name: type_name
+        }
+    }
+
+    /// One widget per line, children are indented by two spaces:
+    ///
+    /// `GtkButton#save visible sensitive 10,20 80x30 .text-button label="Save"`
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+
+        self.write_text(&mut text, 0);
+        text
+    }
+
+    /// An indented JSON object, children are in the "children" array.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+
+        self.write_json(&mut json, 0);
+        json.push('\n');
+        json
+    }
+
+    fn write_text(&self, text: &mut String, depth: usize) {
+        push_indent(text, depth);
+        text.push_str(&self.type_name);
+        if let Some(ref name) = self.name {
+            text.push('#');
+            text.push_str(name);
+        }
+        text.push_str(if self.visible { " visible" } else { " hidden" });
+        text.push_str(if self.sensitive { " sensitive" } else { " insensitive" });
+        text.push_str(&format!(" {},{} {}x{}", self.allocation.x, self.allocation.y, self.allocation.width,
+                               self.allocation.height));
+        for class_name in self.style_classes.iter() {
+            text.push_str(" .");
+            text.push_str(class_name);
+        }
+        for &(ref property, ref value) in self.properties.iter() {
+            text.push_str(&format!(" {}={:?}", property, value));
+        }
+        text.push('\n');
+
+        for child in self.children.iter() {
+            child.write_text(text, depth + 1);
+        }
+    }
+
+    fn write_json(&self, json: &mut String, depth: usize) {
+        json.push_str("{\n");
+        push_indent(json, depth + 1);
+        json.push_str(&format!("\"type\": {},\n", json_string(&self.type_name)));
+        push_indent(json, depth + 1);
+        json.push_str(&format!("\"name\": {},\n", self.name.as_ref().map_or("null".to_owned(), |n| json_string(n))));
+        push_indent(json, depth + 1);
+        json.push_str(&format!("\"visible\": {},\n", self.visible));
+        push_indent(json, depth + 1);
+        json.push_str(&format!("\"sensitive\": {},\n", self.sensitive));
+        push_indent(json, depth + 1);
+        json.push_str(&format!("\"allocation\": {{\"x\": {}, \"y\": {}, \"width\": {}, \"height\": {}}},\n",
+                               self.allocation.x, self.allocation.y, self.allocation.width, self.allocation.height));
+        push_indent(json, depth + 1);
+        let classes: Vec<String> = self.style_classes.iter().map(|c| json_string(c)).collect();
+        json.push_str(&format!("\"style_classes\": [{}],\n", classes.join(", ")));
+        push_indent(json, depth + 1);
+        let properties: Vec<String> = self.properties.iter()
+            .map(|&(ref property, ref value)| format!("{}: {}", json_string(property), json_string(value)))
+            .collect();
+        json.push_str(&format!("\"properties\": {{{}}},\n", properties.join(", ")));
+        push_indent(json, depth + 1);
+        if self.children.is_empty() {
+            json.push_str("\"children\": []\n");
+        } else {
+            json.push_str("\"children\": [\n");
+            for (i, child) in self.children.iter().enumerate() {
+                push_indent(json, depth + 2);
+                child.write_json(json, depth + 2);
+                json.push_str(if i + 1 < self.children.len() { ",\n" } else { "\n" });
+            }
+            push_indent(json, depth + 1);
+            json.push_str("]\n");
+        }
+        push_indent(json, depth);
+        json.push('}');
+    }
+}
+
+/// Returns the GType name of the widget, e.g. "GtkButton".
+pub fn get_type_name<T: WidgetTrait>(widget: &T) -> String {
+    let type_name: Option<String> = unsafe { from_glib_none(ffi::g_type_name(widget.get_widget_type())) };
+
+    type_name.unwrap_or(String::new())
+}
+
+/// Returns the non-internal children of `widget`, none if it isn't a container.
+pub fn get_children<T: WidgetTrait>(widget: &T) -> Vec<Widget> {
+    Container::from_widget(widget).map_or(Vec::new(), |container| container.get_children())
+}
+
+/// Returns all the children of `widget`, including the internal ones, none if it isn't a
+/// container.
+pub fn get_all_children<T: WidgetTrait>(widget: &T) -> Vec<Widget> {
+    let mut children = Vec::new();
+
+    if let Some(container) = Container::from_widget(widget) {
+        container.forall(|child| children.push(Widget::upcast(child)));
+    }
+    children
+}
+
+/// Returns the value of the `property` of `widget` as text, None if the widget has no such
+/// property or if it is a NULL string.
+pub fn get_property<T: WidgetTrait>(widget: &T, property: &str) -> Option<String> {
+    let object = G_OBJECT(widget.unwrap_widget());
+    let value_type = unsafe { ffi::glue_object_property_type(object, property.to_glib_none().0) };
+
+    if value_type == 0 {
+        return None;
+    }
+    unsafe {
+        let mut value = Value::new();
+        glib_ffi::g_value_init(value.as_mut_ptr(), value_type);
+        ffi::g_object_get_property(object, property.to_glib_none().0, value.as_mut_ptr());
+
+        if value_type == Type::String.to_glib() {
+            from_glib_none(ffi::g_value_get_string(value.as_ptr()))
+        } else {
+            from_glib_full(ffi::g_strdup_value_contents(value.as_ptr()))
+        }
+    }
+}
+
+fn push_indent(s: &mut String, depth: usize) {
+    for _ in 0..depth {
+        s.push_str("  ");
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c)
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_string, WidgetInfo};
+    use gdk;
+
+    fn info(type_name: &str, name: Option<&str>, properties: Vec<(&str, &str)>, children: Vec<WidgetInfo>)
+            -> WidgetInfo {
+        WidgetInfo {
+            type_name: type_name.to_owned(),
+            name: name.map(|name| name.to_owned()),
+            visible: true,
+            sensitive: false,
+            allocation: gdk::Rectangle { x: 10, y: 20, width: 80, height: 30 },
+            style_classes: vec!["flat".to_owned(), "a\"b".to_owned()],
+            properties: properties.iter().map(|&(p, v)| (p.to_owned(), v.to_owned())).collect(),
+            children: children
+        }
+    }
+
+    #[test]
+    fn escaping() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string("say \"hi\""), r#""say \"hi\"""#);
+        assert_eq!(json_string("C:\\dir\\"), r#""C:\\dir\\""#);
+        assert_eq!(json_string("a\nb\rc\td"), r#""a\nb\rc\td""#);
+        assert_eq!(json_string("\u{0}\u{1}\u{1f} \u{7f}é"), "\"\\u0000\\u0001\\u001f \u{7f}é\"");
+    }
+
+    #[test]
+    fn leaf_to_json() {
+        let widget = info("GtkLabel", None, Vec::new(), Vec::new());
+
+        assert_eq!(widget.to_json(), r#"{
+  "type": "GtkLabel",
+  "name": null,
+  "visible": true,
+  "sensitive": false,
+  "allocation": {"x": 10, "y": 20, "width": 80, "height": 30},
+  "style_classes": ["flat", "a\"b"],
+  "properties": {},
+  "children": []
+}
+"#);
+    }
+
+    #[test]
+    fn nested_to_json() {
+        let label = info("GtkLabel", Some("path"), vec![("label", "C:\\tmp\n\"x\"")], Vec::new());
+        let inner = info("GtkBox", None, vec![("orientation", "GTK_ORIENTATION_VERTICAL")], vec![label]);
+        let empty = info("GtkSeparator", None, Vec::new(), Vec::new());
+        let window = info("GtkWindow", Some("main\twindow"), vec![("title", "A \u{1} B")], vec![inner, empty]);
+
+        assert_eq!(window.to_json(), r#"{
+  "type": "GtkWindow",
+  "name": "main\twindow",
+  "visible": true,
+  "sensitive": false,
+  "allocation": {"x": 10, "y": 20, "width": 80, "height": 30},
+  "style_classes": ["flat", "a\"b"],
+  "properties": {"title": "A \u0001 B"},
+  "children": [
+    {
+      "type": "GtkBox",
+      "name": null,
+      "visible": true,
+      "sensitive": false,
+      "allocation": {"x": 10, "y": 20, "width": 80, "height": 30},
+      "style_classes": ["flat", "a\"b"],
+      "properties": {"orientation": "GTK_ORIENTATION_VERTICAL"},
+      "children": [
+        {
+          "type": "GtkLabel",
+          "name": "path",
+          "visible": true,
+          "sensitive": false,
+          "allocation": {"x": 10, "y": 20, "width": 80, "height": 30},
+          "style_classes": ["flat", "a\"b"],
+          "properties": {"label": "C:\\tmp\n\"x\""},
+          "children": []
+        }
+      ]
+    },
+    {
+      "type": "GtkSeparator",
+      "name": null,
+      "visible": true,
+      "sensitive": false,
+      "allocation": {"x": 10, "y": 20, "width": 80, "height": 30},
+      "style_classes": ["flat", "a\"b"],
+      "properties": {},
+      "children": []
+    }
+  ]
+}
+"#);
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Parses .ini-like config files
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::ffi::{CString, NulError};
+use std::ptr;
+use glib::translate::{from_glib_full, FromGlibPtrContainer, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use glib;
+use ffi;
+use libc::{c_char, c_ulong};
+
+// G_KEY_FILE_KEEP_COMMENTS | G_KEY_FILE_KEEP_TRANSLATIONS
+const LOAD_FLAGS: i32 = 1 << 0 | 1 << 1;
+
+/// KeyFile — Parses .ini-like config files
+pub struct KeyFile {
+    pointer: *mut ffi::C_GKeyFile
+}
+
+impl KeyFile {
+    pub fn new() -> KeyFile {
+        KeyFile {
+            pointer: unsafe { ffi::g_key_file_new() }
+        }
+    }
+
+    pub fn load_from_file(&self, file: &str) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+
+        if unsafe { to_bool(ffi::g_key_file_load_from_file(self.pointer, file.to_glib_none().0, LOAD_FLAGS, &mut error)) } {
+            Ok(())
+        } else {
+            Err(glib::GlibContainer::wrap(error))
+        }
+    }
+
+    pub fn load_from_data(&self, data: &str) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+
+        if unsafe {
+            to_bool(ffi::g_key_file_load_from_data(self.pointer, data.to_glib_none().0, data.len() as c_ulong,
+                                                   LOAD_FLAGS, &mut error))
+        } {
+            Ok(())
+        } else {
+            Err(glib::GlibContainer::wrap(error))
+        }
+    }
+
+    pub fn to_data(&self) -> String {
+        let data: Option<String> = unsafe {
+            from_glib_full(ffi::g_key_file_to_data(self.pointer, ptr::null_mut(), ptr::null_mut()))
+        };
+
+        data.unwrap_or(String::new())
+    }
+
+    pub fn save_to_file(&self, file: &str) -> io::Result<()> {
+        let mut f = try!(File::create(file));
+
+        f.write_all(self.to_data().as_bytes())
+    }
+
+    pub fn has_group(&self, group_name: &str) -> bool {
+        unsafe { to_bool(ffi::g_key_file_has_group(self.pointer, group_name.to_glib_none().0)) }
+    }
+
+    pub fn has_key(&self, group_name: &str, key: &str) -> bool {
+        unsafe {
+            to_bool(ffi::g_key_file_has_key(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                            ptr::null_mut()))
+        }
+    }
+
+    pub fn remove_group(&self, group_name: &str) -> bool {
+        unsafe { to_bool(ffi::g_key_file_remove_group(self.pointer, group_name.to_glib_none().0, ptr::null_mut())) }
+    }
+
+    pub fn get_string(&self, group_name: &str, key: &str) -> Option<String> {
+        if !self.has_key(group_name, key) {
+            return None;
+        }
+        unsafe {
+            from_glib_full(ffi::g_key_file_get_string(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                                      ptr::null_mut()))
+        }
+    }
+
+    pub fn set_string(&self, group_name: &str, key: &str, string: &str) {
+        unsafe {
+            ffi::g_key_file_set_string(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                       string.to_glib_none().0)
+        }
+    }
+
+    /// Returns the values of a list, which are separated by ';' in the file.
+    pub fn get_string_list(&self, group_name: &str, key: &str) -> Option<Vec<String>> {
+        if !self.has_key(group_name, key) {
+            return None;
+        }
+        let list = unsafe {
+            ffi::g_key_file_get_string_list(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                            ptr::null_mut(), ptr::null_mut())
+        };
+
+        if list.is_null() {
+            None
+        } else {
+            Some(unsafe { FromGlibPtrContainer::from_glib_full(list as *const *const c_char) })
+        }
+    }
+
+    /// Sets a list of values, the ';' in them are escaped. Fails if a value contains a nul
+    /// byte, the key is left as it was.
+    pub fn set_string_list(&self, group_name: &str, key: &str, list: &[&str]) -> Result<(), NulError> {
+        let mut c_strings = Vec::with_capacity(list.len());
+        for s in list.iter() {
+            c_strings.push(try!(CString::new(*s)));
+        }
+        let mut c_list: Vec<*const c_char> = c_strings.iter().map(|s| s.as_ptr()).collect();
+        c_list.push(ptr::null());
+
+        unsafe {
+            ffi::g_key_file_set_string_list(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                            c_list.as_ptr(), c_strings.len() as c_ulong)
+        }
+        Ok(())
+    }
+
+    pub fn get_boolean(&self, group_name: &str, key: &str) -> Option<bool> {
+        if !self.has_key(group_name, key) {
+            return None;
+        }
+        unsafe {
+            Some(to_bool(ffi::g_key_file_get_boolean(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                                     ptr::null_mut())))
+        }
+    }
+
+    pub fn set_boolean(&self, group_name: &str, key: &str, value: bool) {
+        unsafe {
+            ffi::g_key_file_set_boolean(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                        to_gboolean(value))
+        }
+    }
+
+    pub fn get_integer(&self, group_name: &str, key: &str) -> Option<i32> {
+        if !self.has_key(group_name, key) {
+            return None;
+        }
+        unsafe {
+            Some(ffi::g_key_file_get_integer(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                             ptr::null_mut()))
+        }
+    }
+
+    pub fn set_integer(&self, group_name: &str, key: &str, value: i32) {
+        unsafe {
+            ffi::g_key_file_set_integer(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0, value)
+        }
+    }
+
+    pub fn get_double(&self, group_name: &str, key: &str) -> Option<f64> {
+        if !self.has_key(group_name, key) {
+            return None;
+        }
+        unsafe {
+            Some(ffi::g_key_file_get_double(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0,
+                                            ptr::null_mut()))
+        }
+    }
+
+    pub fn set_double(&self, group_name: &str, key: &str, value: f64) {
+        unsafe {
+            ffi::g_key_file_set_double(self.pointer, group_name.to_glib_none().0, key.to_glib_none().0, value)
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GKeyFile {
+        self.pointer
+    }
+}
+
+impl Drop for KeyFile {
+    fn drop(&mut self) {
+        unsafe { ffi::g_key_file_free(self.pointer) }
+    }
+}
@@ -72,6 +72,7 @@ pub use self::rt::{
 /// GTK Widgets for all versions
 pub use self::widgets::{
     Widget,
+    Container,
     Window,
     Label,
     Button,
@@ -142,14 +143,34 @@ pub use self::widgets::{
     RadioButton,
     TreeView,
     TreeViewColumn,
+    TreeViewLayout,
+    ColumnState,
+    WindowStateTracker,
     TreePath,
+    ParseTreePathError,
     TreeIter,
+    TreeIterError,
+    TreeRowReference,
     TreeModel,
     ListStore,
     TreeStore,
+    LazyTree,
     MenuItem,
     SeparatorMenuItem,
     CheckMenuItem,
+    RadioMenuItem,
+    ImageMenuItem,
+    Menu,
+    MenuBar,
+    AccelGroup,
+    accel_groups_activate,
+    accelerator_parse,
+    accelerator_name,
+    accelerator_get_label,
+    accelerator_valid,
+    accelerator_get_default_mod_mask,
+    AccelMap,
+    Clipboard,
     ScrollBar,
     Viewport,
     StatusBar,
@@ -163,6 +184,7 @@ pub use self::widgets::{
     ComboBox,
     //g_type,
     ComboBoxText,
+    TypedComboBox,
     TextMark,
     TextTag,
     TextAttributes,
@@ -175,7 +197,15 @@ pub use self::widgets::{
     FileChooserWidget,
     ColorChooserWidget,
     FontChooserWidget,
-    EventBox
+    EventBox,
+    OffscreenWindow,
+    MenuModel,
+    GMenu,
+    GMenuItem,
+    Application,
+    ApplicationWindow,
+    SimpleAction,
+    SimpleActionGroup
 };
 
 #[cfg(target_os = "linux")]
@@ -286,6 +316,8 @@ pub use ffi::enums::TreeViewColumnSizing;
 pub use ffi::enums::CellRendererState;
 pub use ffi::enums::TreeModelFlags;
 pub use ffi::enums::IconViewDropPosition;
+pub use ffi::enums::TreeViewDropPosition;
+pub use ffi::enums::TargetFlags;
 pub use ffi::enums::SensitivityType;
 pub use ffi::enums::GType;
 pub use ffi::enums::TextSearchFlags;
@@ -293,9 +325,12 @@ pub use ffi::enums::PlacesOpenFlags;
 pub use ffi::enums::ToolPaletteDragTargets;
 pub use ffi::enums::DestDefaults;
 pub use ffi::enums::SizeGroupMode;
+pub use ffi::enums::ApplicationFlags;
+pub use ffi::enums::ApplicationInhibitFlags;
 
 /// Gtk Traits
 pub use self::traits::FFIWidget;
+pub use self::traits::WidgetType;
 pub use self::traits::GObjectTrait;
 pub use self::traits::BoxTrait;
 pub use self::traits::ActionableTrait;
@@ -341,13 +376,25 @@ pub use self::traits::WindowTrait;
 /// GTK various struct
 pub use self::types::{
     Tooltip,
+    TargetEntry,
+    SelectionData,
 };
 
+pub use self::key_file::KeyFile;
+pub use self::variant::Variant;
+
 mod macros;
 mod cast;
 mod rt;
+mod key_file;
+mod variant;
 
 pub mod traits;
 pub mod signal;
 pub mod widgets;
-pub mod types;
\ No newline at end of file
+pub mod types;
+pub mod inspect;
+pub mod selector;
+pub mod testing;
+pub mod recording;
+pub mod rendering;
\ No newline at end of file
@@ -53,6 +53,15 @@ macro_rules! impl_TraitObject(
 
 macro_rules! impl_TraitWidget(
     ($gtk_struct:ident) => (
+        impl_TraitWidget!($gtk_struct, concat!("Gtk", stringify!($gtk_struct)));
+    );
+    ($gtk_struct:ident, $type_name:expr) => (
+        impl ::WidgetType for $gtk_struct {
+            fn static_type_name() -> &'static str {
+                $type_name
+            }
+        }
+
         impl ::FFIWidget for $gtk_struct {
             fn unwrap_widget(&self) -> *mut ffi::C_GtkWidget {
                 self.pointer
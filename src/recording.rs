@@ -0,0 +1,634 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Records the key, button and motion events of a session to replay them later
+//!
+//! A recorded event is one line of text, its fields separated by tabs:
+//!
+//! `<milliseconds since start>  <widget path>  <kind>  <kind specific fields>  <modifiers>`
+//!
+//! The widget path is the chain of widgets from the toplevel to the one which received the
+//! event, e.g. `GtkWindow[Editor]:0/GtkBox:0/GtkButton#save:1`. Each widget is described by
+//! its type, its name if it has one and its index among the siblings with the same
+//! description. A toplevel without a name is described by its title instead, so its path
+//! doesn't depend on the order of the other windows. `%`, `/`, tabs and newlines in names and
+//! titles are written as `%25`, `%2F`, `%09` and `%0A`. The coordinates of button and motion
+//! events are relative to this widget.
+//!
+//! Popup windows, e.g. menus and tooltips, aren't toplevels for the paths: their events can't
+//! be replayed.
+//!
+//! The events are replayed by queueing synthesized events for the widget at the recorded path,
+//! with the recorded key codes, so the widgets must be realized but the pointer isn't moved.
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::mem::transmute;
+use libc::{c_char, c_int, c_uint, c_ulong};
+use glib;
+use glib::translate::from_glib_none;
+use gdk::{EventAny, EventButton, EventKey, EventMotion, EventType, ModifierType};
+use gdk_ffi;
+use ffi;
+use inspect;
+use testing;
+use cast::GTK_WINDOW;
+use {FFIWidget, Widget, WidgetTrait, WindowType};
+
+/// The part of an event which is recorded
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum InputEvent {
+    KeyPress { keyval: u32, keycode: u16 },
+    KeyRelease { keyval: u32, keycode: u16 },
+    ButtonPress { button: u32, x: f64, y: f64 },
+    ButtonRelease { button: u32, x: f64, y: f64 },
+    Motion { x: f64, y: f64 }
+}
+
+/// An event of a recorded session
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedEvent {
+    /// Milliseconds elapsed since the start of the recording.
+    pub time: u32,
+    pub widget_path: String,
+    pub event: InputEvent,
+    /// The modifier mask, see `gdk::ModifierType`.
+    pub state: u32
+}
+
+// Parses a field of a recorded line, returns None from the function if it isn't valid
+macro_rules! try_field {
+    ($field:expr) => (match $field.parse() { Ok(value) => value, Err(_) => return None })
+}
+
+impl RecordedEvent {
+    /// Parses a line written by a `Recorder`, returns None if it isn't valid.
+    pub fn parse(line: &str) -> Option<RecordedEvent> {
+        let fields: Vec<&str> = line.split('\t').collect();
+
+        if fields.len() < 4 || fields[1].is_empty() {
+            return None;
+        }
+        let args = &fields[3..fields.len() - 1];
+        let event = match (fields[2], args.len()) {
+            ("key-press", 2) => InputEvent::KeyPress {
+                keyval: try_field!(args[0]),
+                keycode: try_field!(args[1])
+            },
+            ("key-release", 2) => InputEvent::KeyRelease {
+                keyval: try_field!(args[0]),
+                keycode: try_field!(args[1])
+            },
+            ("button-press", 3) => InputEvent::ButtonPress {
+                button: try_field!(args[0]),
+                x: try_field!(args[1]),
+                y: try_field!(args[2])
+            },
+            ("button-release", 3) => InputEvent::ButtonRelease {
+                button: try_field!(args[0]),
+                x: try_field!(args[1]),
+                y: try_field!(args[2])
+            },
+            ("motion", 2) => InputEvent::Motion {
+                x: try_field!(args[0]),
+                y: try_field!(args[1])
+            },
+            _ => return None
+        };
+
+        Some(RecordedEvent {
+            time: try_field!(fields[0]),
+            widget_path: fields[1].to_owned(),
+            event: event,
+            state: try_field!(fields[fields.len() - 1])
+        })
+    }
+}
+
+impl fmt::Display for RecordedEvent {
+    /// Formats the event as a line of a recording, without the line break.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(write!(f, "{}\t{}\t", self.time, self.widget_path));
+        try!(match self.event {
+            InputEvent::KeyPress { keyval, keycode } => write!(f, "key-press\t{}\t{}", keyval, keycode),
+            InputEvent::KeyRelease { keyval, keycode } => write!(f, "key-release\t{}\t{}", keyval, keycode),
+            InputEvent::ButtonPress { button, x, y } => write!(f, "button-press\t{}\t{}\t{}", button, x, y),
+            InputEvent::ButtonRelease { button, x, y } => write!(f, "button-release\t{}\t{}\t{}", button, x, y),
+            InputEvent::Motion { x, y } => write!(f, "motion\t{}\t{}", x, y)
+        });
+        write!(f, "\t{}", self.state)
+    }
+}
+
+struct RecorderState {
+    writer: RefCell<BufWriter<File>>,
+    start: i64,
+    error: RefCell<Option<io::Error>>
+}
+
+/// Recorder — Writes the input events of all the windows to a file
+///
+/// The events are recorded as GTK delivers them to the widgets, the GDK event handler is left
+/// as it is. The recording stops when the recorder is dropped.
+pub struct Recorder {
+    state: *mut RecorderState,
+    hook_id: c_ulong
+}
+
+impl Recorder {
+    pub fn start(file: &str) -> io::Result<Recorder> {
+        let state = Box::new(RecorderState {
+            writer: RefCell::new(BufWriter::new(try!(File::create(file)))),
+            start: unsafe { ffi::g_get_monotonic_time() },
+            error: RefCell::new(None)
+        });
+        let state: *mut RecorderState = unsafe { transmute(state) };
+
+        let hook_id = unsafe {
+            ffi::g_signal_add_emission_hook(get_event_signal(), 0, Some(transmute(event_hook_trampoline)),
+                                            state as ffi::gpointer, None)
+        };
+        Ok(Recorder {
+            state: state,
+            hook_id: hook_id
+        })
+    }
+
+    /// Stops the recording and returns the first error which occurred while writing, if any.
+    pub fn stop(mut self) -> io::Result<()> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if self.state.is_null() {
+            return Ok(());
+        }
+        unsafe { ffi::g_signal_remove_emission_hook(get_event_signal(), self.hook_id) }
+
+        let state: Box<RecorderState> = unsafe { transmute(self.state) };
+        self.state = ::std::ptr::null_mut();
+        if let Some(err) = state.error.borrow_mut().take() {
+            return Err(err);
+        }
+        let result = state.writer.borrow_mut().flush();
+        result
+    }
+}
+
+impl Drop for Recorder {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
+/// Returns the id of the `event` signal of the widgets, which every event delivered by GTK
+/// goes through.
+fn get_event_signal() -> c_uint {
+    unsafe {
+        // The signals of a type are only registered once its class exists
+        let class = ffi::g_type_class_ref(ffi::gtk_widget_get_type());
+        let signal_id = ffi::g_signal_lookup(b"event\0".as_ptr() as *const c_char, ffi::gtk_widget_get_type());
+
+        ffi::g_type_class_unref(class);
+        signal_id
+    }
+}
+
+extern "C" fn event_hook_trampoline(_: ffi::gpointer, _: c_uint, param_values: *const ffi::C_GValue,
+                                    data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let state: &RecorderState = transmute(data);
+        let widget = ffi::g_value_get_object(param_values) as *mut ffi::C_GtkWidget;
+        let event = ffi::g_value_get_boxed(param_values.offset(1));
+
+        // The event is emitted again on the ancestors while it propagates
+        if widget == ffi::gtk_get_event_widget(event) {
+            if let Some(recorded) = read_event(widget, event, state.start) {
+                if state.error.borrow().is_none() {
+                    if let Err(err) = writeln!(state.writer.borrow_mut(), "{}", recorded) {
+                        *state.error.borrow_mut() = Some(err);
+                    }
+                }
+            }
+        }
+    }
+    ffi::GTRUE
+}
+
+/// Reads a key, button or motion event received by `widget`, None for the other events.
+unsafe fn read_event(widget: *mut ffi::C_GtkWidget, event: ffi::gpointer, start: i64) -> Option<RecordedEvent> {
+    let any: &EventAny = transmute(event);
+    let (event, state) = match any._type {
+        EventType::KeyPress => {
+            let key: &EventKey = transmute(event);
+            (InputEvent::KeyPress { keyval: key.keyval, keycode: key.hardware_keycode }, key.state)
+        }
+        EventType::KeyRelease => {
+            let key: &EventKey = transmute(event);
+            (InputEvent::KeyRelease { keyval: key.keyval, keycode: key.hardware_keycode }, key.state)
+        }
+        EventType::ButtonPress => {
+            let button: &EventButton = transmute(event);
+            let (x, y) = match to_widget_coords(widget, button.x_root, button.y_root) {
+                Some(coords) => coords,
+                None => return None
+            };
+            (InputEvent::ButtonPress { button: button.button, x: x, y: y }, button.state)
+        }
+        EventType::ButtonRelease => {
+            let button: &EventButton = transmute(event);
+            let (x, y) = match to_widget_coords(widget, button.x_root, button.y_root) {
+                Some(coords) => coords,
+                None => return None
+            };
+            (InputEvent::ButtonRelease { button: button.button, x: x, y: y }, button.state)
+        }
+        EventType::MotionNotify => {
+            let motion: &EventMotion = transmute(event);
+            let (x, y) = match to_widget_coords(widget, motion.x_root, motion.y_root) {
+                Some(coords) => coords,
+                None => return None
+            };
+            (InputEvent::Motion { x: x, y: y }, motion.state)
+        }
+        _ => return None
+    };
+    let widget: Widget = FFIWidget::wrap_widget(widget);
+
+    Some(RecordedEvent {
+        time: ((ffi::g_get_monotonic_time() - start) / 1000) as u32,
+        widget_path: get_widget_path(&widget),
+        event: event,
+        state: state.bits()
+    })
+}
+
+/// Returns the offset of the allocation of `widget` in its GdkWindow, None if it isn't realized.
+fn get_allocation_offset(widget: &Widget) -> Option<(f64, f64)> {
+    if unsafe { ffi::gtk_widget_get_window(widget.unwrap_widget()) }.is_null() {
+        None
+    } else if unsafe { glib::to_bool(ffi::gtk_widget_get_has_window(widget.unwrap_widget())) } {
+        Some((0., 0.))
+    } else {
+        let allocation = widget.get_allocation();
+        Some((allocation.x as f64, allocation.y as f64))
+    }
+}
+
+/// Converts root window coordinates to coordinates relative to the allocation of `widget`.
+unsafe fn to_widget_coords(widget: *mut ffi::C_GtkWidget, x_root: f64, y_root: f64) -> Option<(f64, f64)> {
+    let widget: Widget = FFIWidget::wrap_widget(widget);
+    let (offset_x, offset_y) = match get_allocation_offset(&widget) {
+        Some(offset) => offset,
+        None => return None
+    };
+    let (mut origin_x, mut origin_y): (c_int, c_int) = (0, 0);
+
+    gdk_ffi::gdk_window_get_origin(ffi::gtk_widget_get_window(widget.unwrap_widget()), &mut origin_x, &mut origin_y);
+    Some((x_root - origin_x as f64 - offset_x, y_root - origin_y as f64 - offset_y))
+}
+
+/// Reads the events recorded in `file`, the invalid lines are skipped.
+pub fn load(file: &str) -> io::Result<Vec<RecordedEvent>> {
+    let reader = BufReader::new(try!(File::open(file)));
+    let mut events = Vec::new();
+
+    for line in reader.lines() {
+        if let Some(event) = RecordedEvent::parse(&try!(line)) {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReplaySpeed {
+    /// Waits between the events as long as during the recording.
+    Recorded,
+    AsFastAsPossible
+}
+
+/// Why a replay stopped
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayError {
+    /// No widget has this path.
+    WidgetNotFound(String),
+    /// The widget at this path has no window, it probably isn't realized.
+    NotRealized(String),
+    /// The event for the widget at this path couldn't be synthesized, its display has no pointer.
+    SimulationFailed(String)
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReplayError::WidgetNotFound(ref path) |
+            ReplayError::NotRealized(ref path) |
+            ReplayError::SimulationFailed(ref path) => write!(f, "{}: {}", self.description(), path)
+        }
+    }
+}
+
+impl Error for ReplayError {
+    fn description(&self) -> &str {
+        match *self {
+            ReplayError::WidgetNotFound(_) => "no widget has this path",
+            ReplayError::NotRealized(_) => "the widget isn't realized",
+            ReplayError::SimulationFailed(_) => "the event couldn't be sent"
+        }
+    }
+}
+
+/// Sends `events` again to the widgets having the same paths, running the main loop in
+/// between. Stops at the first event which can't be replayed.
+pub fn replay(events: &[RecordedEvent], speed: ReplaySpeed) -> Result<(), ReplayError> {
+    let start = unsafe { ffi::g_get_monotonic_time() };
+
+    for recorded in events.iter() {
+        if speed == ReplaySpeed::Recorded {
+            let elapsed = ((unsafe { ffi::g_get_monotonic_time() } - start) / 1000) as u32;
+            if recorded.time > elapsed {
+                testing::run_for(recorded.time - elapsed);
+            }
+        }
+        try!(replay_event(recorded));
+        testing::run_until_idle();
+    }
+    Ok(())
+}
+
+fn replay_event(recorded: &RecordedEvent) -> Result<(), ReplayError> {
+    let path = &recorded.widget_path;
+    let widget = match find_widget(path) {
+        Some(widget) => widget,
+        None => return Err(ReplayError::WidgetNotFound(path.clone()))
+    };
+    let offset = match get_allocation_offset(&widget) {
+        Some(offset) => offset,
+        None => return Err(ReplayError::NotRealized(path.clone()))
+    };
+
+    if unsafe { put_event(ffi::gtk_widget_get_window(widget.unwrap_widget()), recorded, offset) } {
+        Ok(())
+    } else {
+        Err(ReplayError::SimulationFailed(path.clone()))
+    }
+}
+
+/// Queues the event for `window` as if it came from the windowing system, the recorded
+/// coordinates are moved by `offset`. Returns false if the display has no pointer.
+unsafe fn put_event(window: *mut gdk_ffi::C_GdkWindow, recorded: &RecordedEvent, offset: (f64, f64)) -> bool {
+    let device_manager = gdk_ffi::gdk_display_get_device_manager(gdk_ffi::gdk_window_get_display(window));
+    let pointer = gdk_ffi::gdk_device_manager_get_client_pointer(device_manager);
+    let state = ModifierType::from_bits_truncate(recorded.state);
+    let (mut origin_x, mut origin_y): (c_int, c_int) = (0, 0);
+
+    if pointer.is_null() {
+        return false;
+    }
+    gdk_ffi::gdk_window_get_origin(window, &mut origin_x, &mut origin_y);
+    let origin = (origin_x as f64, origin_y as f64);
+
+    let (event, device) = match recorded.event {
+        InputEvent::KeyPress { keyval, keycode } => {
+            (new_key_event(EventType::KeyPress, keyval, keycode, state), gdk_ffi::gdk_device_get_associated_device(pointer))
+        }
+        InputEvent::KeyRelease { keyval, keycode } => {
+            (new_key_event(EventType::KeyRelease, keyval, keycode, state), gdk_ffi::gdk_device_get_associated_device(pointer))
+        }
+        InputEvent::ButtonPress { button, x, y } => {
+            (new_button_event(EventType::ButtonPress, button, (x + offset.0, y + offset.1), origin, state), pointer)
+        }
+        InputEvent::ButtonRelease { button, x, y } => {
+            (new_button_event(EventType::ButtonRelease, button, (x + offset.0, y + offset.1), origin, state), pointer)
+        }
+        InputEvent::Motion { x, y } => (new_motion_event((x + offset.0, y + offset.1), origin, state), pointer)
+    };
+
+    {
+        let any: &mut EventAny = transmute(event);
+        any.window = ::glib_ffi::g_object_ref(window as *mut _) as *mut gdk_ffi::C_GdkWindow;
+        any.send_event = 1;
+    }
+    gdk_ffi::gdk_event_set_device(event as *mut _, device);
+    gdk_ffi::gdk_event_put(event as *mut _);
+    gdk_ffi::gdk_event_free(event as *mut _);
+    true
+}
+
+// The time of the synthesized events is GDK_CURRENT_TIME
+
+unsafe fn new_key_event(event_type: EventType, keyval: u32, keycode: u16, state: ModifierType) -> ffi::gpointer {
+    let event = gdk_ffi::gdk_event_new(event_type) as ffi::gpointer;
+    {
+        let key: &mut EventKey = transmute(event);
+        key.time = 0;
+        key.state = state;
+        key.keyval = keyval;
+        key.hardware_keycode = keycode;
+    }
+    event
+}
+
+unsafe fn new_button_event(event_type: EventType, button: u32, (x, y): (f64, f64), (origin_x, origin_y): (f64, f64),
+                           state: ModifierType) -> ffi::gpointer {
+    let event = gdk_ffi::gdk_event_new(event_type) as ffi::gpointer;
+    {
+        let button_event: &mut EventButton = transmute(event);
+        button_event.time = 0;
+        button_event.state = state;
+        button_event.button = button;
+        button_event.x = x;
+        button_event.y = y;
+        button_event.x_root = origin_x + x;
+        button_event.y_root = origin_y + y;
+    }
+    event
+}
+
+unsafe fn new_motion_event((x, y): (f64, f64), (origin_x, origin_y): (f64, f64), state: ModifierType) -> ffi::gpointer {
+    let event = gdk_ffi::gdk_event_new(EventType::MotionNotify) as ffi::gpointer;
+    {
+        let motion: &mut EventMotion = transmute(event);
+        motion.time = 0;
+        motion.state = state;
+        motion.x = x;
+        motion.y = y;
+        motion.x_root = origin_x + x;
+        motion.y_root = origin_y + y;
+    }
+    event
+}
+
+/// Returns the path of `widget`, see the module documentation.
+pub fn get_widget_path<T: WidgetTrait>(widget: &T) -> String {
+    let mut segments = Vec::new();
+    let mut current: Widget = FFIWidget::wrap_widget(widget.unwrap_widget());
+
+    loop {
+        let parent = current.get_parent();
+        let siblings = match parent {
+            Some(ref parent) => inspect::get_all_children(parent),
+            None => list_toplevels()
+        };
+
+        // Only the windows can be described by their title, popups and unparented widgets
+        // aren't in the toplevels
+        let toplevel = parent.is_none() &&
+            siblings.iter().any(|sibling| sibling.unwrap_widget() == current.unwrap_widget());
+        segments.push(get_segment(&siblings, &current, toplevel));
+        match parent {
+            Some(parent) => current = parent,
+            None => break
+        }
+    }
+    segments.reverse();
+    segments.join("/")
+}
+
+/// Returns the widget at `path`, None if there is none.
+pub fn find_widget(path: &str) -> Option<Widget> {
+    let mut current: Option<Widget> = None;
+
+    for segment in path.split('/') {
+        let (siblings, toplevel) = match current {
+            Some(ref widget) => (inspect::get_all_children(widget), false),
+            None => (list_toplevels(), true)
+        };
+
+        match siblings.iter().find(|sibling| get_segment(&siblings, sibling, toplevel) == segment) {
+            Some(widget) => current = Some(widget.clone()),
+            None => return None
+        }
+    }
+    current
+}
+
+fn get_segment(siblings: &[Widget], widget: &Widget, toplevel: bool) -> String {
+    let key = get_key(widget, toplevel);
+    let index = siblings.iter()
+        .take_while(|sibling| sibling.unwrap_widget() != widget.unwrap_widget())
+        .filter(|sibling| get_key(*sibling, toplevel) == key)
+        .count();
+
+    format!("{}:{}", key, index)
+}
+
+/// Returns the description of `widget` in its segment, without the index.
+fn get_key(widget: &Widget, toplevel: bool) -> String {
+    let type_name = inspect::get_type_name(widget);
+
+    if let Some(name) = widget.get_name() {
+        if name != type_name {
+            return format!("{}#{}", type_name, escape(&name));
+        }
+    }
+    if toplevel {
+        let title: Option<String> = unsafe {
+            from_glib_none(ffi::gtk_window_get_title(GTK_WINDOW(widget.unwrap_widget())))
+        };
+        if let Some(title) = title {
+            return format!("{}[{}]", type_name, escape(&title));
+        }
+    }
+    type_name
+}
+
+fn escape(text: &str) -> String {
+    text.replace("%", "%25").replace("/", "%2F").replace("\t", "%09").replace("\n", "%0A")
+}
+
+/// Returns the toplevel windows, without the popups.
+fn list_toplevels() -> Vec<Widget> {
+    let list: glib::List<*mut ffi::C_GtkWidget> = glib::GlibContainer::wrap(unsafe {
+        ffi::gtk_window_list_toplevels()
+    });
+
+    list.iter()
+        .filter(|&&toplevel| unsafe { ffi::gtk_window_get_window_type(GTK_WINDOW(toplevel)) } != WindowType::PopUp)
+        .map(|&toplevel| FFIWidget::wrap_widget(toplevel))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputEvent, RecordedEvent};
+
+    fn recorded(event: InputEvent) -> RecordedEvent {
+        RecordedEvent {
+            time: 1250,
+            widget_path: "GtkWindow[Editor]:0/GtkBox:0/GtkButton#save:1".to_owned(),
+            event: event,
+            state: 5
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let events = [
+            InputEvent::KeyPress { keyval: 0xff0d, keycode: 36 },
+            InputEvent::KeyRelease { keyval: 97, keycode: 38 },
+            InputEvent::ButtonPress { button: 1, x: 12.5, y: 3. },
+            InputEvent::ButtonRelease { button: 3, x: -0.25, y: 100. },
+            InputEvent::Motion { x: 0., y: 7.75 }
+        ];
+
+        for &event in events.iter() {
+            let line = recorded(event).to_string();
+            assert_eq!(RecordedEvent::parse(&line), Some(recorded(event)));
+        }
+    }
+
+    #[test]
+    fn line_format() {
+        let path = "GtkWindow[Editor]:0/GtkBox:0/GtkButton#save:1";
+
+        assert_eq!(recorded(InputEvent::KeyPress { keyval: 97, keycode: 38 }).to_string(),
+                   format!("1250\t{}\tkey-press\t97\t38\t5", path));
+        assert_eq!(recorded(InputEvent::KeyRelease { keyval: 97, keycode: 38 }).to_string(),
+                   format!("1250\t{}\tkey-release\t97\t38\t5", path));
+        assert_eq!(recorded(InputEvent::ButtonPress { button: 1, x: 12.5, y: 3. }).to_string(),
+                   format!("1250\t{}\tbutton-press\t1\t12.5\t3\t5", path));
+        assert_eq!(recorded(InputEvent::ButtonRelease { button: 1, x: 12.5, y: 3. }).to_string(),
+                   format!("1250\t{}\tbutton-release\t1\t12.5\t3\t5", path));
+        assert_eq!(recorded(InputEvent::Motion { x: 12.5, y: 3. }).to_string(),
+                   format!("1250\t{}\tmotion\t12.5\t3\t5", path));
+    }
+
+    #[test]
+    fn malformed_lines() {
+        let lines = [
+            "",
+            "1250",
+            "1250\tGtkWindow:0",
+            "1250\tGtkWindow:0\tkey-press",
+            // Missing or extra fields
+            "1250\tGtkWindow:0\tkey-press\t97\t38",
+            "1250\tGtkWindow:0\tkey-press\t97\t5",
+            "1250\tGtkWindow:0\tkey-release\t97\t38\t1\t5",
+            "1250\tGtkWindow:0\tbutton-press\t1\t12.5\t5",
+            "1250\tGtkWindow:0\tbutton-release\t1\t12.5\t3\t4\t5",
+            "1250\tGtkWindow:0\tmotion\t12.5\t5",
+            "1250\tGtkWindow:0\tmotion\t12.5\t3\t4\t5",
+            // Invalid numbers
+            "-1\tGtkWindow:0\tkey-press\t97\t38\t5",
+            "12.5\tGtkWindow:0\tkey-press\t97\t38\t5",
+            "1250\tGtkWindow:0\tkey-press\ta\t38\t5",
+            "1250\tGtkWindow:0\tkey-press\t97\t70000\t5",
+            "1250\tGtkWindow:0\tbutton-press\t1.5\t12.5\t3\t5",
+            "1250\tGtkWindow:0\tmotion\tx\t3\t5",
+            "1250\tGtkWindow:0\tmotion\t12.5\t3\tshift",
+            // Unknown kind and empty path
+            "1250\tGtkWindow:0\tscroll\t12.5\t3\t5",
+            "1250\t\tmotion\t12.5\t3\t5"
+        ];
+
+        for line in lines.iter() {
+            assert_eq!(RecordedEvent::parse(line), None, "{:?}", line);
+        }
+    }
+}
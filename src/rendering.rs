@@ -0,0 +1,292 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Renders widgets into images and compares them, for screenshot tests
+
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::slice;
+use cairo::{Context, Format, ImageSurface, Status};
+use cairo_ffi;
+use ffi;
+use testing;
+use {ContainerTrait, OffscreenWindow, WidgetTrait, WindowTrait};
+
+/// Why a snapshot couldn't be created, loaded or written
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// A cairo status other than success.
+    Cairo(Status),
+    Io(io::Error),
+    /// The offscreen window to render in couldn't be created.
+    NoWindow
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SnapshotError::Cairo(status) => write!(f, "{}: {:?}", self.description(), status),
+            SnapshotError::Io(ref err) => write!(f, "{}: {}", self.description(), err),
+            SnapshotError::NoWindow => write!(f, "{}", self.description())
+        }
+    }
+}
+
+impl Error for SnapshotError {
+    fn description(&self) -> &str {
+        match *self {
+            SnapshotError::Cairo(_) => "cairo error",
+            SnapshotError::Io(_) => "I/O error",
+            SnapshotError::NoWindow => "couldn't create the offscreen window"
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> SnapshotError {
+        SnapshotError::Io(err)
+    }
+}
+
+/// Snapshot — A cairo image surface in the ARGB32 format holding a rendered widget
+pub struct Snapshot {
+    surface: ImageSurface
+}
+
+impl Snapshot {
+    /// Creates a transparent image.
+    pub fn new(width: i32, height: i32) -> Result<Snapshot, SnapshotError> {
+        Snapshot::from_surface(ImageSurface::create(Format::ARgb32, width, height))
+    }
+
+    /// Images which aren't in the ARGB32 format, e.g. RGB24 or grayscale ones, are converted.
+    pub fn load_png(file: &str) -> Result<Snapshot, SnapshotError> {
+        let surface = try!(ImageSurface::create_from_png(&mut try!(File::open(file))));
+
+        Snapshot::from_surface(surface)
+    }
+
+    pub fn write_png(&self, file: &str) -> Result<(), SnapshotError> {
+        try!(self.surface.write_to_png(&mut try!(File::create(file))));
+        Ok(())
+    }
+
+    pub fn get_width(&self) -> i32 {
+        self.surface.get_width()
+    }
+
+    pub fn get_height(&self) -> i32 {
+        self.surface.get_height()
+    }
+
+    /// Returns the premultiplied (alpha, red, green, blue) of the pixel at (`x`, `y`), None if
+    /// it is out of the image.
+    pub fn get_pixel(&self, x: i32, y: i32) -> Option<(u8, u8, u8, u8)> {
+        if x < 0 || y < 0 || x >= self.get_width() || y >= self.get_height() {
+            return None;
+        }
+        let pixel = self.get_row(y)[x as usize];
+
+        Some(((pixel >> 24) as u8, (pixel >> 16) as u8, (pixel >> 8) as u8, pixel as u8))
+    }
+
+    /// Compares the image with `reference`, two pixels are the same if none of their channels
+    /// differ by more than `tolerance`. Pixels which are only in one of the images differ.
+    pub fn compare(&self, reference: &Snapshot, tolerance: u8) -> Result<SnapshotDiff, SnapshotError> {
+        let width = ::std::cmp::max(self.get_width(), reference.get_width());
+        let height = ::std::cmp::max(self.get_height(), reference.get_height());
+        let mut diff = try!(Snapshot::new(width, height));
+        let mut differing_pixels = 0;
+
+        for y in 0..height {
+            let row = diff.get_row_mut(y);
+            for x in 0..width {
+                row[x as usize] = match (self.get_pixel(x, y), reference.get_pixel(x, y)) {
+                    (Some(pixel), Some(expected)) if same_pixel(pixel, expected, tolerance) => faded(expected),
+                    _ => {
+                        differing_pixels += 1;
+                        0xffff0000
+                    }
+                };
+            }
+        }
+        diff.surface.mark_dirty();
+
+        Ok(SnapshotDiff {
+            differing_pixels: differing_pixels,
+            diff: diff
+        })
+    }
+
+    pub fn get_surface(&self) -> &ImageSurface {
+        &self.surface
+    }
+
+    fn from_surface(surface: ImageSurface) -> Result<Snapshot, SnapshotError> {
+        match surface.status() {
+            Status::Success => (),
+            status => return Err(SnapshotError::Cairo(status))
+        }
+        surface.flush();
+
+        if unsafe { cairo_ffi::cairo_image_surface_get_format(surface.get_ptr()) } == Format::ARgb32 {
+            Ok(Snapshot { surface: surface })
+        } else {
+            Snapshot::convert(&surface)
+        }
+    }
+
+    /// Paints `surface` on a new ARGB32 surface.
+    fn convert(surface: &ImageSurface) -> Result<Snapshot, SnapshotError> {
+        let snapshot = try!(Snapshot::new(surface.get_width(), surface.get_height()));
+        let cr = Context::new(&snapshot.surface);
+
+        unsafe {
+            cairo_ffi::cairo_set_source_surface(cr.get_ptr(), surface.get_ptr(), 0., 0.);
+            cairo_ffi::cairo_paint(cr.get_ptr());
+        }
+        match cr.status() {
+            Status::Success => {
+                snapshot.surface.flush();
+                Ok(snapshot)
+            }
+            status => Err(SnapshotError::Cairo(status))
+        }
+    }
+
+    /// The data of a surface which failed is null, hence the status check of `from_surface`.
+    fn get_row(&self, y: i32) -> &[u32] {
+        unsafe {
+            let data = cairo_ffi::cairo_image_surface_get_data(self.surface.get_ptr());
+
+            slice::from_raw_parts(data.offset((y * self.surface.get_stride()) as isize) as *const u32,
+                                  self.get_width() as usize)
+        }
+    }
+
+    fn get_row_mut(&mut self, y: i32) -> &mut [u32] {
+        unsafe {
+            let data = cairo_ffi::cairo_image_surface_get_data(self.surface.get_ptr());
+
+            slice::from_raw_parts_mut(data.offset((y * self.surface.get_stride()) as isize) as *mut u32,
+                                      self.get_width() as usize)
+        }
+    }
+}
+
+/// The result of `Snapshot::compare`
+pub struct SnapshotDiff {
+    pub differing_pixels: usize,
+    /// The differing pixels in red over a faded copy of the reference.
+    pub diff: Snapshot
+}
+
+/// Draws `widget` as it currently is, it must be realized and allocated.
+pub fn render<T: WidgetTrait>(widget: &T) -> Result<Snapshot, SnapshotError> {
+    let snapshot = try!(Snapshot::new(widget.get_allocated_width(), widget.get_allocated_height()));
+    let cr = Context::new(&snapshot.surface);
+
+    unsafe { ffi::gtk_widget_draw(widget.unwrap_widget(), cr.get_ptr() as ffi::gpointer) }
+    match cr.status() {
+        Status::Success => {
+            snapshot.surface.flush();
+            Ok(snapshot)
+        }
+        status => Err(SnapshotError::Cairo(status))
+    }
+}
+
+/// Draws `widget` in an offscreen window of `width` x `height`, -1 to use its natural size.
+/// The widget mustn't have a parent, it has none again afterwards.
+pub fn render_offscreen<T: WidgetTrait>(widget: &T, width: i32, height: i32) -> Result<Snapshot, SnapshotError> {
+    let window = match OffscreenWindow::new() {
+        Some(window) => window,
+        None => return Err(SnapshotError::NoWindow)
+    };
+
+    window.set_default_size(width, height);
+    window.add(widget);
+    window.show_all();
+    testing::run_until_idle();
+
+    let snapshot = render(&window);
+    window.remove(widget);
+    window.destroy();
+    snapshot
+}
+
+/// Compares `snapshot` with the PNG image `reference_file` and, if some pixels differ, writes
+/// the diff image to `diff_file`. Returns the number of differing pixels.
+pub fn compare_with_reference(snapshot: &Snapshot, reference_file: &str, tolerance: u8,
+                              diff_file: &str) -> Result<usize, SnapshotError> {
+    let reference = try!(Snapshot::load_png(reference_file));
+    let result = try!(snapshot.compare(&reference, tolerance));
+
+    if result.differing_pixels > 0 {
+        try!(result.diff.write_png(diff_file));
+    }
+    Ok(result.differing_pixels)
+}
+
+fn same_pixel(pixel: (u8, u8, u8, u8), expected: (u8, u8, u8, u8), tolerance: u8) -> bool {
+    let close = |a: u8, b: u8| if a > b { a - b <= tolerance } else { b - a <= tolerance };
+
+    close(pixel.0, expected.0) && close(pixel.1, expected.1) && close(pixel.2, expected.2) &&
+        close(pixel.3, expected.3)
+}
+
+/// Returns `pixel` at a quarter of its opacity, as a premultiplied ARGB32 value.
+fn faded(pixel: (u8, u8, u8, u8)) -> u32 {
+    let (a, r, g, b) = pixel;
+
+    ((a as u32 / 4) << 24) | ((r as u32 / 4) << 16) | ((g as u32 / 4) << 8) | (b as u32 / 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Snapshot;
+
+    fn snapshot(width: i32, height: i32, pixels: &[u32]) -> Snapshot {
+        let mut snapshot = Snapshot::new(width, height).unwrap();
+
+        for y in 0..height {
+            let start = (y * width) as usize;
+            snapshot.get_row_mut(y).clone_from_slice(&pixels[start..start + width as usize]);
+        }
+        snapshot.surface.mark_dirty();
+        snapshot
+    }
+
+    #[test]
+    fn compare_tolerance() {
+        let reference = snapshot(2, 2, &[0xff000000, 0xff808080, 0x80404040, 0x00000000]);
+        let image = snapshot(2, 2, &[0xff000000, 0xff828080, 0x80404043, 0x00000000]);
+
+        assert_eq!(image.compare(&reference, 0).unwrap().differing_pixels, 2);
+        assert_eq!(image.compare(&reference, 2).unwrap().differing_pixels, 1);
+        assert_eq!(image.compare(&reference, 3).unwrap().differing_pixels, 0);
+
+        let result = image.compare(&reference, 2).unwrap();
+        assert_eq!(result.diff.get_pixel(0, 1), Some((0xff, 0xff, 0, 0)));
+        assert_eq!(result.diff.get_pixel(1, 0), Some((0x3f, 0x20, 0x20, 0x20)));
+        assert_eq!(result.diff.get_pixel(0, 0), Some((0x3f, 0, 0, 0)));
+    }
+
+    #[test]
+    fn compare_out_of_bounds() {
+        let reference = snapshot(2, 1, &[0xff102030, 0xff102030]);
+        let image = snapshot(1, 2, &[0xff102030, 0xff102030]);
+        let result = image.compare(&reference, 255).unwrap();
+
+        assert_eq!(result.differing_pixels, 3);
+        assert_eq!((result.diff.get_width(), result.diff.get_height()), (2, 2));
+        assert_eq!(result.diff.get_pixel(0, 0), Some((0x3f, 0x04, 0x08, 0x0c)));
+        assert_eq!(result.diff.get_pixel(1, 0), Some((0xff, 0xff, 0, 0)));
+        assert_eq!(result.diff.get_pixel(0, 1), Some((0xff, 0xff, 0, 0)));
+        assert_eq!(result.diff.get_pixel(1, 1), Some((0xff, 0xff, 0, 0)));
+        assert_eq!(result.diff.get_pixel(2, 0), None);
+    }
+}
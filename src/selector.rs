@@ -0,0 +1,454 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! CSS-like selectors to look widgets up in a widget tree
+//!
+//! A selector is a list of compound selectors separated by combinators, e.g.
+//! `Window > Box Button#save`:
+//!
+//! * `Button` or `GtkButton` matches the widgets of this type or of a subtype, `*` matches any widget
+//! * `#save` matches the widgets named "save" (see `WidgetTrait::set_name`)
+//! * `.suggested-action` matches the widgets having this style class
+//! * `[label="Save"]` matches the widgets whose property has this value, `[label*="Sa"]` the ones
+//!   whose value contains "Sa" and `[label]` the ones having the property set
+//! * `A B` matches a B which has an A as ancestor, `A > B` a B whose parent is an A
+
+use std::error::Error;
+use std::fmt;
+use glib::translate::ToGlibPtr;
+use glib::to_bool;
+use ffi;
+use inspect;
+use {Container, ContainerTrait, FFIWidget, Widget, WidgetTrait, WidgetType};
+
+/// Why a selector couldn't be parsed, with the byte offset where parsing stopped.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectorError {
+    pub position: usize,
+    pub message: &'static str
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at offset {}", self.message, self.position)
+    }
+}
+
+impl Error for SelectorError {
+    fn description(&self) -> &str {
+        self.message
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Condition {
+    Name(String),
+    StyleClass(String),
+    HasProperty(String),
+    PropertyEquals(String, String),
+    PropertyContains(String, String)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Compound {
+    /// None for `*`.
+    type_name: Option<String>,
+    conditions: Vec<Condition>
+}
+
+/// A parsed selector, see the module documentation for the syntax.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Selector {
+    /// The combinator of the first compound is ignored.
+    compounds: Vec<(Combinator, Compound)>
+}
+
+impl Selector {
+    pub fn parse(selector: &str) -> Result<Selector, SelectorError> {
+        Parser { input: selector, position: 0 }.parse()
+    }
+
+    /// Returns true if `widget` matches, its ancestors are looked at for the combinators.
+    pub fn matches<T: WidgetTrait>(&self, widget: &T) -> bool {
+        let widget: Widget = FFIWidget::wrap_widget(widget.unwrap_widget());
+
+        self.matches_at(&widget, self.compounds.len() - 1)
+    }
+
+    /// Returns the first descendant of `root` matching the selector, in depth-first order.
+    pub fn find<T: WidgetTrait, U: WidgetTrait + WidgetType>(&self, root: &T) -> Option<U> {
+        let mut found = None;
+
+        self.walk(root, &mut |widget| {
+            found = downcast(widget);
+            found.is_some()
+        });
+        found
+    }
+
+    /// Returns all the descendants of `root` matching the selector, in depth-first order.
+    pub fn find_all<T: WidgetTrait, U: WidgetTrait + WidgetType>(&self, root: &T) -> Vec<U> {
+        let mut found = Vec::new();
+
+        self.walk(root, &mut |widget| {
+            if let Some(widget) = downcast(widget) {
+                found.push(widget);
+            }
+            false
+        });
+        found
+    }
+
+    /// Calls `f` on the matching descendants of `root` until it returns true.
+    fn walk<T: WidgetTrait>(&self, root: &T, f: &mut FnMut(&Widget) -> bool) -> bool {
+        let children = Container::from_widget(root).map_or(Vec::new(), |container| container.get_children());
+
+        for child in children {
+            if self.matches(&child) && f(&child) {
+                return true;
+            }
+            if self.walk(&child, f) {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn matches_at(&self, widget: &Widget, index: usize) -> bool {
+        let (combinator, ref compound) = self.compounds[index];
+
+        if !compound.matches(widget) {
+            return false;
+        }
+        if index == 0 {
+            return true;
+        }
+
+        let mut ancestor = widget.get_parent();
+        while let Some(parent) = ancestor {
+            if self.matches_at(&parent, index - 1) {
+                return true;
+            }
+            if combinator == Combinator::Child {
+                return false;
+            }
+            ancestor = parent.get_parent();
+        }
+        false
+    }
+}
+
+impl Compound {
+    fn matches(&self, widget: &Widget) -> bool {
+        if let Some(ref type_name) = self.type_name {
+            if !is_a(widget, type_name) {
+                return false;
+            }
+        }
+
+        self.conditions.iter().all(|condition| match *condition {
+            Condition::Name(ref name) => widget.get_name().map_or(false, |n| n == *name),
+            Condition::StyleClass(ref class_name) => widget.has_style_class(class_name),
+            Condition::HasProperty(ref property) => inspect::get_property(widget, property).is_some(),
+            Condition::PropertyEquals(ref property, ref value) => {
+                inspect::get_property(widget, property).map_or(false, |v| v == *value)
+            }
+            Condition::PropertyContains(ref property, ref value) => {
+                inspect::get_property(widget, property).map_or(false, |v| v.contains(&value[..]))
+            }
+        })
+    }
+}
+
+/// Returns `widget` as a `T` if it is an instance of the GType of `T`.
+pub fn downcast<T: WidgetTrait + WidgetType>(widget: &Widget) -> Option<T> {
+    if is_a(widget, T::static_type_name()) {
+        Some(FFIWidget::wrap_widget(widget.unwrap_widget()))
+    } else {
+        None
+    }
+}
+
+/// Returns true if `widget` is an instance of the type named `type_name`, the "Gtk" prefix can
+/// be omitted. A type which hasn't been registered yet has no instances.
+fn is_a(widget: &Widget, type_name: &str) -> bool {
+    let mut gtype = unsafe { ffi::g_type_from_name(type_name.to_glib_none().0) };
+
+    if gtype == 0 && !type_name.starts_with("Gtk") {
+        gtype = unsafe { ffi::g_type_from_name(format!("Gtk{}", type_name).to_glib_none().0) };
+    }
+    gtype != 0 && unsafe { to_bool(ffi::g_type_is_a(widget.get_widget_type(), gtype)) }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize
+}
+
+impl<'a> Parser<'a> {
+    fn parse(mut self) -> Result<Selector, SelectorError> {
+        let mut compounds = Vec::new();
+        let mut combinator = Combinator::Descendant;
+
+        loop {
+            self.skip_whitespace();
+            compounds.push((combinator, try!(self.parse_compound())));
+
+            let had_whitespace = self.skip_whitespace();
+            match self.peek() {
+                None => break,
+                Some('>') => {
+                    self.position += 1;
+                    combinator = Combinator::Child;
+                }
+                Some(_) if had_whitespace => combinator = Combinator::Descendant,
+                Some(_) => return Err(self.error("unexpected character"))
+            }
+        }
+        Ok(Selector { compounds: compounds })
+    }
+
+    fn parse_compound(&mut self) -> Result<Compound, SelectorError> {
+        let mut compound = Compound { type_name: None, conditions: Vec::new() };
+        let start = self.position;
+
+        match self.peek() {
+            Some('*') => self.position += 1,
+            Some(c) if is_identifier_char(c) => compound.type_name = Some(self.parse_identifier()),
+            _ => {}
+        }
+        loop {
+            match self.peek() {
+                Some('#') => {
+                    self.position += 1;
+                    let name = try!(self.expect_identifier());
+                    compound.conditions.push(Condition::Name(name));
+                }
+                Some('.') => {
+                    self.position += 1;
+                    let class_name = try!(self.expect_identifier());
+                    compound.conditions.push(Condition::StyleClass(class_name));
+                }
+                Some('[') => {
+                    self.position += 1;
+                    let condition = try!(self.parse_attribute());
+                    compound.conditions.push(condition);
+                }
+                _ => break
+            }
+        }
+
+        if self.position == start {
+            Err(self.error("expected a type, '*', '#', '.' or '['"))
+        } else {
+            Ok(compound)
+        }
+    }
+
+    /// Parses what follows a '['.
+    fn parse_attribute(&mut self) -> Result<Condition, SelectorError> {
+        self.skip_whitespace();
+        let property = try!(self.expect_identifier());
+        self.skip_whitespace();
+
+        let contains = match self.peek() {
+            Some(']') => {
+                self.position += 1;
+                return Ok(Condition::HasProperty(property));
+            }
+            Some('=') => {
+                self.position += 1;
+                false
+            }
+            Some('*') if self.input[self.position + 1..].starts_with('=') => {
+                self.position += 2;
+                true
+            }
+            _ => return Err(self.error("expected '=', '*=' or ']'"))
+        };
+
+        self.skip_whitespace();
+        let value = try!(self.parse_value());
+        self.skip_whitespace();
+        if self.peek() != Some(']') {
+            return Err(self.error("expected ']'"));
+        }
+        self.position += 1;
+
+        Ok(if contains {
+            Condition::PropertyContains(property, value)
+        } else {
+            Condition::PropertyEquals(property, value)
+        })
+    }
+
+    /// A quoted string, in which '\' escapes the next character, or an identifier.
+    fn parse_value(&mut self) -> Result<String, SelectorError> {
+        let quote = match self.peek() {
+            Some(c) if c == '"' || c == '\'' => c,
+            _ => return self.expect_identifier()
+        };
+        let mut value = String::new();
+        let mut chars = self.input[self.position + 1..].char_indices();
+
+        while let Some((offset, c)) = chars.next() {
+            if c == quote {
+                self.position += offset + 2;
+                return Ok(value);
+            } else if c == '\\' {
+                match chars.next() {
+                    Some((_, escaped)) => value.push(escaped),
+                    None => break
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        Err(self.error("unterminated string"))
+    }
+
+    fn expect_identifier(&mut self) -> Result<String, SelectorError> {
+        match self.peek() {
+            Some(c) if is_identifier_char(c) => Ok(self.parse_identifier()),
+            _ => Err(self.error("expected an identifier"))
+        }
+    }
+
+    fn parse_identifier(&mut self) -> String {
+        let rest = &self.input[self.position..];
+        let end = rest.find(|c| !is_identifier_char(c)).unwrap_or(rest.len());
+
+        self.position += end;
+        rest[..end].to_owned()
+    }
+
+    /// Returns true if some whitespace was skipped.
+    fn skip_whitespace(&mut self) -> bool {
+        let rest = &self.input[self.position..];
+        let end = rest.find(|c: char| !c.is_whitespace()).unwrap_or(rest.len());
+
+        self.position += end;
+        end > 0
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn error(&self, message: &'static str) -> SelectorError {
+        SelectorError {
+            position: self.position,
+            message: message
+        }
+    }
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '-'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Combinator, Compound, Condition, Selector, SelectorError};
+
+    fn compound(type_name: Option<&str>, conditions: Vec<Condition>) -> Compound {
+        Compound {
+            type_name: type_name.map(|name| name.to_owned()),
+            conditions: conditions
+        }
+    }
+
+    fn single(selector: &str) -> Compound {
+        let mut selector = Selector::parse(selector).unwrap();
+
+        assert_eq!(selector.compounds.len(), 1);
+        selector.compounds.pop().unwrap().1
+    }
+
+    fn error(selector: &str) -> SelectorError {
+        Selector::parse(selector).unwrap_err()
+    }
+
+    #[test]
+    fn simple_selectors() {
+        assert_eq!(single("Button"), compound(Some("Button"), vec![]));
+        assert_eq!(single("GtkButton"), compound(Some("GtkButton"), vec![]));
+        assert_eq!(single("*"), compound(None, vec![]));
+        assert_eq!(single("#save"), compound(None, vec![Condition::Name("save".to_owned())]));
+        assert_eq!(single(".suggested-action"),
+                   compound(None, vec![Condition::StyleClass("suggested-action".to_owned())]));
+        assert_eq!(single("[label]"), compound(None, vec![Condition::HasProperty("label".to_owned())]));
+        assert_eq!(single("[label=\"Save\"]"),
+                   compound(None, vec![Condition::PropertyEquals("label".to_owned(), "Save".to_owned())]));
+        assert_eq!(single("[label*=\"Sa\"]"),
+                   compound(None, vec![Condition::PropertyContains("label".to_owned(), "Sa".to_owned())]));
+    }
+
+    #[test]
+    fn attribute_values() {
+        assert_eq!(single("[ label = 'Save as' ]"),
+                   compound(None, vec![Condition::PropertyEquals("label".to_owned(), "Save as".to_owned())]));
+        assert_eq!(single("[label=Save]"),
+                   compound(None, vec![Condition::PropertyEquals("label".to_owned(), "Save".to_owned())]));
+        assert_eq!(single("[label=\"a \\\"b\\\" \\\\\"]"),
+                   compound(None, vec![Condition::PropertyEquals("label".to_owned(), "a \"b\" \\".to_owned())]));
+    }
+
+    #[test]
+    fn compound_selectors() {
+        assert_eq!(single("*#save.flat[sensitive][label*=\"Sa\"]"), compound(None, vec![
+            Condition::Name("save".to_owned()),
+            Condition::StyleClass("flat".to_owned()),
+            Condition::HasProperty("sensitive".to_owned()),
+            Condition::PropertyContains("label".to_owned(), "Sa".to_owned())
+        ]));
+        assert_eq!(single("Button.flat"),
+                   compound(Some("Button"), vec![Condition::StyleClass("flat".to_owned())]));
+    }
+
+    #[test]
+    fn combinators() {
+        let selector = Selector::parse("Window > Box Button#save").unwrap();
+
+        assert_eq!(selector.compounds, vec![
+            (Combinator::Descendant, compound(Some("Window"), vec![])),
+            (Combinator::Child, compound(Some("Box"), vec![])),
+            (Combinator::Descendant, compound(Some("Button"), vec![Condition::Name("save".to_owned())]))
+        ]);
+        assert_eq!(Selector::parse("  Window>Box  ").unwrap().compounds, vec![
+            (Combinator::Descendant, compound(Some("Window"), vec![])),
+            (Combinator::Child, compound(Some("Box"), vec![]))
+        ]);
+    }
+
+    #[test]
+    fn errors() {
+        let expected_compound = "expected a type, '*', '#', '.' or '['";
+
+        assert_eq!(error(""), SelectorError { position: 0, message: expected_compound });
+        assert_eq!(error("   "), SelectorError { position: 3, message: expected_compound });
+        assert_eq!(error("Box >"), SelectorError { position: 5, message: expected_compound });
+        assert_eq!(error("Box > "), SelectorError { position: 6, message: expected_compound });
+        assert_eq!(error("> Box"), SelectorError { position: 0, message: expected_compound });
+        assert_eq!(error("Box $"), SelectorError { position: 4, message: expected_compound });
+        assert_eq!(error("Box$"), SelectorError { position: 3, message: "unexpected character" });
+        assert_eq!(error("Box#"), SelectorError { position: 4, message: "expected an identifier" });
+        assert_eq!(error("."), SelectorError { position: 1, message: "expected an identifier" });
+        assert_eq!(error("[]"), SelectorError { position: 1, message: "expected an identifier" });
+        assert_eq!(error("[label"), SelectorError { position: 6, message: "expected '=', '*=' or ']'" });
+        assert_eq!(error("[label*]"), SelectorError { position: 6, message: "expected '=', '*=' or ']'" });
+        assert_eq!(error("[label=]"), SelectorError { position: 7, message: "expected an identifier" });
+        assert_eq!(error("[label=\"Save\""), SelectorError { position: 13, message: "expected ']'" });
+        assert_eq!(error("[label=Save x]"), SelectorError { position: 12, message: "expected ']'" });
+        assert_eq!(error("[label=\"Save]"), SelectorError { position: 7, message: "unterminated string" });
+        assert_eq!(error("[label='Save\\']"), SelectorError { position: 7, message: "unterminated string" });
+        assert_eq!(error("[label=\"Save\\"), SelectorError { position: 7, message: "unterminated string" });
+    }
+}
@@ -4,23 +4,29 @@
 
 //use std::boxed::into_raw;
 use std::mem::transmute;
+use std::slice;
 
 use glib::signal::connect;
 use glib::translate::*;
 use glib::{FFIGObject, ParamSpec};
 
-use ffi::{Gboolean, C_GtkAdjustment, C_GtkTreeSelection, C_GtkTreeViewColumn};
+use ffi::{self, Gboolean, C_GFile, C_GSimpleAction, C_GVariant, C_GtkAccelMap, C_GtkAdjustment, C_GtkApplication,
+          C_GtkClipboard, C_GtkTreeIter, C_GtkTreeModel, C_GtkTreePath, C_GtkTreeSelection, C_GtkTreeViewColumn,
+          C_GtkWidget};
+use libc::{c_char, c_int, c_uint};
+use gdk_ffi::C_GdkDragContext;
 use gdk::{
     EventAny, EventButton, EventConfigure, EventCrossing, EventExpose, EventFocus, EventGrabBroken,
-    EventKey, EventMotion, EventProperty, EventProximity, EventScroll, EventWindowState,
-    Rectangle, Screen,
+    EventKey, EventMotion, EventOwnerChange, EventProperty, EventProximity, EventScroll, EventWindowState,
+    ModifierType, Rectangle, Screen,
 };
 use cairo::Context;
 
 use {
-    Adjustment, Button, Dialog, DirectionType, Range, ScrollType, SpinButton, StateFlags,
-    TextDirection, ToolButton, Tooltip, TreeIter, TreePath, TreeSelection, TreeView,
-    TreeViewColumn, Widget, WidgetHelpType,
+    AccelMap, Adjustment, Application, Button, Clipboard, Dialog, DirectionType, IconView, IconViewDropPosition, Range,
+    ScrollType, SelectionData, SimpleAction, SpinButton, StateFlags, TextDirection, ToolButton, Tooltip,
+    TreeIter, TreeModel, TreePath, TreeSelection, TreeView, TreeViewColumn, TreeViewDropPosition,
+    Variant, Widget, WidgetHelpType, Window,
 };
 
 /// Whether to propagate the signal to other handlers
@@ -39,6 +45,67 @@ impl ToGlib for Inhibit {
 // libstd stability workaround
 unsafe fn into_raw<T>(b: Box<T>) -> *mut T { transmute(b) }
 
+// The tree and icon views also request the drag data while the pointer moves over them, to
+// check whether the row under it accepts the drop. Those requests are left to the class
+// handler, which answers them with the drag status; the `drag-drop` handler below marks the
+// context of the real drop so that `drag-data-received` handlers can tell both apart. The
+// mark is cleared once the drop was handled, or after the emission if no handler took it.
+const DROP_KEY: &'static [u8] = b"rgtk-drop\0";
+const DROP_MARK_CONNECTED_KEY: &'static [u8] = b"rgtk-drop-mark-connected\0";
+
+// G_CONNECT_AFTER
+const CONNECT_AFTER: i32 = 1;
+
+// The tree and icon views keep a GtkTreeRowReference to the dragged row on the context, under
+// `key`, from the start of the drag.
+unsafe fn get_source_row(context: *mut C_GdkDragContext, key: &[u8]) -> Option<TreePath> {
+    let reference = ffi::g_object_get_data(context as *mut _, key.as_ptr() as *const c_char);
+
+    if reference.is_null() {
+        return None
+    }
+    let path = ffi::gtk_tree_row_reference_get_path(reference as *mut ffi::C_GtkTreeRowReference);
+    if path.is_null() {
+        None
+    } else {
+        Some(TreePath::wrap_pointer(path))
+    }
+}
+
+/// Connects the handlers setting and clearing the drop mark, once per widget.
+unsafe fn connect_drop_mark(widget: *mut C_GtkWidget) {
+    let key = DROP_MARK_CONNECTED_KEY.as_ptr() as *const c_char;
+
+    if !ffi::g_object_get_data(widget as *mut _, key).is_null() {
+        return
+    }
+    ffi::g_object_set_data(widget as *mut _, key, 1 as ffi::gpointer);
+    ffi::g_signal_connect_data(widget as *mut _, b"drag-drop\0".as_ptr() as *const c_char,
+        Some(transmute(drop_mark_trampoline)), ::std::ptr::null_mut(), None, 0);
+    ffi::g_signal_connect_data(widget as *mut _, b"drag-data-received\0".as_ptr() as *const c_char,
+        Some(transmute(clear_drop_mark_trampoline)), ::std::ptr::null_mut(), None, CONNECT_AFTER);
+}
+
+/// Returns true if `context` is the one of a drop which wasn't handled yet.
+unsafe fn is_drop_marked(context: *mut C_GdkDragContext) -> bool {
+    !ffi::g_object_get_data(context as *mut _, DROP_KEY.as_ptr() as *const c_char).is_null()
+}
+
+unsafe fn clear_drop_mark(context: *mut C_GdkDragContext) {
+    ffi::g_object_set_data(context as *mut _, DROP_KEY.as_ptr() as *const c_char, ::std::ptr::null_mut());
+}
+
+extern "C" fn drop_mark_trampoline(_: *mut C_GtkWidget, context: *mut C_GdkDragContext, _: c_int, _: c_int,
+        _: c_uint, _: ffi::gpointer) -> Gboolean {
+    unsafe { ffi::g_object_set_data(context as *mut _, DROP_KEY.as_ptr() as *const c_char, 1 as ffi::gpointer) }
+    ffi::GFALSE
+}
+
+extern "C" fn clear_drop_mark_trampoline(_: *mut C_GtkWidget, context: *mut C_GdkDragContext, _: c_int,
+        _: c_int, _: ffi::gpointer, _: c_uint, _: c_uint, _: ffi::gpointer) {
+    unsafe { clear_drop_mark(context) }
+}
+
 pub trait WidgetSignals {
     fn connect_notify<F: Fn(Widget, &ParamSpec) + 'static>(&self, f: F) -> u64;
     fn connect_accel_closures_changed<F: Fn(Widget) + 'static>(&self, f: F) -> u64;
@@ -640,9 +707,9 @@ mod widget {
     }
 
     extern "C" fn query_trampoline(this: *mut C_GtkWidget, x: c_int, y: c_int, keyboard: Gboolean,
-            _tooltip: *mut C_GtkTooltip, f: &Box<Fn(Widget, i32, i32, bool, Tooltip) -> bool + 'static>)
+            tooltip: *mut C_GtkTooltip, f: &Box<Fn(Widget, i32, i32, bool, Tooltip) -> bool + 'static>)
             -> Gboolean {
-        f(FFIWidget::wrap_widget(this), x, y, from_glib(keyboard), Tooltip).to_glib()
+        f(FFIWidget::wrap_widget(this), x, y, from_glib(keyboard), Tooltip::wrap_pointer(tooltip)).to_glib()
     }
 
     extern "C" fn rectangle_trampoline(this: *mut C_GtkWidget, allocation: *mut Rectangle,
@@ -766,6 +833,82 @@ mod spin_button {
     }
 }
 
+pub trait ComboBoxSignals {
+    fn connect_changed<F: Fn(ComboBox) + 'static>(&self, f: F) -> u64;
+    /// Emitted when the popup is opened with the keyboard.
+    fn connect_popup<F: Fn(ComboBox) + 'static>(&self, f: F) -> u64;
+    /// Emitted when the popup is closed with the keyboard, the closure returns whether the
+    /// popup was closed.
+    fn connect_popdown<F: Fn(ComboBox) -> bool + 'static>(&self, f: F) -> u64;
+    /// For combo boxes with an entry, the closure receives the path of the selected row and
+    /// returns the text to display in the entry.
+    fn connect_format_entry_text<F: Fn(ComboBox, &str) -> String + 'static>(&self, f: F) -> u64;
+}
+
+mod combo_box {
+    use super::into_raw;
+    use std::mem::transmute;
+    use libc::c_char;
+    use glib::signal::connect;
+    use glib::translate::*;
+    use glib_ffi;
+    use traits::{FFIWidget, ComboBoxTrait};
+    use ffi::{Gboolean, C_GtkComboBox};
+    use ComboBox;
+
+    impl<T: FFIWidget + ComboBoxTrait> super::ComboBoxSignals for T {
+        fn connect_changed<F: Fn(ComboBox) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ComboBox) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "changed",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_popup<F: Fn(ComboBox) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ComboBox) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "popup",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_popdown<F: Fn(ComboBox) -> bool + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ComboBox) -> bool + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "popdown",
+                    transmute(bool_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_format_entry_text<F: Fn(ComboBox, &str) -> String + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ComboBox, &str) -> String + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "format-entry-text",
+                    transmute(format_entry_text_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn void_trampoline(this: *mut C_GtkComboBox, f: &Box<Fn(ComboBox) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _));
+    }
+
+    extern "C" fn bool_trampoline(this: *mut C_GtkComboBox, f: &Box<Fn(ComboBox) -> bool + 'static>)
+            -> Gboolean {
+        f(FFIWidget::wrap_widget(this as *mut _)).to_glib()
+    }
+
+    extern "C" fn format_entry_text_trampoline(this: *mut C_GtkComboBox, path: *const c_char,
+            f: &Box<Fn(ComboBox, &str) -> String + 'static>) -> *mut c_char {
+        let path: String = unsafe { from_glib_none(path) };
+        let text = f(FFIWidget::wrap_widget(this as *mut _), &path);
+
+        // the caller frees the returned string
+        unsafe { glib_ffi::g_strdup(text.to_glib_none().0) }
+    }
+}
+
 pub trait DialogSignals {
     fn connect_close<F: Fn(Dialog) + 'static>(&self, f: F) -> u64;
     fn connect_response<F: Fn(Dialog, i32) + 'static>(&self, f: F) -> u64;
@@ -808,6 +951,71 @@ mod dialog {
     }
 }
 
+pub trait WindowSignals {
+    /// Emitted when the focus widget changes, with None when the window loses its focus widget.
+    fn connect_set_focus<F: Fn(Window, Option<Widget>) + 'static>(&self, f: F) -> u64;
+    /// Keybinding signal emitted when the user activates the default widget.
+    fn connect_activate_default<F: Fn(Window) + 'static>(&self, f: F) -> u64;
+    /// Keybinding signal emitted when the user activates the focus widget.
+    fn connect_activate_focus<F: Fn(Window) + 'static>(&self, f: F) -> u64;
+    /// Emitted when the mnemonics or accelerators of the window change.
+    fn connect_keys_changed<F: Fn(Window) + 'static>(&self, f: F) -> u64;
+}
+
+mod window {
+    use super::into_raw;
+    use std::mem::transmute;
+    use glib::signal::connect;
+    use traits::{FFIWidget, WindowTrait};
+    use ffi::{C_GtkWidget, C_GtkWindow};
+    use {Widget, Window};
+
+    impl<T: FFIWidget + WindowTrait> super::WindowSignals for T {
+        fn connect_set_focus<F: Fn(Window, Option<Widget>) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(Window, Option<Widget>) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "set-focus",
+                    transmute(widget_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_activate_default<F: Fn(Window) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(Window) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "activate-default",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_activate_focus<F: Fn(Window) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(Window) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "activate-focus",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_keys_changed<F: Fn(Window) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(Window) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "keys-changed",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn void_trampoline(this: *mut C_GtkWindow, f: &Box<Fn(Window) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _));
+    }
+
+    extern "C" fn widget_trampoline(this: *mut C_GtkWindow, widget: *mut C_GtkWidget,
+            f: &Box<Fn(Window, Option<Widget>) + 'static>) {
+        let widget = if widget.is_null() { None } else { Some(FFIWidget::wrap_widget(widget)) };
+
+        f(FFIWidget::wrap_widget(this as *mut _), widget);
+    }
+}
+
 pub trait TreeViewSignals {
     fn connect_columns_changed<F: Fn(TreeView) + 'static>(&self, f: F) -> u64;
     fn connect_cursor_changed<F: Fn(TreeView) + 'static>(&self, f: F) -> u64;
@@ -826,16 +1034,30 @@ pub trait TreeViewSignals {
         -> u64;
     fn connect_toggle_cursor_row<F: Fn(TreeView) -> bool + 'static>(&self, f: F) -> u64;
     fn connect_unselect_all<F: Fn(TreeView) -> bool + 'static>(&self, f: F) -> u64;
+    /// The closure receives the row being dragged, None if it was deleted since the drag
+    /// began, and the info of the requested target.
+    fn connect_drag_data_get_rows<F: Fn(TreeView, &SelectionData, Option<TreePath>, u32) + 'static>(&self,
+        f: F) -> u64;
+    /// The closure receives the row and position the data was dropped at and returns
+    /// whether the drop succeeded. This replaces the default handler on drops, the source
+    /// row is never deleted: remove it from its model in the closure to implement a move.
+    /// The data requested while hovering rows is still handled by the default handler.
+    /// A drop is handled by the first connected closure only, it stops the emission.
+    fn connect_drag_data_received_rows<F: Fn(TreeView, &SelectionData, Option<TreePath>, TreeViewDropPosition)
+        -> bool + 'static>(&self, f: F) -> u64;
 }
 
 mod tree_view {
-    use super::into_raw;
+    use super::{clear_drop_mark, connect_drop_mark, get_source_row, into_raw, is_drop_marked};
     use std::mem::transmute;
+    use libc::{c_int, c_uint};
     use glib::signal::connect;
     use glib::translate::*;
     use traits::FFIWidget;
-    use ffi::{Gboolean, C_GtkTreeIter, C_GtkTreePath, C_GtkTreeView, C_GtkTreeViewColumn};
-    use {TreeIter, TreePath, TreeView, TreeViewColumn};
+    use ffi;
+    use ffi::{Gboolean, C_GtkSelectionData, C_GtkTreeIter, C_GtkTreePath, C_GtkTreeView, C_GtkTreeViewColumn};
+    use gdk_ffi::C_GdkDragContext;
+    use {SelectionData, TreeIter, TreePath, TreeView, TreeViewColumn, TreeViewDropPosition};
 
     impl super::TreeViewSignals for TreeView {
         fn connect_columns_changed<F: Fn(TreeView) + 'static>(&self, f: F) -> u64 {
@@ -954,6 +1176,27 @@ mod tree_view {
                     transmute(bool_trampoline), into_raw(f) as *mut _)
             }
         }
+
+        fn connect_drag_data_get_rows<F: Fn(TreeView, &SelectionData, Option<TreePath>, u32) + 'static>(&self,
+                f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(TreeView, &SelectionData, Option<TreePath>, u32) + 'static>> =
+                    Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "drag-data-get",
+                    transmute(drag_data_get_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_drag_data_received_rows<F: Fn(TreeView, &SelectionData, Option<TreePath>,
+                TreeViewDropPosition) -> bool + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(TreeView, &SelectionData, Option<TreePath>, TreeViewDropPosition) -> bool + 'static>> =
+                    Box::new(Box::new(f));
+                connect_drop_mark(self.unwrap_widget());
+                connect(self.unwrap_widget() as *mut _, "drag-data-received",
+                    transmute(drag_data_received_trampoline), into_raw(f) as *mut _)
+            }
+        }
     }
 
     extern "C" fn void_trampoline(this: *mut C_GtkTreeView, f: &Box<Fn(TreeView) + 'static>) {
@@ -978,21 +1221,216 @@ mod tree_view {
 
     extern "C" fn path_column_trampoline(this: *mut C_GtkTreeView, path: *mut C_GtkTreePath,
             column: *mut C_GtkTreeViewColumn, f: &Box<Fn(TreeView, TreePath, TreeViewColumn) + 'static>) {
-        f(FFIWidget::wrap_widget(this as *mut _), TreePath::wrap_pointer(path),
+        f(FFIWidget::wrap_widget(this as *mut _), TreePath::wrap_borrowed_pointer(path),
             TreeViewColumn::wrap_pointer(column));
     }
 
     extern "C" fn iter_path_trampoline(this: *mut C_GtkTreeView, iter: *mut C_GtkTreeIter,
             path: *mut C_GtkTreePath, f: &Box<Fn(TreeView, &mut TreeIter, TreePath) + 'static>) {
         f(FFIWidget::wrap_widget(this as *mut _), &mut TreeIter::wrap_pointer(iter),
-            TreePath::wrap_pointer(path));
+            TreePath::wrap_borrowed_pointer(path));
     }
 
     extern "C" fn iter_path_bool_trampoline(this: *mut C_GtkTreeView, iter: *mut C_GtkTreeIter,
             path: *mut C_GtkTreePath, f: &Box<Fn(TreeView, &mut TreeIter, TreePath) -> bool + 'static>)
             -> Gboolean {
         f(FFIWidget::wrap_widget(this as *mut _), &mut TreeIter::wrap_pointer(iter),
-            TreePath::wrap_pointer(path)).to_glib()
+            TreePath::wrap_borrowed_pointer(path)).to_glib()
+    }
+
+    extern "C" fn drag_data_get_trampoline(this: *mut C_GtkTreeView, context: *mut C_GdkDragContext,
+            selection_data: *mut C_GtkSelectionData, info: c_uint, _: c_uint,
+            f: &Box<Fn(TreeView, &SelectionData, Option<TreePath>, u32) + 'static>) {
+        let row = unsafe { get_source_row(context, b"gtk-tree-view-source-row\0") };
+        f(FFIWidget::wrap_widget(this as *mut _), &SelectionData::wrap_pointer(selection_data), row, info);
+    }
+
+    extern "C" fn drag_data_received_trampoline(this: *mut C_GtkTreeView, context: *mut C_GdkDragContext,
+            x: c_int, y: c_int, selection_data: *mut C_GtkSelectionData, _: c_uint, time: c_uint,
+            f: &Box<Fn(TreeView, &SelectionData, Option<TreePath>, TreeViewDropPosition) -> bool + 'static>) {
+        if !unsafe { is_drop_marked(context) } {
+            return
+        }
+        let tree_view: TreeView = FFIWidget::wrap_widget(this as *mut _);
+        let (path, pos) = match tree_view.get_dest_row_at_pos(x, y) {
+            Some((path, pos)) => (Some(path), pos),
+            None => (None, TreeViewDropPosition::After)
+        };
+        let success = f(tree_view, &SelectionData::wrap_pointer(selection_data), path, pos);
+
+        unsafe {
+            ffi::gtk_drag_finish(context, success.to_glib(), 0, time);
+            clear_drop_mark(context);
+            ffi::g_signal_stop_emission_by_name(this as *mut _, "drag-data-received".to_glib_none().0);
+        }
+    }
+}
+
+pub trait IconViewSignals {
+    /// The closure receives the item being dragged, None if it was deleted since the drag
+    /// began, and the info of the requested target.
+    fn connect_drag_data_get_rows<F: Fn(IconView, &SelectionData, Option<TreePath>, u32) + 'static>(&self,
+        f: F) -> u64;
+    /// The closure receives the item and position the data was dropped at and returns
+    /// whether the drop succeeded. This replaces the default handler on drops, the source
+    /// row is never deleted: remove it from its model in the closure to implement a move.
+    /// The data requested while hovering items is still handled by the default handler.
+    /// A drop is handled by the first connected closure only, it stops the emission.
+    fn connect_drag_data_received_rows<F: Fn(IconView, &SelectionData, Option<TreePath>, IconViewDropPosition)
+        -> bool + 'static>(&self, f: F) -> u64;
+}
+
+mod icon_view {
+    use super::{clear_drop_mark, connect_drop_mark, get_source_row, into_raw, is_drop_marked};
+    use std::mem::transmute;
+    use libc::{c_int, c_uint};
+    use glib::signal::connect;
+    use glib::translate::*;
+    use traits::FFIWidget;
+    use ffi;
+    use ffi::{C_GtkIconView, C_GtkSelectionData};
+    use gdk_ffi::C_GdkDragContext;
+    use {IconView, IconViewDropPosition, SelectionData, TreePath};
+
+    impl super::IconViewSignals for IconView {
+        fn connect_drag_data_get_rows<F: Fn(IconView, &SelectionData, Option<TreePath>, u32) + 'static>(&self,
+                f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(IconView, &SelectionData, Option<TreePath>, u32) + 'static>> =
+                    Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "drag-data-get",
+                    transmute(drag_data_get_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_drag_data_received_rows<F: Fn(IconView, &SelectionData, Option<TreePath>,
+                IconViewDropPosition) -> bool + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(IconView, &SelectionData, Option<TreePath>, IconViewDropPosition) -> bool + 'static>> =
+                    Box::new(Box::new(f));
+                connect_drop_mark(self.unwrap_widget());
+                connect(self.unwrap_widget() as *mut _, "drag-data-received",
+                    transmute(drag_data_received_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn drag_data_get_trampoline(this: *mut C_GtkIconView, context: *mut C_GdkDragContext,
+            selection_data: *mut C_GtkSelectionData, info: c_uint, _: c_uint,
+            f: &Box<Fn(IconView, &SelectionData, Option<TreePath>, u32) + 'static>) {
+        let row = unsafe { get_source_row(context, b"gtk-icon-view-source-row\0") };
+        f(FFIWidget::wrap_widget(this as *mut _), &SelectionData::wrap_pointer(selection_data), row, info);
+    }
+
+    extern "C" fn drag_data_received_trampoline(this: *mut C_GtkIconView, context: *mut C_GdkDragContext,
+            x: c_int, y: c_int, selection_data: *mut C_GtkSelectionData, _: c_uint, time: c_uint,
+            f: &Box<Fn(IconView, &SelectionData, Option<TreePath>, IconViewDropPosition) -> bool + 'static>) {
+        if !unsafe { is_drop_marked(context) } {
+            return
+        }
+        let icon_view: IconView = FFIWidget::wrap_widget(this as *mut _);
+        let (path, pos) = match icon_view.get_dest_item_at_pos(x, y) {
+            Some((path, pos)) => (Some(path), pos),
+            None => (None, IconViewDropPosition::NoDrop)
+        };
+        let success = f(icon_view, &SelectionData::wrap_pointer(selection_data), path, pos);
+
+        unsafe {
+            ffi::gtk_drag_finish(context, success.to_glib(), 0, time);
+            clear_drop_mark(context);
+            ffi::g_signal_stop_emission_by_name(this as *mut _, "drag-data-received".to_glib_none().0);
+        }
+    }
+}
+
+#[cfg(feature = "gtk_3_10")]
+pub trait ListBoxSignals {
+    fn connect_row_activated<F: Fn(ListBox, ListBoxRow) + 'static>(&self, f: F) -> u64;
+    /// The row is None when the selection is cleared. Only emitted in the single and browse
+    /// selection modes.
+    fn connect_row_selected<F: Fn(ListBox, Option<ListBoxRow>) + 'static>(&self, f: F) -> u64;
+}
+
+#[cfg(feature = "gtk_3_10")]
+mod list_box {
+    use super::into_raw;
+    use std::mem::transmute;
+    use glib::signal::connect;
+    use traits::FFIWidget;
+    use ffi::{C_GtkListBox, C_GtkListBoxRow};
+    use {ListBox, ListBoxRow};
+
+    impl super::ListBoxSignals for ListBox {
+        fn connect_row_activated<F: Fn(ListBox, ListBoxRow) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ListBox, ListBoxRow) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "row-activated",
+                    transmute(row_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_row_selected<F: Fn(ListBox, Option<ListBoxRow>) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(ListBox, Option<ListBoxRow>) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "row-selected",
+                    transmute(option_row_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn row_trampoline(this: *mut C_GtkListBox, row: *mut C_GtkListBoxRow,
+            f: &Box<Fn(ListBox, ListBoxRow) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _), FFIWidget::wrap_widget(row as *mut _));
+    }
+
+    extern "C" fn option_row_trampoline(this: *mut C_GtkListBox, row: *mut C_GtkListBoxRow,
+            f: &Box<Fn(ListBox, Option<ListBoxRow>) + 'static>) {
+        let row = if row.is_null() { None } else { Some(FFIWidget::wrap_widget(row as *mut _)) };
+
+        f(FFIWidget::wrap_widget(this as *mut _), row);
+    }
+}
+
+#[cfg(feature = "gtk_3_12")]
+pub trait FlowBoxSignals {
+    fn connect_child_activated<F: Fn(FlowBox, FlowBoxChild) + 'static>(&self, f: F) -> u64;
+    fn connect_selected_children_changed<F: Fn(FlowBox) + 'static>(&self, f: F) -> u64;
+}
+
+#[cfg(feature = "gtk_3_12")]
+mod flow_box {
+    use super::into_raw;
+    use std::mem::transmute;
+    use glib::signal::connect;
+    use traits::FFIWidget;
+    use ffi::{C_GtkFlowBox, C_GtkFlowBoxChild};
+    use {FlowBox, FlowBoxChild};
+
+    impl super::FlowBoxSignals for FlowBox {
+        fn connect_child_activated<F: Fn(FlowBox, FlowBoxChild) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(FlowBox, FlowBoxChild) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "child-activated",
+                    transmute(child_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_selected_children_changed<F: Fn(FlowBox) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(FlowBox) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "selected-children-changed",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn child_trampoline(this: *mut C_GtkFlowBox, child: *mut C_GtkFlowBoxChild,
+            f: &Box<Fn(FlowBox, FlowBoxChild) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _), FFIWidget::wrap_widget(child as *mut _));
+    }
+
+    extern "C" fn void_trampoline(this: *mut C_GtkFlowBox, f: &Box<Fn(FlowBox) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _));
     }
 }
 
@@ -1069,6 +1507,79 @@ mod range {
     }
 }
 
+pub trait MenuItemSignals {
+    /// Emitted when the item is activated, by a click or by its accelerator.
+    fn connect_activate<F: Fn(MenuItem) + 'static>(&self, f: F) -> u64;
+    /// Emitted to get the width of the toggle area of the item, the closure can change the width
+    /// it gets. The default handler runs afterwards, which most items override in the process.
+    fn connect_toggle_size_request<F: Fn(MenuItem, &mut i32) + 'static>(&self, f: F) -> u64;
+}
+
+mod menu_item {
+    use super::into_raw;
+    use std::mem::transmute;
+    use libc::c_int;
+    use glib::signal::connect;
+    use traits::{FFIWidget, MenuItemTrait};
+    use ffi::C_GtkMenuItem;
+    use MenuItem;
+
+    impl<T: FFIWidget + MenuItemTrait> super::MenuItemSignals for T {
+        fn connect_activate<F: Fn(MenuItem) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(MenuItem) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "activate",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+
+        fn connect_toggle_size_request<F: Fn(MenuItem, &mut i32) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(MenuItem, &mut i32) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "toggle-size-request",
+                    transmute(toggle_size_request_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn void_trampoline(this: *mut C_GtkMenuItem, f: &Box<Fn(MenuItem) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _));
+    }
+
+    extern "C" fn toggle_size_request_trampoline(this: *mut C_GtkMenuItem, requisition: *mut c_int,
+            f: &Box<Fn(MenuItem, &mut i32) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _), unsafe { &mut *(requisition as *mut i32) });
+    }
+}
+
+pub trait CheckMenuItemSignals {
+    /// Emitted when the active state of the item changes.
+    fn connect_toggled<F: Fn(CheckMenuItem) + 'static>(&self, f: F) -> u64;
+}
+
+mod check_menu_item {
+    use super::into_raw;
+    use std::mem::transmute;
+    use glib::signal::connect;
+    use traits::{FFIWidget, CheckMenuItemTrait};
+    use ffi::C_GtkCheckMenuItem;
+    use CheckMenuItem;
+
+    impl<T: FFIWidget + CheckMenuItemTrait> super::CheckMenuItemSignals for T {
+        fn connect_toggled<F: Fn(CheckMenuItem) + 'static>(&self, f: F) -> u64 {
+            unsafe {
+                let f: Box<Box<Fn(CheckMenuItem) + 'static>> = Box::new(Box::new(f));
+                connect(self.unwrap_widget() as *mut _, "toggled",
+                    transmute(void_trampoline), into_raw(f) as *mut _)
+            }
+        }
+    }
+
+    extern "C" fn void_trampoline(this: *mut C_GtkCheckMenuItem, f: &Box<Fn(CheckMenuItem) + 'static>) {
+        f(FFIWidget::wrap_widget(this as *mut _));
+    }
+}
+
 impl Adjustment {
     pub fn connect_value_changed<F: Fn(Adjustment) + 'static>(&self, f: F) -> u64 {
         unsafe {
@@ -1112,3 +1623,213 @@ extern "C" fn tree_view_column_trampoline(this: *mut C_GtkTreeViewColumn,
         f: &Box<Fn(TreeViewColumn) + 'static>) {
     f(TreeViewColumn::wrap_pointer(this))
 }
+
+// The model signals are emitted by every model, a ListStore or a TreeStore can be observed
+// through its `get_model`.
+impl TreeModel {
+    pub fn connect_row_changed<F: Fn(TreeModel, TreePath, &TreeIter) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, TreePath, &TreeIter) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "row-changed",
+                transmute(tree_model_path_iter_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    pub fn connect_row_inserted<F: Fn(TreeModel, TreePath, &TreeIter) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, TreePath, &TreeIter) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "row-inserted",
+                transmute(tree_model_path_iter_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    pub fn connect_row_has_child_toggled<F: Fn(TreeModel, TreePath, &TreeIter) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, TreePath, &TreeIter) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "row-has-child-toggled",
+                transmute(tree_model_path_iter_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// The path is the one the row had before it was deleted, it can't be turned into an iter.
+    pub fn connect_row_deleted<F: Fn(TreeModel, TreePath) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, TreePath) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "row-deleted",
+                transmute(tree_model_path_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// The closure gets the path and iter of the parent whose children were reordered (None
+    /// for the top level) and the new order, `new_order[new_position] = old_position`.
+    pub fn connect_rows_reordered<F: Fn(TreeModel, TreePath, Option<&TreeIter>, &[i32]) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, TreePath, Option<&TreeIter>, &[i32]) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "rows-reordered",
+                transmute(tree_model_rows_reordered_trampoline), into_raw(f) as *mut _)
+        }
+    }
+}
+
+fn borrow_tree_model(this: *mut C_GtkTreeModel) -> TreeModel {
+    unsafe { ::glib_ffi::g_object_ref(this as *mut _) };
+    TreeModel::wrap_pointer(this)
+}
+
+extern "C" fn tree_model_path_iter_trampoline(this: *mut C_GtkTreeModel, path: *mut C_GtkTreePath,
+        iter: *mut C_GtkTreeIter, f: &Box<Fn(TreeModel, TreePath, &TreeIter) + 'static>) {
+    let mut iter = TreeIter::wrap_pointer(iter);
+
+    iter.set_model_pointer(this);
+    f(borrow_tree_model(this), TreePath::wrap_borrowed_pointer(path), &iter)
+}
+
+extern "C" fn tree_model_path_trampoline(this: *mut C_GtkTreeModel, path: *mut C_GtkTreePath,
+        f: &Box<Fn(TreeModel, TreePath) + 'static>) {
+    f(borrow_tree_model(this), TreePath::wrap_borrowed_pointer(path))
+}
+
+extern "C" fn tree_model_rows_reordered_trampoline(this: *mut C_GtkTreeModel, path: *mut C_GtkTreePath,
+        iter: *mut C_GtkTreeIter, new_order: *mut c_int,
+        f: &Box<Fn(TreeModel, TreePath, Option<&TreeIter>, &[i32]) + 'static>) {
+    let model = borrow_tree_model(this);
+    let iter = if iter.is_null() {
+        None
+    } else {
+        let mut iter = TreeIter::wrap_pointer(iter);
+
+        iter.set_model_pointer(this);
+        Some(iter)
+    };
+    let n_children = unsafe {
+        ffi::gtk_tree_model_iter_n_children(this, iter.as_ref().map_or(::std::ptr::null_mut(), |i| i.unwrap_pointer()))
+    };
+    let new_order = unsafe { slice::from_raw_parts(new_order as *const i32, n_children as usize) };
+
+    f(model, TreePath::wrap_borrowed_pointer(path), iter.as_ref(), new_order)
+}
+
+impl Application {
+    /// Emitted in the primary instance when the application is registered, before `activate` or
+    /// `open`. The application menu, menubar and actions should be set up here.
+    pub fn connect_startup<F: Fn(Application) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(Application) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "startup",
+                transmute(application_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// Emitted in the primary instance when the application is run without files, including
+    /// from other instances. The first window is usually presented here.
+    pub fn connect_activate<F: Fn(Application) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(Application) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "activate",
+                transmute(application_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// Emitted in the primary instance when the application is run with files, it must have
+    /// been created with `ApplicationFlags::HandlesOpen`. The closure gets the URIs of the files
+    /// and a hint, usually an empty string.
+    pub fn connect_open<F: Fn(Application, &[String], &str) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(Application, &[String], &str) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "open",
+                transmute(application_open_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// Emitted when `run` returns, once the windows have been closed.
+    pub fn connect_shutdown<F: Fn(Application) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(Application) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "shutdown",
+                transmute(application_trampoline), into_raw(f) as *mut _)
+        }
+    }
+}
+
+extern "C" fn application_trampoline(this: *mut C_GtkApplication, f: &Box<Fn(Application) + 'static>) {
+    f(Application::wrap_pointer(this))
+}
+
+extern "C" fn application_open_trampoline(this: *mut C_GtkApplication, files: *mut *mut C_GFile, n_files: c_int,
+        hint: *const c_char, f: &Box<Fn(Application, &[String], &str) + 'static>) {
+    let files = unsafe { slice::from_raw_parts(files, n_files as usize) };
+    let uris: Vec<String> = files.iter()
+        .map(|&file| unsafe { from_glib_full(ffi::g_file_get_uri(file)) })
+        .collect();
+    let hint: String = unsafe { from_glib_none(hint) };
+
+    f(Application::wrap_pointer(this), &uris, &hint)
+}
+
+impl SimpleAction {
+    /// The closure gets the parameter, None if the action takes none. Connecting a handler
+    /// replaces the default one which toggles a boolean state.
+    pub fn connect_activate<F: Fn(SimpleAction, Option<&Variant>) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(SimpleAction, Option<&Variant>) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "activate",
+                transmute(simple_action_variant_trampoline), into_raw(f) as *mut _)
+        }
+    }
+
+    /// The closure gets the requested state. Connecting a handler replaces the default one,
+    /// the state then only changes if the handler calls `set_state`.
+    pub fn connect_change_state<F: Fn(SimpleAction, Option<&Variant>) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(SimpleAction, Option<&Variant>) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "change-state",
+                transmute(simple_action_variant_trampoline), into_raw(f) as *mut _)
+        }
+    }
+}
+
+extern "C" fn simple_action_variant_trampoline(this: *mut C_GSimpleAction, value: *mut C_GVariant,
+        f: &Box<Fn(SimpleAction, Option<&Variant>) + 'static>) {
+    let value = if value.is_null() {
+        None
+    } else {
+        Some(Variant::wrap_borrowed_pointer(value))
+    };
+
+    f(SimpleAction::wrap_pointer(this), value.as_ref())
+}
+
+impl AccelMap {
+    /// Emitted when the accelerator of a path changes, the closure gets the path, the new key
+    /// and the new modifiers.
+    pub fn connect_changed<F: Fn(AccelMap, &str, u32, ModifierType) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(AccelMap, &str, u32, ModifierType) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "changed",
+                transmute(accel_map_changed_trampoline), into_raw(f) as *mut _)
+        }
+    }
+}
+
+extern "C" fn accel_map_changed_trampoline(this: *mut C_GtkAccelMap, accel_path: *const c_char, accel_key: c_uint,
+        accel_mods: ModifierType, f: &Box<Fn(AccelMap, &str, u32, ModifierType) + 'static>) {
+    let accel_path: String = unsafe { from_glib_none(accel_path) };
+
+    f(AccelMap::wrap_pointer(this), &accel_path, accel_key, accel_mods)
+}
+
+impl Clipboard {
+    /// Emitted when the owner of the clipboard changes, e.g. to refresh a paste action.
+    pub fn connect_owner_change<F: Fn(Clipboard, &EventOwnerChange) + 'static>(&self, f: F) -> u64 {
+        unsafe {
+            let f: Box<Box<Fn(Clipboard, &EventOwnerChange) + 'static>> = Box::new(Box::new(f));
+            connect(self.unwrap_pointer() as *mut _, "owner-change",
+                transmute(clipboard_owner_change_trampoline), into_raw(f) as *mut _)
+        }
+    }
+}
+
+extern "C" fn clipboard_owner_change_trampoline(this: *mut C_GtkClipboard, event: *mut EventAny,
+        f: &Box<Fn(Clipboard, &EventOwnerChange) + 'static>) {
+    unsafe { f(Clipboard::wrap_pointer(this), transmute(event)) }
+}
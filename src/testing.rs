@@ -0,0 +1,192 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Helpers to drive a user interface from tests
+//!
+//! The simulated events go through the windowing system, the widgets must be realized and,
+//! for the `simulate_*` functions, mapped. Under X a headless server such as Xvfb is enough.
+//! GTK must have been initialized on the thread running the test, so the tests using these
+//! helpers shouldn't run in parallel.
+
+use glib::translate::{from_glib_full, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use gdk;
+use gdk_ffi;
+use ffi;
+use cast::GTK_SPINBUTTON;
+use {events_pending, main_iteration, main_iteration_do, FFIWidget, SpinButton, Widget, WidgetTrait, WidgetType};
+
+/// Dispatches the pending events and returns once there are none left.
+pub fn run_until_idle() {
+    while events_pending() {
+        main_iteration();
+    }
+}
+
+/// Runs the main loop until `condition` returns true or `timeout_ms` milliseconds have
+/// passed, returns false in the latter case.
+pub fn run_until<F: FnMut() -> bool>(timeout_ms: u32, mut condition: F) -> bool {
+    let deadline = unsafe { ffi::g_get_monotonic_time() } + timeout_ms as i64 * 1000;
+
+    loop {
+        if condition() {
+            return true;
+        }
+        if unsafe { ffi::g_get_monotonic_time() } >= deadline {
+            return false;
+        }
+        if !main_iteration_do(false) && !events_pending() {
+            unsafe { ffi::g_usleep(1000) }
+        }
+    }
+}
+
+/// Runs the main loop for `duration_ms` milliseconds, e.g. to let timeouts fire.
+pub fn run_for(duration_ms: u32) {
+    run_until(duration_ms, || false);
+}
+
+/// Waits until `widget` has been drawn.
+#[cfg(feature = "gtk_3_10")]
+pub fn wait_for_draw<T: WidgetTrait>(widget: &T) {
+    unsafe { ffi::gtk_test_widget_wait_for_draw(widget.unwrap_widget()) }
+}
+
+/// Clicks the middle of `widget` with the mouse `button` (1 is the primary button), returns
+/// false if the events couldn't be sent.
+pub fn click<T: WidgetTrait>(widget: &T, button: u32, modifiers: gdk::ModifierType) -> bool {
+    unsafe { to_bool(ffi::gtk_test_widget_click(widget.unwrap_widget(), button, modifiers)) }
+}
+
+/// Presses and releases `keyval`, a GDK key value, on the toplevel of `widget`.
+pub fn send_key<T: WidgetTrait>(widget: &T, keyval: u32, modifiers: gdk::ModifierType) -> bool {
+    unsafe { to_bool(ffi::gtk_test_widget_send_key(widget.unwrap_widget(), keyval, modifiers)) }
+}
+
+/// Sends the key presses typing `text`, which usually goes to the focused widget of the toplevel
+/// of `widget`. Stops at the first key which couldn't be sent and returns false.
+pub fn type_text<T: WidgetTrait>(widget: &T, text: &str) -> bool {
+    for c in text.chars() {
+        let keyval = unsafe { ffi::gdk_unicode_to_keyval(c as u32) };
+
+        if !send_key(widget, keyval, gdk::ModifierType::empty()) {
+            return false;
+        }
+        run_until_idle();
+    }
+    true
+}
+
+/// Presses and releases `button` at (`x`, `y`), relative to the allocation of `widget`, through
+/// the windowing system.
+pub fn simulate_button<T: WidgetTrait>(widget: &T, x: i32, y: i32, button: u32,
+                                       modifiers: gdk::ModifierType) -> bool {
+    let (window, x, y) = match get_event_window(widget, x, y) {
+        Some(target) => target,
+        None => return false
+    };
+
+    unsafe {
+        to_bool(ffi::gdk_test_simulate_button(window, x, y, button, modifiers, gdk::EventType::ButtonPress)) &&
+        to_bool(ffi::gdk_test_simulate_button(window, x, y, button, modifiers, gdk::EventType::ButtonRelease))
+    }
+}
+
+/// Presses and releases `keyval` on the window of `widget` through the windowing system.
+pub fn simulate_key<T: WidgetTrait>(widget: &T, keyval: u32, modifiers: gdk::ModifierType) -> bool {
+    let (window, _, _) = match get_event_window(widget, 0, 0) {
+        Some(target) => target,
+        None => return false
+    };
+
+    unsafe {
+        to_bool(ffi::gdk_test_simulate_key(window, -1, -1, keyval, modifiers, gdk::EventType::KeyPress)) &&
+        to_bool(ffi::gdk_test_simulate_key(window, -1, -1, keyval, modifiers, gdk::EventType::KeyRelease))
+    }
+}
+
+/// Sets the value of a Range or SpinButton to `percentage` of its adjustment's range.
+pub fn slider_set_perc<T: WidgetTrait>(widget: &T, percentage: f64) {
+    unsafe { ffi::gtk_test_slider_set_perc(widget.unwrap_widget(), percentage) }
+}
+
+/// Returns the value of a Range or SpinButton, 0 for other widgets.
+pub fn slider_get_value<T: WidgetTrait>(widget: &T) -> f64 {
+    unsafe { ffi::gtk_test_slider_get_value(widget.unwrap_widget()) }
+}
+
+/// Clicks the up or down arrow of `spinner` with `button`.
+pub fn spin_button_click(spinner: &SpinButton, button: u32, upwards: bool) -> bool {
+    unsafe {
+        to_bool(ffi::gtk_test_spin_button_click(GTK_SPINBUTTON(spinner.unwrap_widget()), button,
+                                                to_gboolean(upwards)))
+    }
+}
+
+/// Sets the text of a Label, Editable or TextView.
+pub fn text_set<T: WidgetTrait>(widget: &T, text: &str) {
+    unsafe { ffi::gtk_test_text_set(widget.unwrap_widget(), text.to_glib_none().0) }
+}
+
+/// Returns the text of a Label, Editable or TextView.
+pub fn text_get<T: WidgetTrait>(widget: &T) -> Option<String> {
+    unsafe { from_glib_full(ffi::gtk_test_text_get(widget.unwrap_widget())) }
+}
+
+/// Returns the label among `widget` and its descendants whose text matches the glob
+/// `label_pattern`, e.g. "Name*".
+pub fn find_label<T: WidgetTrait>(widget: &T, label_pattern: &str) -> Option<Widget> {
+    let tmp = unsafe { ffi::gtk_test_find_label(widget.unwrap_widget(), label_pattern.to_glib_none().0) };
+
+    wrap_found(tmp)
+}
+
+/// Returns the `U` closest to `base_widget` among its siblings and the descendants of its
+/// ancestors, e.g. the entry of the form field `base_widget` is the label of.
+pub fn find_sibling<T: WidgetTrait, U: WidgetTrait + WidgetType>(base_widget: &T) -> Option<U> {
+    let gtype = unsafe { ffi::g_type_from_name(U::static_type_name().to_glib_none().0) };
+
+    if gtype == 0 {
+        return None;
+    }
+    let tmp = unsafe { ffi::gtk_test_find_sibling(base_widget.unwrap_widget(), gtype) };
+
+    wrap_found(tmp)
+}
+
+/// Returns the `U` next to the label matching the glob `label_pattern` among `widget` and its
+/// descendants, i.e. `find_sibling` applied to the result of `find_label`.
+pub fn find_widget<T: WidgetTrait, U: WidgetTrait + WidgetType>(widget: &T, label_pattern: &str) -> Option<U> {
+    let gtype = unsafe { ffi::g_type_from_name(U::static_type_name().to_glib_none().0) };
+
+    if gtype == 0 {
+        return None;
+    }
+    let tmp = unsafe { ffi::gtk_test_find_widget(widget.unwrap_widget(), label_pattern.to_glib_none().0, gtype) };
+
+    wrap_found(tmp)
+}
+
+fn wrap_found<T: WidgetTrait>(pointer: *mut ffi::C_GtkWidget) -> Option<T> {
+    if pointer.is_null() {
+        None
+    } else {
+        Some(FFIWidget::wrap_widget(pointer))
+    }
+}
+
+/// Returns the GdkWindow receiving the events of `widget` and (`x`, `y`) relative to it, None if
+/// the widget isn't realized.
+fn get_event_window<T: WidgetTrait>(widget: &T, x: i32, y: i32) -> Option<(*mut gdk_ffi::C_GdkWindow, i32, i32)> {
+    let window = unsafe { ffi::gtk_widget_get_window(widget.unwrap_widget()) };
+
+    if window.is_null() {
+        None
+    } else if unsafe { to_bool(ffi::gtk_widget_get_has_window(widget.unwrap_widget())) } {
+        Some((window, x, y))
+    } else {
+        let allocation = widget.get_allocation();
+        Some((window, allocation.x + x, allocation.y + y))
+    }
+}
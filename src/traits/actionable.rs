@@ -7,6 +7,7 @@
 use glib::translate::{from_glib_none, ToGlibPtr};
 use cast::GTK_ACTIONABLE;
 use ffi;
+use Variant;
 
 pub trait ActionableTrait: ::WidgetTrait {
     fn get_action_name(&self) -> Option<String> {
@@ -27,4 +28,23 @@ pub trait ActionableTrait: ::WidgetTrait {
             ffi::gtk_actionable_set_detailed_action_name(GTK_ACTIONABLE(self.unwrap_widget()), detailed_action_name.to_glib_none().0)
         }
     }
+
+    /// Sets the parameter the action is activated with, for a stateful action the widget is
+    /// shown as active when the state equals `target_value`, like a radio button.
+    fn set_action_target_value(&self, target_value: Option<&Variant>) {
+        unsafe {
+            ffi::gtk_actionable_set_action_target_value(GTK_ACTIONABLE(self.unwrap_widget()),
+                                                        target_value.map_or(::std::ptr::null_mut(), |v| v.unwrap_pointer()))
+        }
+    }
+
+    fn get_action_target_value(&self) -> Option<Variant> {
+        let tmp = unsafe { ffi::gtk_actionable_get_action_target_value(GTK_ACTIONABLE(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Variant::wrap_borrowed_pointer(tmp))
+        }
+    }
 }
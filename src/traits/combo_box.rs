@@ -2,12 +2,15 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::mem::transmute;
+use std::ptr;
 use glib::translate::{from_glib_none, ToGlibPtr};
 use ffi;
 use glib::{to_bool, to_gboolean};
-use cast::GTK_COMBO_BOX;
+use cast::{GTK_BIN, GTK_COMBO_BOX};
 use glib_ffi;
 use libc::c_void;
+use {TreeIter, TreeModel};
 
 pub trait ComboBoxTrait: ::WidgetTrait + ::ContainerTrait + ::BinTrait {
     fn get_wrap_width(&self) -> i32 {
@@ -43,12 +46,15 @@ pub trait ComboBoxTrait: ::WidgetTrait + ::ContainerTrait + ::BinTrait {
     }
 
     fn get_active_iter(&self) -> Option<::TreeIter> {
-        let tmp_pointer = unsafe { ffi::gtk_combo_box_get_active_iter(GTK_COMBO_BOX(self.unwrap_widget())) };
+        let mut iter = ::TreeIter::new();
 
-        if tmp_pointer.is_null() {
-            None
+        if unsafe { to_bool(ffi::gtk_combo_box_get_active_iter(GTK_COMBO_BOX(self.unwrap_widget()), iter.unwrap_pointer())) } {
+            if let Some(model) = self.get_model() {
+                iter.set_model_pointer(model.unwrap_pointer());
+            }
+            Some(iter)
         } else {
-            Some(::TreeIter::wrap_pointer(tmp_pointer))
+            None
         }
     }
 
@@ -120,6 +126,20 @@ pub trait ComboBoxTrait: ::WidgetTrait + ::ContainerTrait + ::BinTrait {
         unsafe { to_bool(ffi::gtk_combo_box_get_has_entry(GTK_COMBO_BOX(self.unwrap_widget()))) }
     }
 
+    /// Returns the entry of a combo box created with an entry, None otherwise.
+    fn get_entry(&self) -> Option<::Entry> {
+        if !self.get_has_entry() {
+            return None;
+        }
+
+        let tmp_pointer = unsafe { ffi::gtk_bin_get_child(GTK_BIN(self.unwrap_widget())) };
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(::FFIWidget::wrap_widget(tmp_pointer))
+        }
+    }
+
     fn set_entry_text_column(&self, text_column: i32) {
         unsafe { ffi::gtk_combo_box_set_entry_text_column(GTK_COMBO_BOX(self.unwrap_widget()), text_column) }
     }
@@ -128,6 +148,24 @@ pub trait ComboBoxTrait: ::WidgetTrait + ::ContainerTrait + ::BinTrait {
         unsafe { ffi::gtk_combo_box_get_entry_text_column(GTK_COMBO_BOX(self.unwrap_widget())) }
     }
 
+    /// The closure returns whether a row is drawn as a separator.
+    fn set_row_separator_func<F: Fn(TreeModel, &TreeIter) -> bool + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, &TreeIter) -> bool + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_combo_box_set_row_separator_func(GTK_COMBO_BOX(self.unwrap_widget()),
+                                                      Some(row_separator_trampoline),
+                                                      transmute(f),
+                                                      Some(destroy_row_separator_closure))
+        }
+    }
+
+    fn unset_row_separator_func(&self) {
+        unsafe {
+            ffi::gtk_combo_box_set_row_separator_func(GTK_COMBO_BOX(self.unwrap_widget()), None,
+                                                      ptr::null_mut(), None)
+        }
+    }
+
     fn set_popup_fixed_width(&self, fixed: bool) {
         unsafe { ffi::gtk_combo_box_set_popup_fixed_width(GTK_COMBO_BOX(self.unwrap_widget()), to_gboolean(fixed)) }
     }
@@ -135,4 +173,22 @@ pub trait ComboBoxTrait: ::WidgetTrait + ::ContainerTrait + ::BinTrait {
     fn get_popup_fixed_width(&self) -> bool {
         unsafe { to_bool(ffi::gtk_combo_box_get_popup_fixed_width(GTK_COMBO_BOX(self.unwrap_widget()))) }
     }
-}
\ No newline at end of file
+}
+
+extern "C" fn row_separator_trampoline(model: *mut ffi::C_GtkTreeModel, iter: *mut ffi::C_GtkTreeIter,
+                                       data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(TreeModel, &TreeIter) -> bool + 'static> = transmute(data);
+        let mut iter = TreeIter::wrap_pointer(iter);
+
+        iter.set_model_pointer(model);
+        glib_ffi::g_object_ref(model as *mut c_void);
+        to_gboolean(f(TreeModel::wrap_pointer(model), &iter))
+    }
+}
+
+extern "C" fn destroy_row_separator_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(TreeModel, &TreeIter) -> bool + 'static>> = transmute(data);
+    }
+}
@@ -2,10 +2,15 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::mem::transmute;
 use libc::c_uint;
 
+use glib::translate::{from_glib, ToGlibPtr};
+use glib::{self, GlibContainer, Type, Value};
+use glib_ffi;
 use cast::GTK_CONTAINER;
-use ResizeMode;
+use selector::{Selector, SelectorError};
+use {FFIWidget, ResizeMode, Widget};
 use ffi;
 
 pub trait ContainerTrait: ::WidgetTrait {
@@ -44,4 +49,115 @@ pub trait ContainerTrait: ::WidgetTrait {
             ffi::gtk_container_set_border_width(GTK_CONTAINER(self.unwrap_widget()), border_width as c_uint);
         }
     }
+
+    /// Returns the non-internal children.
+    fn get_children(&self) -> Vec<Widget> {
+        let list: glib::List<*mut ffi::C_GtkWidget> = glib::GlibContainer::wrap(unsafe {
+            ffi::gtk_container_get_children(GTK_CONTAINER(self.unwrap_widget()))
+        });
+
+        list.iter().map(|&widget| FFIWidget::wrap_widget(widget)).collect()
+    }
+
+    /// Calls `callback` for each non-internal child.
+    fn foreach<F: FnMut(&Widget)>(&self, mut callback: F) {
+        let mut f: &mut FnMut(&Widget) = &mut callback;
+
+        unsafe {
+            ffi::gtk_container_foreach(GTK_CONTAINER(self.unwrap_widget()), Some(callback_trampoline),
+                                       &mut f as *mut _ as ffi::gpointer)
+        }
+    }
+
+    /// Calls `callback` for each child, including the internal ones.
+    fn forall<F: FnMut(&Widget)>(&self, mut callback: F) {
+        let mut f: &mut FnMut(&Widget) = &mut callback;
+
+        unsafe {
+            ffi::gtk_container_forall(GTK_CONTAINER(self.unwrap_widget()), Some(callback_trampoline),
+                                      &mut f as *mut _ as ffi::gpointer)
+        }
+    }
+
+    /// Returns None if the container has no child property named `property_name`.
+    fn child_get_property<T: ::WidgetTrait>(&self, child: &T, property_name: &str) -> Option<Value> {
+        let container = GTK_CONTAINER(self.unwrap_widget());
+        let value_type = unsafe { ffi::glue_container_child_property_type(container, property_name.to_glib_none().0) };
+
+        if value_type == 0 {
+            return None;
+        }
+        unsafe {
+            let mut value = Value::new();
+            glib_ffi::g_value_init(value.as_mut_ptr(), value_type);
+            ffi::gtk_container_child_get_property(container, child.unwrap_widget(), property_name.to_glib_none().0,
+                                                  value.as_mut_ptr());
+            Some(value)
+        }
+    }
+
+    fn child_set_property<T: ::WidgetTrait>(&self, child: &T, property_name: &str, value: &Value) {
+        unsafe {
+            ffi::gtk_container_child_set_property(GTK_CONTAINER(self.unwrap_widget()), child.unwrap_widget(),
+                                                  property_name.to_glib_none().0, value.as_ptr())
+        }
+    }
+
+    /// Sets the order in which the children get the focus, see `Widget::upcast`.
+    fn set_focus_chain(&self, focusable_widgets: &[Widget]) {
+        let mut list: glib::List<*mut ffi::C_GtkWidget> = glib::List::new();
+
+        for widget in focusable_widgets.iter() {
+            list.append(widget.unwrap_widget());
+        }
+        unsafe { ffi::gtk_container_set_focus_chain(GTK_CONTAINER(self.unwrap_widget()), list.unwrap()) }
+    }
+
+    fn unset_focus_chain(&self) {
+        unsafe { ffi::gtk_container_unset_focus_chain(GTK_CONTAINER(self.unwrap_widget())) }
+    }
+
+    fn get_focus_child(&self) -> Option<Widget> {
+        let tmp_pointer = unsafe { ffi::gtk_container_get_focus_child(GTK_CONTAINER(self.unwrap_widget())) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp_pointer))
+        }
+    }
+
+    fn set_focus_child<T: ::WidgetTrait>(&self, child: &T) {
+        unsafe { ffi::gtk_container_set_focus_child(GTK_CONTAINER(self.unwrap_widget()), child.unwrap_widget()) }
+    }
+
+    /// Returns the type of the children the container accepts, `Type::Invalid` if it can't take
+    /// any more.
+    fn child_type(&self) -> Type {
+        unsafe { from_glib(ffi::gtk_container_child_type(GTK_CONTAINER(self.unwrap_widget()))) }
+    }
+
+    /// Returns the first descendant matching `selector` which is a `T`, see `selector` for the
+    /// syntax. Use `Widget` as `T` to accept any widget.
+    ///
+    /// Returns an error if `selector` is invalid.
+    fn find<T: ::WidgetTrait + ::WidgetType>(&self, selector: &str) -> Result<Option<T>, SelectorError> {
+        Ok(try!(Selector::parse(selector)).find(self))
+    }
+
+    /// Returns all the descendants matching `selector` which are a `T`, in depth-first order.
+    ///
+    /// Returns an error if `selector` is invalid.
+    fn find_all<T: ::WidgetTrait + ::WidgetType>(&self, selector: &str) -> Result<Vec<T>, SelectorError> {
+        Ok(try!(Selector::parse(selector)).find_all(self))
+    }
+}
+
+extern "C" fn callback_trampoline(widget: *mut ffi::C_GtkWidget, data: ffi::gpointer) {
+    unsafe {
+        let f: &mut &mut FnMut(&Widget) = transmute(data);
+        let widget: Widget = FFIWidget::wrap_widget(widget);
+
+        f(&widget)
+    }
 }
@@ -7,6 +7,11 @@ pub trait FFIWidget: Sized {
     fn wrap_widget(widget: *mut ::ffi::C_GtkWidget) -> Self;
 }
 
+/// Gives the name of the GType a wrapper stands for, used to check downcasts.
+pub trait WidgetType {
+    fn static_type_name() -> &'static str;
+}
+
 pub use self::widget::WidgetTrait;
 pub use self::container::ContainerTrait;
 pub use self::window::WindowTrait;
@@ -659,4 +659,76 @@ pub trait WidgetTrait: ::FFIWidget + ::GObjectTrait {
 
         unsafe { ffi::gtk_widget_destroyed(self.unwrap_widget(), &mut tmp) }
     }
-}
\ No newline at end of file
+
+    /// Returns the actual type of the widget, which may be a subclass of the wrapper type.
+    fn get_widget_type(&self) -> GType {
+        unsafe { ffi::glue_object_type(::cast::G_OBJECT(self.unwrap_widget())) }
+    }
+
+    fn get_allocation(&self) -> gdk::Rectangle {
+        let mut allocation = gdk::Rectangle { x: 0, y: 0, width: 0, height: 0 };
+
+        unsafe { ffi::gtk_widget_get_allocation(self.unwrap_widget(), &mut allocation as *mut _ as *mut _) }
+        allocation
+    }
+
+    fn add_style_class(&self, class_name: &str) {
+        unsafe {
+            ffi::gtk_style_context_add_class(ffi::gtk_widget_get_style_context(self.unwrap_widget()),
+                                             class_name.to_glib_none().0)
+        }
+    }
+
+    fn remove_style_class(&self, class_name: &str) {
+        unsafe {
+            ffi::gtk_style_context_remove_class(ffi::gtk_widget_get_style_context(self.unwrap_widget()),
+                                                class_name.to_glib_none().0)
+        }
+    }
+
+    fn has_style_class(&self, class_name: &str) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_style_context_has_class(ffi::gtk_widget_get_style_context(self.unwrap_widget()),
+                                                     class_name.to_glib_none().0))
+        }
+    }
+
+    /// Returns the CSS classes of the widget style context.
+    fn get_style_classes(&self) -> Vec<String> {
+        let list: glib::List<*const c_char> = glib::GlibContainer::wrap(unsafe {
+            ffi::gtk_style_context_list_classes(ffi::gtk_widget_get_style_context(self.unwrap_widget()))
+        });
+
+        list.iter().map(|&class_name| unsafe { from_glib_none(class_name) }).collect()
+    }
+
+    /// Makes the actions of `group` available to the widget and its descendants as
+    /// "<name>.<action>", None removes the group inserted as `name`.
+    #[cfg(feature = "gtk_3_6")]
+    fn insert_action_group(&self, name: &str, group: Option<&::SimpleActionGroup>) {
+        unsafe {
+            ffi::gtk_widget_insert_action_group(self.unwrap_widget(), name.to_glib_none().0,
+                                                group.map_or(::std::ptr::null_mut(), |g| g.unwrap_action_group()))
+        }
+    }
+
+    /// Emits `accel_signal`, e.g. "activate" or "clicked", when `accel_key` is pressed with
+    /// `accel_mods` in a window of `accel_group`.
+    fn add_accelerator(&self, accel_signal: &str, accel_group: &::AccelGroup, accel_key: u32,
+                       accel_mods: gdk::ModifierType, accel_flags: &[::AccelFlags]) {
+        let accel_flags = accel_flags.iter().fold(0, |acc, &flag| acc | flag as ::libc::c_uint);
+
+        unsafe {
+            ffi::gtk_widget_add_accelerator(self.unwrap_widget(), accel_signal.to_glib_none().0,
+                                            accel_group.unwrap_pointer(), accel_key, accel_mods, accel_flags)
+        }
+    }
+
+    /// Removes an accelerator added with `add_accelerator`, returns false if there is none.
+    fn remove_accelerator(&self, accel_group: &::AccelGroup, accel_key: u32, accel_mods: gdk::ModifierType) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_widget_remove_accelerator(self.unwrap_widget(), accel_group.unwrap_pointer(),
+                                                       accel_key, accel_mods))
+        }
+    }
+}
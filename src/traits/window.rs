@@ -4,8 +4,11 @@
 
 use glib::translate::{from_glib_none, ToGlibPtr};
 use ffi;
-use glib::to_gboolean;
+use gdk;
+use gdk::pixbuf::Pixbuf;
+use glib::{to_bool, to_gboolean};
 use cast::GTK_WINDOW;
+use {AccelGroup, Application, FFIWidget, Widget, Window};
 
 pub trait WindowTrait : ::WidgetTrait {
     fn set_title(&self, title: &str) -> () {
@@ -44,4 +47,228 @@ pub trait WindowTrait : ::WidgetTrait {
             ffi::gtk_window_set_titlebar(GTK_WINDOW(self.unwrap_widget()), titlebar.unwrap_widget());
         }
     }
+
+    fn set_resizable(&self, resizable: bool) {
+        unsafe { ffi::gtk_window_set_resizable(GTK_WINDOW(self.unwrap_widget()), to_gboolean(resizable)) }
+    }
+
+    fn get_resizable(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_get_resizable(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    fn set_modal(&self, modal: bool) {
+        unsafe { ffi::gtk_window_set_modal(GTK_WINDOW(self.unwrap_widget()), to_gboolean(modal)) }
+    }
+
+    fn get_modal(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_get_modal(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Keeps the window on top of `parent`, dialogs should be transient for the window they
+    /// belong to. None removes the transient parent.
+    fn set_transient_for<T: WindowTrait>(&self, parent: Option<&T>) {
+        unsafe {
+            ffi::gtk_window_set_transient_for(GTK_WINDOW(self.unwrap_widget()),
+                                              parent.map_or(::std::ptr::null_mut(), |p| GTK_WINDOW(p.unwrap_widget())))
+        }
+    }
+
+    fn get_transient_for(&self) -> Option<Window> {
+        let tmp = unsafe { ffi::gtk_window_get_transient_for(GTK_WINDOW(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp as *mut ffi::C_GtkWidget))
+        }
+    }
+
+    /// If set, the window is destroyed along with its transient parent.
+    fn set_destroy_with_parent(&self, setting: bool) {
+        unsafe { ffi::gtk_window_set_destroy_with_parent(GTK_WINDOW(self.unwrap_widget()), to_gboolean(setting)) }
+    }
+
+    fn get_destroy_with_parent(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_get_destroy_with_parent(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Shows the window, deiconifies it and raises it above the others.
+    fn present(&self) {
+        unsafe { ffi::gtk_window_present(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn present_with_time(&self, timestamp: u32) {
+        unsafe { ffi::gtk_window_present_with_time(GTK_WINDOW(self.unwrap_widget()), timestamp) }
+    }
+
+    fn resize(&self, width: i32, height: i32) {
+        unsafe { ffi::gtk_window_resize(GTK_WINDOW(self.unwrap_widget()), width, height) }
+    }
+
+    /// Asks the window manager to move the window to (`x`, `y`), `move` is a keyword.
+    fn move_(&self, x: i32, y: i32) {
+        unsafe { ffi::gtk_window_move(GTK_WINDOW(self.unwrap_widget()), x, y) }
+    }
+
+    /// Returns the (width, height) of the window, without the decorations of the window manager.
+    fn get_size(&self) -> (i32, i32) {
+        let mut width = 0;
+        let mut height = 0;
+
+        unsafe { ffi::gtk_window_get_size(GTK_WINDOW(self.unwrap_widget()), &mut width, &mut height) }
+        (width, height)
+    }
+
+    /// Returns the (x, y) position the window manager was last asked for.
+    fn get_position(&self) -> (i32, i32) {
+        let mut x = 0;
+        let mut y = 0;
+
+        unsafe { ffi::gtk_window_get_position(GTK_WINDOW(self.unwrap_widget()), &mut x, &mut y) }
+        (x, y)
+    }
+
+    fn maximize(&self) {
+        unsafe { ffi::gtk_window_maximize(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn unmaximize(&self) {
+        unsafe { ffi::gtk_window_unmaximize(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn fullscreen(&self) {
+        unsafe { ffi::gtk_window_fullscreen(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn unfullscreen(&self) {
+        unsafe { ffi::gtk_window_unfullscreen(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn iconify(&self) {
+        unsafe { ffi::gtk_window_iconify(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn deiconify(&self) {
+        unsafe { ffi::gtk_window_deiconify(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn set_keep_above(&self, setting: bool) {
+        unsafe { ffi::gtk_window_set_keep_above(GTK_WINDOW(self.unwrap_widget()), to_gboolean(setting)) }
+    }
+
+    fn set_keep_below(&self, setting: bool) {
+        unsafe { ffi::gtk_window_set_keep_below(GTK_WINDOW(self.unwrap_widget()), to_gboolean(setting)) }
+    }
+
+    fn is_active(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_is_active(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    fn set_icon(&self, icon: Option<&Pixbuf>) {
+        unsafe { ffi::gtk_window_set_icon(GTK_WINDOW(self.unwrap_widget()), icon.to_glib_none().0) }
+    }
+
+    fn get_icon(&self) -> Option<Pixbuf> {
+        unsafe { from_glib_none(ffi::gtk_window_get_icon(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Uses the themed icon `name` as icon of the window.
+    fn set_icon_name(&self, name: Option<&str>) {
+        unsafe { ffi::gtk_window_set_icon_name(GTK_WINDOW(self.unwrap_widget()), name.to_glib_none().0) }
+    }
+
+    fn get_icon_name(&self) -> Option<String> {
+        unsafe { from_glib_none(ffi::gtk_window_get_icon_name(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Tells the window manager what kind of window this is, it must be called before the
+    /// window is shown.
+    fn set_type_hint(&self, hint: gdk::WindowTypeHint) {
+        unsafe { ffi::gtk_window_set_type_hint(GTK_WINDOW(self.unwrap_widget()), hint) }
+    }
+
+    fn get_type_hint(&self) -> gdk::WindowTypeHint {
+        unsafe { ffi::gtk_window_get_type_hint(GTK_WINDOW(self.unwrap_widget())) }
+    }
+
+    fn set_role(&self, role: &str) {
+        unsafe { ffi::gtk_window_set_role(GTK_WINDOW(self.unwrap_widget()), role.to_glib_none().0) }
+    }
+
+    fn get_role(&self) -> Option<String> {
+        unsafe { from_glib_none(ffi::gtk_window_get_role(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Sets the widget activated when the user presses Enter, it must have `can-default` set.
+    fn set_default<T: ::WidgetTrait>(&self, default_widget: Option<&T>) {
+        unsafe {
+            ffi::gtk_window_set_default(GTK_WINDOW(self.unwrap_widget()),
+                                        default_widget.map_or(::std::ptr::null_mut(), |w| w.unwrap_widget()))
+        }
+    }
+
+    fn get_default_widget(&self) -> Option<Widget> {
+        let tmp = unsafe { ffi::gtk_window_get_default_widget(GTK_WINDOW(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp))
+        }
+    }
+
+    /// Activates the default widget, returns false if there is none or if it is insensitive.
+    fn activate_default(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_activate_default(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Sets the focus widget, None unsets it.
+    fn set_focus<T: ::WidgetTrait>(&self, focus: Option<&T>) {
+        unsafe {
+            ffi::gtk_window_set_focus(GTK_WINDOW(self.unwrap_widget()),
+                                      focus.map_or(::std::ptr::null_mut(), |w| w.unwrap_widget()))
+        }
+    }
+
+    fn get_focus(&self) -> Option<Widget> {
+        let tmp = unsafe { ffi::gtk_window_get_focus(GTK_WINDOW(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp))
+        }
+    }
+
+    /// Activates the focus widget, returns false if there is none or if it is insensitive.
+    fn activate_focus(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_window_activate_focus(GTK_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Adds the window to `application`, or removes it from its application with None.
+    fn set_application(&self, application: Option<&Application>) {
+        unsafe {
+            ffi::gtk_window_set_application(GTK_WINDOW(self.unwrap_widget()),
+                                            application.map_or(::std::ptr::null_mut(), |a| a.unwrap_pointer()))
+        }
+    }
+
+    fn get_application(&self) -> Option<Application> {
+        let tmp = unsafe { ffi::gtk_window_get_application(GTK_WINDOW(self.unwrap_widget())) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Application::wrap_pointer(tmp))
+        }
+    }
+
+    /// Makes the accelerators of `accel_group` active in the window.
+    fn add_accel_group(&self, accel_group: &AccelGroup) {
+        unsafe { ffi::gtk_window_add_accel_group(GTK_WINDOW(self.unwrap_widget()), accel_group.unwrap_pointer()) }
+    }
+
+    fn remove_accel_group(&self, accel_group: &AccelGroup) {
+        unsafe { ffi::gtk_window_remove_accel_group(GTK_WINDOW(self.unwrap_widget()), accel_group.unwrap_pointer()) }
+    }
 }
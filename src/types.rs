@@ -1,32 +1,191 @@
-pub struct Tooltip;
-/*{
-  GObject parent_instance;
+use std::ffi::{CString, NulError};
+use std::ptr;
+use std::slice;
+use ffi;
+use glib::translate::{from_glib_full, ToGlibPtr};
+use gdk::pixbuf::Pixbuf;
+use glib::to_bool;
+use {TargetFlags, TreeModel, TreePath};
 
-  Widget *window;
-  Widget *box;
-  Widget *image;
-  Widget *label;
-  Widget *custom_widget;
+/// Tooltip — Add tips to your widgets
+pub struct Tooltip {
+    pointer: *mut ffi::C_GtkTooltip
+}
 
-  Window *current_window;
-  Widget *keyboard_widget;
+impl Tooltip {
+    pub fn set_markup(&self, markup: &str) {
+        unsafe { ffi::gtk_tooltip_set_markup(self.pointer, markup.to_glib_none().0) }
+    }
 
-  Widget *tooltip_widget;
-  GdkWindow *toplevel_window;
+    pub fn set_text(&self, text: &str) {
+        unsafe { ffi::gtk_tooltip_set_text(self.pointer, text.to_glib_none().0) }
+    }
 
-  gdouble last_x;
-  gdouble last_y;
-  GdkWindow *last_window;
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTooltip {
+        self.pointer
+    }
 
-  guint timeout_id;
-  guint browse_mode_timeout_id;
+    #[doc(hidden)]
+    pub fn wrap_pointer(tooltip: *mut ffi::C_GtkTooltip) -> Tooltip {
+        unsafe {
+            ::glib::ffi::g_object_ref(tooltip as *mut ::libc::c_void);
+        }
 
-  GdkRectangle tip_area;
+        Tooltip {
+            pointer: tooltip
+        }
+    }
+}
 
-  guint browse_mode_enabled : 1;
-  guint keyboard_mode_enabled : 1;
-  guint tip_area_set : 1;
-  guint custom_was_reset : 1;
-};*/
+impl Drop for Tooltip {
+    fn drop(&mut self) {
+        unsafe {
+            ::glib::ffi::g_object_unref(self.pointer as *mut ::libc::c_void);
+        }
+    }
+}
+
+impl Clone for Tooltip {
+    fn clone(&self) -> Tooltip {
+        Tooltip::wrap_pointer(self.pointer)
+    }
+}
+
+/// TargetEntry — A target a drag source can provide or a drag destination accepts
+pub struct TargetEntry {
+    target: CString,
+    flags: Vec<TargetFlags>,
+    info: u32
+}
+
+impl TargetEntry {
+    /// `flags` restricts where the target can be dropped, an empty slice for anywhere. Fails
+    /// if `target` contains a nul byte.
+    pub fn new(target: &str, flags: &[TargetFlags], info: u32) -> Result<TargetEntry, NulError> {
+        Ok(TargetEntry {
+            target: try!(CString::new(target)),
+            flags: flags.to_vec(),
+            info: info
+        })
+    }
+
+    pub fn get_target(&self) -> String {
+        String::from_utf8_lossy(self.target.as_bytes()).into_owned()
+    }
+
+    pub fn get_flags(&self) -> &[TargetFlags] {
+        &self.flags
+    }
+
+    pub fn get_info(&self) -> u32 {
+        self.info
+    }
+
+    /// The returned entry borrows the target string of `self`.
+    #[doc(hidden)]
+    pub fn to_c_entry(&self) -> ffi::C_GtkTargetEntry {
+        ffi::C_GtkTargetEntry {
+            target: self.target.as_ptr() as *mut _,
+            flags: self.flags.iter().fold(0, |acc, &flag| acc | flag as u32),
+            info: self.info
+        }
+    }
+}
+
+/// SelectionData — The data being transferred by a drag and drop or clipboard operation
+///
+/// It is only valid for the duration of the handler it is given to.
+pub struct SelectionData {
+    pointer: *mut ffi::C_GtkSelectionData
+}
+
+impl SelectionData {
+    pub fn set_text(&self, text: &str) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_selection_data_set_text(self.pointer, text.to_glib_none().0, text.len() as i32))
+        }
+    }
+
+    pub fn get_text(&self) -> Option<String> {
+        unsafe { from_glib_full(ffi::gtk_selection_data_get_text(self.pointer)) }
+    }
+
+    pub fn get_length(&self) -> i32 {
+        unsafe { ffi::gtk_selection_data_get_length(self.pointer) }
+    }
+
+    /// Returns the target requested, e.g. "UTF8_STRING" or a custom one.
+    pub fn get_target(&self) -> String {
+        unsafe { from_glib_full(ffi::gdk_atom_name(ffi::gtk_selection_data_get_target(self.pointer))) }
+    }
+
+    /// Stores `data` for the requested target, `format` is the size of its units in bits,
+    /// usually 8.
+    pub fn set(&self, format: i32, data: &[u8]) {
+        unsafe {
+            ffi::gtk_selection_data_set(self.pointer, ffi::gtk_selection_data_get_target(self.pointer), format,
+                                        data.as_ptr(), data.len() as i32)
+        }
+    }
+
+    /// Returns the raw data, empty if there is none.
+    pub fn get_data(&self) -> Vec<u8> {
+        let data = unsafe { ffi::gtk_selection_data_get_data(self.pointer) };
+        let length = self.get_length();
+
+        if data.is_null() || length <= 0 {
+            Vec::new()
+        } else {
+            unsafe { slice::from_raw_parts(data, length as usize).to_vec() }
+        }
+    }
+
+    pub fn get_format(&self) -> i32 {
+        unsafe { ffi::gtk_selection_data_get_format(self.pointer) }
+    }
+
+    /// Returns false if the target of the selection isn't an image one.
+    pub fn set_pixbuf(&self, pixbuf: &Pixbuf) -> bool {
+        unsafe { to_bool(ffi::gtk_selection_data_set_pixbuf(self.pointer, pixbuf.to_glib_none().0)) }
+    }
+
+    pub fn get_pixbuf(&self) -> Option<Pixbuf> {
+        unsafe { from_glib_full(ffi::gtk_selection_data_get_pixbuf(self.pointer)) }
+    }
+
+    /// Stores a row of `model` so that it can be retrieved with `get_row_drag_data`.
+    /// Returns false if the target of the selection isn't "GTK_TREE_MODEL_ROW".
+    pub fn set_row_drag_data(&self, model: &TreeModel, path: &TreePath) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_tree_set_row_drag_data(self.pointer, model.unwrap_pointer(), path.unwrap_pointer()))
+        }
+    }
+
+    /// Returns the model and the path of a row dragged from a tree view or an icon view.
+    pub fn get_row_drag_data(&self) -> Option<(TreeModel, TreePath)> {
+        let mut model = ptr::null_mut();
+        let mut path = ptr::null_mut();
+
+        if unsafe { to_bool(ffi::gtk_tree_get_row_drag_data(self.pointer, &mut model, &mut path)) } {
+            unsafe { ::glib::ffi::g_object_ref(model as *mut ::libc::c_void) };
+            Some((TreeModel::wrap_pointer(model), TreePath::wrap_pointer(path)))
+        } else {
+            None
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkSelectionData {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(selection_data: *mut ffi::C_GtkSelectionData) -> SelectionData {
+        SelectionData {
+            pointer: selection_data
+        }
+    }
+}
 
 // pub struct WidgetHelpType;
\ No newline at end of file
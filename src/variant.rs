@@ -0,0 +1,198 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GVariant — Strongly typed value datatype
+
+use std::fmt;
+use std::ptr;
+use glib::translate::{from_glib_full, from_glib_none, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use glib;
+use ffi;
+
+// G_VARIANT_PARSE_ERROR_INVALID_TYPE_STRING
+const INVALID_TYPE_STRING: i32 = 9;
+
+/// GVariant — Strongly typed value datatype
+///
+/// Variants are the parameters and states of the actions. Their type is given by a type string
+/// such as "b" for a boolean, "i" for an i32, "x" for an i64, "u" for an u32, "d" for a f64 and
+/// "s" for a string.
+pub struct Variant {
+    pointer: *mut ffi::C_GVariant
+}
+
+impl Variant {
+    pub fn new_boolean(value: bool) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_boolean(to_gboolean(value)) })
+    }
+
+    pub fn new_int32(value: i32) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_int32(value) })
+    }
+
+    pub fn new_int64(value: i64) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_int64(value) })
+    }
+
+    pub fn new_uint32(value: u32) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_uint32(value) })
+    }
+
+    pub fn new_double(value: f64) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_double(value) })
+    }
+
+    pub fn new_string(value: &str) -> Variant {
+        Variant::wrap_pointer(unsafe { ffi::g_variant_new_string(value.to_glib_none().0) })
+    }
+
+    /// Parses the text format of `print`, e.g. "('a', 3)". `type_string` is needed when the
+    /// type can't be inferred from `text`.
+    pub fn parse(type_string: Option<&str>, text: &str) -> Result<Variant, glib::Error> {
+        if !type_string.map_or(true, Variant::is_valid_type_string) {
+            return Err(glib::GlibContainer::wrap(unsafe {
+                ffi::g_error_new_literal(ffi::g_variant_parse_error_quark(), INVALID_TYPE_STRING,
+                                         "invalid type string".to_glib_none().0)
+            }));
+        }
+        let mut error = ptr::null_mut();
+        let tmp = unsafe {
+            ffi::g_variant_parse(type_string.to_glib_none().0 as *const ffi::C_GVariantType, text.to_glib_none().0,
+                                 ptr::null(), ptr::null_mut(), &mut error)
+        };
+
+        if tmp.is_null() {
+            Err(glib::GlibContainer::wrap(error))
+        } else {
+            Ok(Variant::wrap_pointer(tmp))
+        }
+    }
+
+    /// Returns true if `type_string` is a valid type string, e.g. "(si)" but not "(si".
+    pub fn is_valid_type_string(type_string: &str) -> bool {
+        !type_string.contains('\0') &&
+            unsafe { to_bool(ffi::g_variant_type_string_is_valid(type_string.to_glib_none().0)) }
+    }
+
+    pub fn get_type_string(&self) -> String {
+        unsafe { from_glib_none(ffi::g_variant_get_type_string(self.pointer)) }
+    }
+
+    /// Returns false if `type_string` isn't a valid type string.
+    pub fn is_of_type(&self, type_string: &str) -> bool {
+        if !Variant::is_valid_type_string(type_string) {
+            return false;
+        }
+        unsafe {
+            to_bool(ffi::g_variant_is_of_type(self.pointer, type_string.to_glib_none().0 as *const ffi::C_GVariantType))
+        }
+    }
+
+    /// Returns None if the variant isn't a boolean.
+    pub fn get_boolean(&self) -> Option<bool> {
+        if self.is_of_type("b") {
+            Some(unsafe { to_bool(ffi::g_variant_get_boolean(self.pointer)) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_int32(&self) -> Option<i32> {
+        if self.is_of_type("i") {
+            Some(unsafe { ffi::g_variant_get_int32(self.pointer) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_int64(&self) -> Option<i64> {
+        if self.is_of_type("x") {
+            Some(unsafe { ffi::g_variant_get_int64(self.pointer) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_uint32(&self) -> Option<u32> {
+        if self.is_of_type("u") {
+            Some(unsafe { ffi::g_variant_get_uint32(self.pointer) })
+        } else {
+            None
+        }
+    }
+
+    pub fn get_double(&self) -> Option<f64> {
+        if self.is_of_type("d") {
+            Some(unsafe { ffi::g_variant_get_double(self.pointer) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns None if the variant isn't a string, an object path or a signature.
+    pub fn get_string(&self) -> Option<String> {
+        if self.is_of_type("s") || self.is_of_type("o") || self.is_of_type("g") {
+            Some(unsafe { from_glib_none(ffi::g_variant_get_string(self.pointer, ptr::null_mut())) })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the text format of the variant, with its type if `type_annotate` is true.
+    pub fn print(&self, type_annotate: bool) -> String {
+        unsafe { from_glib_full(ffi::g_variant_print(self.pointer, to_gboolean(type_annotate))) }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GVariant {
+        self.pointer
+    }
+
+    /// Takes the ownership of `c_variant`, sinking it if it is floating.
+    #[doc(hidden)]
+    pub fn wrap_pointer(c_variant: *mut ffi::C_GVariant) -> Variant {
+        Variant {
+            pointer: unsafe { ffi::g_variant_take_ref(c_variant) }
+        }
+    }
+
+    /// References `c_variant`, which is still owned by the caller.
+    #[doc(hidden)]
+    pub fn wrap_borrowed_pointer(c_variant: *mut ffi::C_GVariant) -> Variant {
+        Variant {
+            pointer: unsafe { ffi::g_variant_ref(c_variant) }
+        }
+    }
+}
+
+impl Drop for Variant {
+    fn drop(&mut self) {
+        unsafe { ffi::g_variant_unref(self.pointer) }
+    }
+}
+
+impl Clone for Variant {
+    fn clone(&self) -> Variant {
+        Variant::wrap_borrowed_pointer(self.pointer)
+    }
+}
+
+impl PartialEq for Variant {
+    fn eq(&self, other: &Variant) -> bool {
+        unsafe { to_bool(ffi::g_variant_equal(self.pointer, other.pointer)) }
+    }
+}
+
+impl fmt::Debug for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.print(true))
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.print(false))
+    }
+}
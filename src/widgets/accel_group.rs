@@ -0,0 +1,188 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkAccelGroup — Groups of global keyboard accelerators for an entire GtkWindow
+
+use std::mem::transmute;
+use glib::translate::{from_glib_full, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use gdk;
+use ffi;
+use libc::c_uint;
+use {AccelFlags, FFIWidget, Widget, WidgetTrait};
+
+/// GtkAccelGroup — Groups of global keyboard accelerators for an entire GtkWindow
+///
+/// The accelerators of a group are active in the windows the group is added to with
+/// `WindowTrait::add_accel_group`.
+///
+/// ```ignore
+/// let accel_group = AccelGroup::new().unwrap();
+/// let (key, mods) = gtk::accelerator_parse("<Control>s");
+/// accel_group.connect(key, mods, &[AccelFlags::Visible], |_, _, _, _| {
+///     save();
+///     true
+/// });
+/// window.add_accel_group(&accel_group);
+/// ```
+pub struct AccelGroup {
+    pointer: *mut ffi::C_GtkAccelGroup
+}
+
+impl AccelGroup {
+    pub fn new() -> Option<AccelGroup> {
+        let tmp_pointer = unsafe { ffi::gtk_accel_group_new() };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(AccelGroup {
+                pointer: tmp_pointer
+            })
+        }
+    }
+
+    /// Calls `f` when `accel_key` is pressed with `accel_mods` in a window of the group. The
+    /// closure gets the group, the window, the key and the modifiers and returns whether it
+    /// handled the accelerator.
+    pub fn connect<F>(&self, accel_key: u32, accel_mods: gdk::ModifierType, accel_flags: &[AccelFlags], f: F)
+            where F: Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static {
+        let accel_flags = accel_flags.iter().fold(0, |acc, &flag| acc | flag as c_uint);
+
+        unsafe {
+            ffi::gtk_accel_group_connect(self.pointer, accel_key, accel_mods, accel_flags, new_closure(f))
+        }
+    }
+
+    /// Calls `f` when the accelerator of `accel_path` in the AccelMap is pressed, it follows the
+    /// changes of the accelerator.
+    pub fn connect_by_path<F>(&self, accel_path: &str, f: F)
+            where F: Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static {
+        unsafe { ffi::gtk_accel_group_connect_by_path(self.pointer, accel_path.to_glib_none().0, new_closure(f)) }
+    }
+
+    /// Removes the closure connected to `accel_key` with `accel_mods`, returns false if there is
+    /// none or if it is locked.
+    pub fn disconnect(&self, accel_key: u32, accel_mods: gdk::ModifierType) -> bool {
+        unsafe { to_bool(ffi::gtk_accel_group_disconnect_key(self.pointer, accel_key, accel_mods)) }
+    }
+
+    /// Calls the closure connected to `accel_key` with `accel_mods` on `acceleratable`, usually
+    /// a window of the group. Returns whether the accelerator was handled.
+    pub fn activate<T: WidgetTrait>(&self, acceleratable: &T, accel_key: u32, accel_mods: gdk::ModifierType) -> bool {
+        let accel_name = accelerator_name(accel_key, accel_mods);
+
+        unsafe {
+            let accel_quark = ffi::g_quark_from_string(accel_name.to_glib_none().0);
+
+            to_bool(ffi::gtk_accel_group_activate(self.pointer, accel_quark, acceleratable.unwrap_widget() as *mut _,
+                                                  accel_key, accel_mods))
+        }
+    }
+
+    /// Prevents the accelerators of the group from being changed, until `unlock` is called as
+    /// many times.
+    pub fn lock(&self) {
+        unsafe { ffi::gtk_accel_group_lock(self.pointer) }
+    }
+
+    pub fn unlock(&self) {
+        unsafe { ffi::gtk_accel_group_unlock(self.pointer) }
+    }
+
+    pub fn get_is_locked(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_accel_group_get_is_locked(self.pointer)) }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkAccelGroup {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkAccelGroup) -> AccelGroup {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        AccelGroup {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for AccelGroup {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for AccelGroup {
+    fn clone(&self) -> AccelGroup {
+        AccelGroup::wrap_pointer(self.pointer)
+    }
+}
+
+/// Activates the accelerator `accel_key` with `accel_mods` in the groups added to the window
+/// `object`, returns whether it was handled.
+pub fn accel_groups_activate<T: WidgetTrait>(object: &T, accel_key: u32, accel_mods: gdk::ModifierType) -> bool {
+    unsafe { to_bool(ffi::gtk_accel_groups_activate(object.unwrap_widget() as *mut _, accel_key, accel_mods)) }
+}
+
+/// Parses an accelerator such as "<Control>a" or "<Shift><Alt>F1" into a key value and
+/// modifiers, the key value is 0 if `accelerator` can't be parsed.
+pub fn accelerator_parse(accelerator: &str) -> (u32, gdk::ModifierType) {
+    let mut accelerator_key = 0;
+    let mut accelerator_mods = gdk::ModifierType::empty();
+
+    unsafe { ffi::gtk_accelerator_parse(accelerator.to_glib_none().0, &mut accelerator_key, &mut accelerator_mods) }
+    (accelerator_key, accelerator_mods)
+}
+
+/// Returns the accelerator in the format of `accelerator_parse`, e.g. "<Primary>s".
+pub fn accelerator_name(accelerator_key: u32, accelerator_mods: gdk::ModifierType) -> String {
+    let name: Option<String> = unsafe { from_glib_full(ffi::gtk_accelerator_name(accelerator_key, accelerator_mods)) };
+
+    name.unwrap_or(String::new())
+}
+
+/// Returns the accelerator as it is shown to the user, e.g. "Ctrl+S".
+pub fn accelerator_get_label(accelerator_key: u32, accelerator_mods: gdk::ModifierType) -> String {
+    let label: Option<String> = unsafe {
+        from_glib_full(ffi::gtk_accelerator_get_label(accelerator_key, accelerator_mods))
+    };
+
+    label.unwrap_or(String::new())
+}
+
+/// Returns false for the key values which can't be accelerators, e.g. modifier keys alone.
+pub fn accelerator_valid(keyval: u32, modifiers: gdk::ModifierType) -> bool {
+    unsafe { to_bool(ffi::gtk_accelerator_valid(keyval, modifiers)) }
+}
+
+/// Returns the modifiers taken into account by the accelerators.
+pub fn accelerator_get_default_mod_mask() -> gdk::ModifierType {
+    unsafe { ffi::gtk_accelerator_get_default_mod_mask() }
+}
+
+fn new_closure<F>(f: F) -> *mut ffi::C_GClosure
+        where F: Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static {
+    unsafe {
+        let f: Box<Box<Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static>> = Box::new(Box::new(f));
+        ffi::g_cclosure_new(accel_trampoline as ffi::gpointer, transmute(f), Some(destroy_accel_closure))
+    }
+}
+
+extern "C" fn accel_trampoline(accel_group: *mut ffi::C_GtkAccelGroup, acceleratable: *mut ffi::C_GObject,
+                               keyval: c_uint, modifier: gdk::ModifierType, data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static> = transmute(data);
+
+        to_gboolean(f(AccelGroup::wrap_pointer(accel_group), FFIWidget::wrap_widget(acceleratable as *mut _),
+                      keyval, modifier))
+    }
+}
+
+extern "C" fn destroy_accel_closure(data: ffi::gpointer, _: *mut ffi::C_GClosure) {
+    unsafe {
+        let _: Box<Box<Fn(AccelGroup, Widget, u32, gdk::ModifierType) -> bool + 'static>> = transmute(data);
+    }
+}
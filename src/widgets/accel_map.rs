@@ -0,0 +1,129 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkAccelMap — Loadable keyboard accelerator specifications
+
+use std::mem::transmute;
+use glib::translate::{from_glib_none, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use gdk;
+use ffi;
+use libc::{c_char, c_uint};
+
+/// GtkAccelMap — Loadable keyboard accelerator specifications
+///
+/// The accel map binds accel paths such as "<MyApp>/File/Open" to accelerators, it is shared
+/// by the whole application. The menu items and widgets given an accel path follow the changes
+/// of its accelerator, so users can rebind them and `save` and `load` their choices.
+///
+/// ```ignore
+/// AccelMap::add_entry("<MyApp>/File/Open", key, mods);
+/// menu_item.set_accel_path("<MyApp>/File/Open");
+///
+/// AccelMap::load("accels.txt");
+/// AccelMap::get().connect_changed(|_, _, _, _| AccelMap::save("accels.txt"));
+/// ```
+pub struct AccelMap {
+    pointer: *mut ffi::C_GtkAccelMap
+}
+
+impl AccelMap {
+    /// Returns the accel map object, to connect to its signals.
+    pub fn get() -> AccelMap {
+        AccelMap::wrap_pointer(unsafe { ffi::gtk_accel_map_get() })
+    }
+
+    /// Registers `accel_path` with a default accelerator, it is kept if the path is already
+    /// registered.
+    pub fn add_entry(accel_path: &str, accel_key: u32, accel_mods: gdk::ModifierType) {
+        unsafe { ffi::gtk_accel_map_add_entry(accel_path.to_glib_none().0, accel_key, accel_mods) }
+    }
+
+    /// Changes the accelerator of `accel_path`. If it is used by other paths it fails, unless
+    /// `replace` is true, which takes it from them. Returns whether it was changed.
+    pub fn change_entry(accel_path: &str, accel_key: u32, accel_mods: gdk::ModifierType, replace: bool) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_accel_map_change_entry(accel_path.to_glib_none().0, accel_key, accel_mods,
+                                                    to_gboolean(replace)))
+        }
+    }
+
+    /// Returns the key and modifiers of `accel_path`, None if it isn't registered.
+    pub fn lookup_entry(accel_path: &str) -> Option<(u32, gdk::ModifierType)> {
+        let mut key = ffi::C_GtkAccelKey {
+            accel_key: 0,
+            accel_mods: gdk::ModifierType::empty(),
+            accel_flags: 0
+        };
+
+        if unsafe { to_bool(ffi::gtk_accel_map_lookup_entry(accel_path.to_glib_none().0, &mut key)) } {
+            Some((key.accel_key, key.accel_mods))
+        } else {
+            None
+        }
+    }
+
+    /// Loads the accelerators saved with `save`, the missing file is ignored.
+    pub fn load(file_name: &str) {
+        unsafe { ffi::gtk_accel_map_load(file_name.to_glib_none().0) }
+    }
+
+    /// Saves the accelerators to `file_name`, only the changed ones are uncommented.
+    pub fn save(file_name: &str) {
+        unsafe { ffi::gtk_accel_map_save(file_name.to_glib_none().0) }
+    }
+
+    /// Calls `callback` with the path, the key, the modifiers and whether the accelerator was
+    /// changed from its default, for each registered path.
+    pub fn foreach<F: FnMut(&str, u32, gdk::ModifierType, bool)>(mut callback: F) {
+        let mut f: &mut FnMut(&str, u32, gdk::ModifierType, bool) = &mut callback;
+
+        unsafe { ffi::gtk_accel_map_foreach(&mut f as *mut _ as ffi::gpointer, foreach_trampoline) }
+    }
+
+    /// Prevents the accelerator of `accel_path` from being changed, until `unlock_path` is
+    /// called as many times.
+    pub fn lock_path(accel_path: &str) {
+        unsafe { ffi::gtk_accel_map_lock_path(accel_path.to_glib_none().0) }
+    }
+
+    pub fn unlock_path(accel_path: &str) {
+        unsafe { ffi::gtk_accel_map_unlock_path(accel_path.to_glib_none().0) }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkAccelMap {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkAccelMap) -> AccelMap {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        AccelMap {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for AccelMap {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for AccelMap {
+    fn clone(&self) -> AccelMap {
+        AccelMap::wrap_pointer(self.pointer)
+    }
+}
+
+extern "C" fn foreach_trampoline(data: ffi::gpointer, accel_path: *const c_char, accel_key: c_uint,
+                                 accel_mods: gdk::ModifierType, changed: ffi::Gboolean) {
+    unsafe {
+        let f: &mut &mut FnMut(&str, u32, gdk::ModifierType, bool) = transmute(data);
+        let accel_path: String = from_glib_none(accel_path);
+
+        f(&accel_path, accel_key, accel_mods, to_bool(changed))
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkApplication — Application class
+
+use std::ffi::{CString, NulError};
+use std::ptr;
+use glib::translate::{from_glib_none, FromGlibPtrContainer, ToGlibPtr};
+use glib::to_bool;
+use glib;
+use ffi;
+use libc::{c_char, c_int, c_uint};
+use cast::GTK_WINDOW;
+use {ApplicationFlags, ApplicationInhibitFlags, FFIWidget, MenuModel, SimpleAction, Window, WindowTrait};
+
+/// GtkApplication — Application class
+///
+/// An application with an id is unique on the session bus: running it a second time activates
+/// the first instance instead. Tests can run the application on a private bus, e.g. with
+/// `dbus-run-session`, or pass `ApplicationFlags::NonUnique` to not use the bus at all.
+/// The windows must be created in the `startup` or `activate` handlers, GTK is initialized
+/// by `run`.
+pub struct Application {
+    pointer: *mut ffi::C_GtkApplication
+}
+
+impl Application {
+    /// `application_id` must be a valid D-Bus name such as "org.example.App", or None for an
+    /// application which isn't unique.
+    pub fn new(application_id: Option<&str>, flags: &[ApplicationFlags]) -> Option<Application> {
+        let flags = flags.iter().fold(0, |acc, &flag| acc | flag as c_uint);
+        let tmp_pointer = unsafe { ffi::gtk_application_new(application_id.to_glib_none().0, flags) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(Application {
+                pointer: tmp_pointer
+            })
+        }
+    }
+
+    /// Runs the application with the command line `args`, the first one being the program name,
+    /// until its last window is closed or it is quitted. Returns the exit status, fails without
+    /// running if an argument contains a nul byte.
+    pub fn run(&self, args: &[String]) -> Result<i32, NulError> {
+        let mut c_args = Vec::with_capacity(args.len());
+        for arg in args.iter() {
+            c_args.push(try!(CString::new(&arg[..])));
+        }
+        let mut argv: Vec<*mut c_char> = c_args.iter().map(|arg| arg.as_ptr() as *mut c_char).collect();
+        argv.push(ptr::null_mut());
+
+        Ok(unsafe { ffi::g_application_run(self.unwrap_application(), c_args.len() as c_int, argv.as_mut_ptr()) })
+    }
+
+    /// Registers the application on the session bus without running it, `run` does it otherwise.
+    pub fn register(&self) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+
+        if unsafe { to_bool(ffi::g_application_register(self.unwrap_application(), ptr::null_mut(), &mut error)) } {
+            Ok(())
+        } else {
+            Err(glib::GlibContainer::wrap(error))
+        }
+    }
+
+    pub fn is_registered(&self) -> bool {
+        unsafe { to_bool(ffi::g_application_get_is_registered(self.unwrap_application())) }
+    }
+
+    /// Returns true if another instance is the primary one, the signals are then emitted there.
+    pub fn is_remote(&self) -> bool {
+        unsafe { to_bool(ffi::g_application_get_is_remote(self.unwrap_application())) }
+    }
+
+    pub fn get_application_id(&self) -> Option<String> {
+        unsafe { from_glib_none(ffi::g_application_get_application_id(self.unwrap_application())) }
+    }
+
+    /// Emits `activate`, in the primary instance if the application is remote.
+    pub fn activate(&self) {
+        unsafe { ffi::g_application_activate(self.unwrap_application()) }
+    }
+
+    /// Stops `run` at the next main loop iteration, whatever the windows and holds.
+    pub fn quit(&self) {
+        unsafe { ffi::g_application_quit(self.unwrap_application()) }
+    }
+
+    /// Keeps the application running while it has no window, until `release` is called.
+    pub fn hold(&self) {
+        unsafe { ffi::g_application_hold(self.unwrap_application()) }
+    }
+
+    pub fn release(&self) {
+        unsafe { ffi::g_application_release(self.unwrap_application()) }
+    }
+
+    /// The application keeps running as long as it has windows, they are removed when destroyed.
+    pub fn add_window<T: WindowTrait>(&self, window: &T) {
+        unsafe { ffi::gtk_application_add_window(self.pointer, GTK_WINDOW(window.unwrap_widget())) }
+    }
+
+    pub fn remove_window<T: WindowTrait>(&self, window: &T) {
+        unsafe { ffi::gtk_application_remove_window(self.pointer, GTK_WINDOW(window.unwrap_widget())) }
+    }
+
+    /// Returns the windows of the application, the most recently focused first.
+    pub fn get_windows(&self) -> Vec<Window> {
+        let tmp = unsafe { ffi::g_list_copy(ffi::gtk_application_get_windows(self.pointer)) };
+
+        if tmp.is_null() {
+            Vec::new()
+        } else {
+            let list: glib::List<*mut ffi::C_GtkWindow> = glib::GlibContainer::wrap(tmp);
+            list.iter().map(|&window| FFIWidget::wrap_widget(window as *mut ffi::C_GtkWidget)).collect()
+        }
+    }
+
+    /// Returns the ApplicationWindow with the id `id`, see `ApplicationWindow::get_id`.
+    pub fn get_window_by_id(&self, id: u32) -> Option<Window> {
+        let tmp = unsafe { ffi::gtk_application_get_window_by_id(self.pointer, id) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp as *mut ffi::C_GtkWidget))
+        }
+    }
+
+    pub fn get_active_window(&self) -> Option<Window> {
+        let tmp = unsafe { ffi::gtk_application_get_active_window(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp as *mut ffi::C_GtkWidget))
+        }
+    }
+
+    /// Asks the session manager to block the `flags` actions, `reason` can be shown to the user.
+    /// Returns a cookie for `uninhibit`, 0 if the request failed.
+    pub fn inhibit<T: WindowTrait>(&self, window: Option<&T>, flags: &[ApplicationInhibitFlags],
+                                   reason: Option<&str>) -> u32 {
+        let flags = flags.iter().fold(0, |acc, &flag| acc | flag as c_uint);
+
+        unsafe {
+            ffi::gtk_application_inhibit(self.pointer,
+                                         window.map_or(ptr::null_mut(), |w| GTK_WINDOW(w.unwrap_widget())),
+                                         flags, reason.to_glib_none().0)
+        }
+    }
+
+    pub fn uninhibit(&self, cookie: u32) {
+        unsafe { ffi::gtk_application_uninhibit(self.pointer, cookie) }
+    }
+
+    /// Returns true if any of the `flags` actions is inhibited, by any application.
+    pub fn is_inhibited(&self, flags: &[ApplicationInhibitFlags]) -> bool {
+        let flags = flags.iter().fold(0, |acc, &flag| acc | flag as c_uint);
+
+        unsafe { to_bool(ffi::gtk_application_is_inhibited(self.pointer, flags)) }
+    }
+
+    /// Sets the keyboard accelerators of the action, e.g. `("app.quit", &["<Primary>q"])`. An
+    /// empty slice removes them. Fails if an accelerator contains a nul byte, the accelerators
+    /// are left as they were.
+    #[cfg(feature = "gtk_3_12")]
+    pub fn set_accels_for_action(&self, detailed_action_name: &str, accels: &[&str]) -> Result<(), NulError> {
+        let mut c_strings = Vec::with_capacity(accels.len());
+        for accel in accels.iter() {
+            c_strings.push(try!(CString::new(*accel)));
+        }
+        let mut c_accels: Vec<*const c_char> = c_strings.iter().map(|accel| accel.as_ptr()).collect();
+        c_accels.push(ptr::null());
+
+        unsafe {
+            ffi::gtk_application_set_accels_for_action(self.pointer, detailed_action_name.to_glib_none().0,
+                                                       c_accels.as_ptr())
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "gtk_3_12")]
+    pub fn get_accels_for_action(&self, detailed_action_name: &str) -> Vec<String> {
+        unsafe {
+            FromGlibPtrContainer::from_glib_full(
+                ffi::gtk_application_get_accels_for_action(self.pointer, detailed_action_name.to_glib_none().0)
+                    as *const *const c_char)
+        }
+    }
+
+    /// Returns the detailed names of the actions having accelerators.
+    #[cfg(feature = "gtk_3_12")]
+    pub fn list_action_descriptions(&self) -> Vec<String> {
+        unsafe {
+            FromGlibPtrContainer::from_glib_full(
+                ffi::gtk_application_list_action_descriptions(self.pointer) as *const *const c_char)
+        }
+    }
+
+    /// Sets the menu shown by the desktop shell for the application, it must be set in `startup`.
+    pub fn set_app_menu(&self, app_menu: Option<&MenuModel>) {
+        unsafe {
+            ffi::gtk_application_set_app_menu(self.pointer, app_menu.map_or(ptr::null_mut(), |m| m.unwrap_pointer()))
+        }
+    }
+
+    pub fn get_app_menu(&self) -> Option<MenuModel> {
+        let tmp = unsafe { ffi::gtk_application_get_app_menu(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MenuModel::wrap_pointer(tmp))
+        }
+    }
+
+    /// Sets the menubar shown by the ApplicationWindows or the desktop shell, it must be set in
+    /// `startup`.
+    pub fn set_menubar(&self, menubar: Option<&MenuModel>) {
+        unsafe {
+            ffi::gtk_application_set_menubar(self.pointer, menubar.map_or(ptr::null_mut(), |m| m.unwrap_pointer()))
+        }
+    }
+
+    pub fn get_menubar(&self) -> Option<MenuModel> {
+        let tmp = unsafe { ffi::gtk_application_get_menubar(self.pointer) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MenuModel::wrap_pointer(tmp))
+        }
+    }
+
+    /// Adds `action` to the application, widgets point at it as "app.<name>".
+    pub fn add_action(&self, action: &SimpleAction) {
+        unsafe { ffi::g_action_map_add_action(self.pointer as *mut ffi::C_GActionMap, action.unwrap_action()) }
+    }
+
+    pub fn remove_action(&self, action_name: &str) {
+        unsafe { ffi::g_action_map_remove_action(self.pointer as *mut ffi::C_GActionMap, action_name.to_glib_none().0) }
+    }
+
+    /// Returns None if there is no action named `action_name` or if it isn't a SimpleAction.
+    pub fn lookup_action(&self, action_name: &str) -> Option<SimpleAction> {
+        let tmp = unsafe {
+            ffi::g_action_map_lookup_action(self.pointer as *mut ffi::C_GActionMap, action_name.to_glib_none().0)
+        };
+
+        SimpleAction::wrap_action(tmp)
+    }
+
+    fn unwrap_application(&self) -> *mut ffi::C_GApplication {
+        self.pointer as *mut ffi::C_GApplication
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkApplication {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkApplication) -> Application {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        Application {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for Application {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for Application {
+    fn clone(&self) -> Application {
+        Application::wrap_pointer(self.pointer)
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkApplicationWindow — GtkWindow subclass with GtkApplication support
+
+use glib::translate::ToGlibPtr;
+use glib::{to_bool, to_gboolean};
+use ffi;
+use cast::GTK_APPLICATION_WINDOW;
+use {Application, SimpleAction};
+
+/// GtkApplicationWindow — GtkWindow subclass with GtkApplication support
+///
+/// The window is added to the application, shows its menubar unless the desktop shell does and
+/// can activate its "win." actions.
+struct_Widget!(ApplicationWindow);
+
+impl ApplicationWindow {
+    pub fn new(application: &Application) -> Option<ApplicationWindow> {
+        let tmp_pointer = unsafe { ffi::gtk_application_window_new(application.unwrap_pointer()) };
+        check_pointer!(tmp_pointer, ApplicationWindow)
+    }
+
+    pub fn set_show_menubar(&self, show_menubar: bool) {
+        unsafe {
+            ffi::gtk_application_window_set_show_menubar(GTK_APPLICATION_WINDOW(self.unwrap_widget()),
+                                                         to_gboolean(show_menubar))
+        }
+    }
+
+    pub fn get_show_menubar(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_application_window_get_show_menubar(GTK_APPLICATION_WINDOW(self.unwrap_widget()))) }
+    }
+
+    /// Returns the unique id of the window in its application, 0 if it hasn't got one.
+    pub fn get_id(&self) -> u32 {
+        unsafe { ffi::gtk_application_window_get_id(GTK_APPLICATION_WINDOW(self.unwrap_widget())) }
+    }
+
+    /// Adds `action` to the window, widgets point at it as "win.<name>".
+    pub fn add_action(&self, action: &SimpleAction) {
+        unsafe { ffi::g_action_map_add_action(self.unwrap_widget() as *mut ffi::C_GActionMap, action.unwrap_action()) }
+    }
+
+    pub fn remove_action(&self, action_name: &str) {
+        unsafe {
+            ffi::g_action_map_remove_action(self.unwrap_widget() as *mut ffi::C_GActionMap,
+                                            action_name.to_glib_none().0)
+        }
+    }
+
+    /// Returns None if there is no action named `action_name` or if it isn't a SimpleAction,
+    /// e.g. the "show-menubar" action GTK adds to the window.
+    pub fn lookup_action(&self, action_name: &str) -> Option<SimpleAction> {
+        let tmp = unsafe {
+            ffi::g_action_map_lookup_action(self.unwrap_widget() as *mut ffi::C_GActionMap,
+                                            action_name.to_glib_none().0)
+        };
+
+        SimpleAction::wrap_action(tmp)
+    }
+}
+
+impl_drop!(ApplicationWindow);
+impl_TraitWidget!(ApplicationWindow);
+
+impl ::ContainerTrait for ApplicationWindow {}
+impl ::BinTrait for ApplicationWindow {}
+impl ::WindowTrait for ApplicationWindow {}
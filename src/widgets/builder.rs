@@ -4,10 +4,12 @@
 
 #![cfg_attr(not(feature = "gtk_3_10"), allow(unused_imports))]
 
+use std::ptr;
 use ffi::{self, C_GtkBuilder};
 use libc::{c_char, c_long};
 use traits::GObjectTrait;
 use glib::translate::ToGlibPtr;
+use glib;
 
 #[repr(C)]
 pub struct Builder {
@@ -74,6 +76,32 @@ impl Builder {
         }
     }
 
+    /// Adds the objects described in the UI file `file_name`, e.g. windows or `<menu>` elements
+    /// which are then got as MenuModels.
+    pub fn add_from_file(&self, file_name: &str) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+
+        if unsafe { ffi::gtk_builder_add_from_file(self.pointer, file_name.to_glib_none().0, &mut error) } != 0 {
+            Ok(())
+        } else {
+            Err(glib::GlibContainer::wrap(error))
+        }
+    }
+
+    pub fn add_from_string(&self, string: &str) -> Result<(), glib::Error> {
+        let mut error = ptr::null_mut();
+
+        if unsafe {
+            // Don't need a null-terminated string here
+            ffi::gtk_builder_add_from_string(self.pointer, string.as_ptr() as *const c_char, string.len() as c_long,
+                                             &mut error)
+        } != 0 {
+            Ok(())
+        } else {
+            Err(glib::GlibContainer::wrap(error))
+        }
+    }
+
     pub fn get_object<T: GObjectTrait>(&self, name: &str) -> Option<T> {
         let tmp = unsafe {
             ffi::gtk_builder_get_object(self.pointer, name.to_glib_none().0)
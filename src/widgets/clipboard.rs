@@ -0,0 +1,243 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkClipboard — Storing data on clipboards
+
+use std::cell::Cell;
+use std::ptr;
+use std::rc::Rc;
+use std::slice;
+use std::mem::transmute;
+use glib::translate::{from_glib_full, from_glib_none, ToGlibPtr};
+use glib::to_bool;
+use gdk::pixbuf::Pixbuf;
+use ffi;
+use libc::{c_char, c_int, c_uint, c_void};
+use {SelectionData, TargetEntry};
+
+/// GtkClipboard — Storing data on clipboards
+///
+/// A clipboard is shared with the other applications, so its content is only got
+/// asynchronously, with the `request_*` methods calling back when it arrives, or by blocking
+/// with the `wait_for_*` methods, which run the main loop meanwhile.
+///
+/// ```ignore
+/// let clipboard = Clipboard::get("CLIPBOARD").unwrap();
+/// clipboard.set_text("copied");
+/// clipboard.request_text(|_, text| {
+///     println!("{:?}", text);
+/// });
+/// ```
+pub struct Clipboard {
+    pointer: *mut ffi::C_GtkClipboard
+}
+
+impl Clipboard {
+    /// Returns the clipboard of the default display for the `selection`, "CLIPBOARD" for the
+    /// usual one or "PRIMARY" for the one filled by selecting text.
+    pub fn get(selection: &str) -> Option<Clipboard> {
+        let tmp_pointer = unsafe { ffi::gtk_clipboard_get(ffi::gdk_atom_intern(selection.to_glib_none().0, ffi::GFALSE)) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(Clipboard::wrap_pointer(tmp_pointer))
+        }
+    }
+
+    pub fn set_text(&self, text: &str) {
+        unsafe { ffi::gtk_clipboard_set_text(self.pointer, text.to_glib_none().0, text.len() as c_int) }
+    }
+
+    pub fn set_image(&self, pixbuf: &Pixbuf) {
+        unsafe { ffi::gtk_clipboard_set_image(self.pointer, pixbuf.to_glib_none().0) }
+    }
+
+    /// Offers the `targets`, `provider` is called with the selection data to fill and the info
+    /// of the target when an application asks for it. The provider is dropped once another
+    /// owner takes the clipboard. Returns false if the clipboard couldn't be taken.
+    pub fn set_with_data<F: Fn(&SelectionData, u32) + 'static>(&self, targets: &[TargetEntry], provider: F) -> bool {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+
+        let cleared = Rc::new(Cell::new(false));
+
+        unsafe {
+            let provider_data = Box::new(ProviderData {
+                provider: Box::new(provider),
+                cleared: cleared.clone()
+            });
+            let data: ffi::gpointer = transmute(provider_data);
+
+            if to_bool(ffi::gtk_clipboard_set_with_data(self.pointer, c_targets.as_ptr(), c_targets.len() as c_uint,
+                                                        get_trampoline, clear_trampoline, data)) {
+                true
+            } else {
+                // GTK may already have called clear_trampoline, which dropped the data
+                if !cleared.get() {
+                    let _: Box<ProviderData> = transmute(data);
+                }
+                false
+            }
+        }
+    }
+
+    /// Empties the clipboard if the application owns it.
+    pub fn clear(&self) {
+        unsafe { ffi::gtk_clipboard_clear(self.pointer) }
+    }
+
+    /// Calls `callback` with the text of the clipboard once received, None if there is none.
+    pub fn request_text<F: Fn(Clipboard, Option<String>) + 'static>(&self, callback: F) {
+        unsafe {
+            let f: Box<Box<Fn(Clipboard, Option<String>) + 'static>> = Box::new(Box::new(callback));
+            ffi::gtk_clipboard_request_text(self.pointer, request_text_trampoline, transmute(f))
+        }
+    }
+
+    /// Calls `callback` with the image of the clipboard once received, None if there is none.
+    pub fn request_image<F: Fn(Clipboard, Option<Pixbuf>) + 'static>(&self, callback: F) {
+        unsafe {
+            let f: Box<Box<Fn(Clipboard, Option<Pixbuf>) + 'static>> = Box::new(Box::new(callback));
+            ffi::gtk_clipboard_request_image(self.pointer, request_image_trampoline, transmute(f))
+        }
+    }
+
+    /// Calls `callback` with the targets the clipboard content is available as once received,
+    /// e.g. "UTF8_STRING" or "image/png".
+    pub fn request_targets<F: Fn(Clipboard, Vec<String>) + 'static>(&self, callback: F) {
+        unsafe {
+            let f: Box<Box<Fn(Clipboard, Vec<String>) + 'static>> = Box::new(Box::new(callback));
+            ffi::gtk_clipboard_request_targets(self.pointer, request_targets_trampoline, transmute(f))
+        }
+    }
+
+    /// Returns the text of the clipboard, running the main loop until it is received.
+    pub fn wait_for_text(&self) -> Option<String> {
+        unsafe { from_glib_full(ffi::gtk_clipboard_wait_for_text(self.pointer)) }
+    }
+
+    pub fn wait_for_image(&self) -> Option<Pixbuf> {
+        unsafe { from_glib_full(ffi::gtk_clipboard_wait_for_image(self.pointer)) }
+    }
+
+    /// Returns None if the clipboard is empty.
+    pub fn wait_for_targets(&self) -> Option<Vec<String>> {
+        let mut targets = ptr::null_mut();
+        let mut n_targets = 0;
+
+        if unsafe { to_bool(ffi::gtk_clipboard_wait_for_targets(self.pointer, &mut targets, &mut n_targets)) } {
+            let names = unsafe { atom_names(targets, n_targets) };
+            unsafe { ::glib_ffi::g_free(targets as *mut _) }
+            Some(names)
+        } else {
+            None
+        }
+    }
+
+    pub fn wait_is_text_available(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_text_available(self.pointer)) }
+    }
+
+    pub fn wait_is_image_available(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_clipboard_wait_is_image_available(self.pointer)) }
+    }
+
+    /// Asks the clipboard manager to keep the `targets` of the content set by the application
+    /// once it exits, all of them if `targets` is empty. See `store`.
+    pub fn set_can_store(&self, targets: &[TargetEntry]) {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+        let c_targets_ptr = if c_targets.is_empty() { ptr::null() } else { c_targets.as_ptr() };
+
+        unsafe { ffi::gtk_clipboard_set_can_store(self.pointer, c_targets_ptr, c_targets.len() as c_int) }
+    }
+
+    /// Hands the content over to the clipboard manager now instead of when the application exits.
+    pub fn store(&self) {
+        unsafe { ffi::gtk_clipboard_store(self.pointer) }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkClipboard {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GtkClipboard) -> Clipboard {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        Clipboard {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for Clipboard {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for Clipboard {
+    fn clone(&self) -> Clipboard {
+        Clipboard::wrap_pointer(self.pointer)
+    }
+}
+
+/// The data passed to `get_trampoline`, `cleared` tells whether `clear_trampoline` dropped it.
+struct ProviderData {
+    provider: Box<Fn(&SelectionData, u32) + 'static>,
+    cleared: Rc<Cell<bool>>
+}
+
+unsafe fn atom_names(atoms: *mut *mut c_void, n_atoms: c_int) -> Vec<String> {
+    if atoms.is_null() {
+        Vec::new()
+    } else {
+        slice::from_raw_parts(atoms, n_atoms as usize).iter()
+            .map(|&atom| from_glib_full(ffi::gdk_atom_name(atom)))
+            .collect()
+    }
+}
+
+extern "C" fn get_trampoline(_: *mut ffi::C_GtkClipboard, selection_data: *mut ffi::C_GtkSelectionData, info: c_uint,
+                             data: ffi::gpointer) {
+    unsafe {
+        let provider_data: &ProviderData = transmute(data);
+
+        (provider_data.provider)(&SelectionData::wrap_pointer(selection_data), info)
+    }
+}
+
+extern "C" fn clear_trampoline(_: *mut ffi::C_GtkClipboard, data: ffi::gpointer) {
+    unsafe {
+        let provider_data: Box<ProviderData> = transmute(data);
+
+        provider_data.cleared.set(true);
+    }
+}
+
+extern "C" fn request_text_trampoline(clipboard: *mut ffi::C_GtkClipboard, text: *const c_char, data: ffi::gpointer) {
+    unsafe {
+        let f: Box<Box<Fn(Clipboard, Option<String>) + 'static>> = transmute(data);
+
+        f(Clipboard::wrap_pointer(clipboard), from_glib_none(text))
+    }
+}
+
+extern "C" fn request_image_trampoline(clipboard: *mut ffi::C_GtkClipboard, pixbuf: *mut ::gdk_ffi::C_GdkPixbuf,
+                                       data: ffi::gpointer) {
+    unsafe {
+        let f: Box<Box<Fn(Clipboard, Option<Pixbuf>) + 'static>> = transmute(data);
+
+        f(Clipboard::wrap_pointer(clipboard), from_glib_none(pixbuf))
+    }
+}
+
+extern "C" fn request_targets_trampoline(clipboard: *mut ffi::C_GtkClipboard, atoms: *mut *mut c_void,
+                                         n_atoms: c_int, data: ffi::gpointer) {
+    unsafe {
+        let f: Box<Box<Fn(Clipboard, Vec<String>) + 'static>> = transmute(data);
+
+        f(Clipboard::wrap_pointer(clipboard), atom_names(atoms, n_atoms))
+    }
+}
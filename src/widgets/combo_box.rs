@@ -46,3 +46,4 @@ impl_TraitWidget!(ComboBox);
 impl ::ContainerTrait for ComboBox {}
 impl ::BinTrait for ComboBox {}
 impl ::ComboBoxTrait for ComboBox {}
+impl ::CellLayoutTrait for ComboBox {}
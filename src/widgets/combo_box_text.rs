@@ -86,3 +86,4 @@ impl_TraitWidget!(ComboBoxText);
 impl ::ContainerTrait for ComboBoxText {}
 impl ::BinTrait for ComboBoxText {}
 impl ::ComboBoxTrait for ComboBoxText {}
+impl ::CellLayoutTrait for ComboBoxText {}
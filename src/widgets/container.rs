@@ -0,0 +1,28 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! A container of any type
+
+use ffi;
+use cast::GTK_IS_CONTAINER;
+
+/// A container of any type, to use the ContainerTrait methods on a widget only known to be
+/// a container at runtime.
+struct_Widget!(Container);
+
+impl Container {
+    /// Returns None if `widget` isn't a container.
+    pub fn from_widget<T: ::WidgetTrait>(widget: &T) -> Option<Container> {
+        if GTK_IS_CONTAINER(widget.unwrap_widget()) {
+            Some(::FFIWidget::wrap_widget(widget.unwrap_widget()))
+        } else {
+            None
+        }
+    }
+}
+
+impl_drop!(Container);
+impl_TraitWidget!(Container);
+
+impl ::ContainerTrait for Container {}
@@ -4,17 +4,22 @@
 
 //! A container that allows reflowing its children
 
+use std::cmp::Ordering;
+use std::mem::transmute;
+use std::ptr;
 use cast::{GTK_FLOW_BOX_CHILD, GTK_FLOW_BOX};
 use ffi;
 use FFIWidget;
 use glib::{to_bool, to_gboolean};
+use glib;
+use libc::c_int;
 
 /// GtkFlowBox — A container that allows reflowing its children
 struct_Widget!(FlowBox);
 
 impl FlowBox {
     pub fn new() -> Option<FlowBox> {
-        let tmp_pointer = unsafe { ffi::gtk_revealer_new() };
+        let tmp_pointer = unsafe { ffi::gtk_flow_box_new() };
         check_pointer!(tmp_pointer, FlowBox)
     }
 
@@ -43,12 +48,18 @@ impl FlowBox {
         }
     }
 
-    pub fn set_colum_spacing(&self, spacing: u32) {
+    pub fn set_column_spacing(&self, spacing: u32) {
         unsafe {
             ffi::gtk_flow_box_set_column_spacing(GTK_FLOW_BOX(self.pointer), spacing)
         }
     }
 
+    /// Misspelled, use `set_column_spacing` instead.
+    #[deprecated(note = "misspelled, use `set_column_spacing` instead")]
+    pub fn set_colum_spacing(&self, spacing: u32) {
+        self.set_column_spacing(spacing)
+    }
+
     pub fn get_column_spacing(&self) -> u32 {
         unsafe {
             ffi::gtk_flow_box_get_column_spacing(GTK_FLOW_BOX(self.pointer))
@@ -137,6 +148,73 @@ impl FlowBox {
         }
     }
 
+    /// Calls `func` for each selected child, the selection must not be modified from it.
+    pub fn selected_foreach<F: FnMut(&FlowBox, &FlowBoxChild)>(&self, mut func: F) {
+        let mut f: &mut FnMut(&FlowBox, &FlowBoxChild) = &mut func;
+
+        unsafe {
+            ffi::gtk_flow_box_selected_foreach(GTK_FLOW_BOX(self.pointer),
+                                               Some(selected_foreach_trampoline),
+                                               &mut f as *mut _ as ffi::gpointer)
+        }
+    }
+
+    pub fn get_selected_children(&self) -> Vec<FlowBoxChild> {
+        let list: glib::List<*mut ffi::C_GtkFlowBoxChild> = glib::GlibContainer::wrap(unsafe {
+            ffi::gtk_flow_box_get_selected_children(GTK_FLOW_BOX(self.pointer))
+        });
+
+        list.iter().map(|&child| ::FFIWidget::wrap_widget(child as *mut ffi::C_GtkWidget)).collect()
+    }
+
+    /// The closure returns whether a child is visible. The children are filtered again when
+    /// `invalidate_filter` or `FlowBoxChild::changed` is called.
+    pub fn set_filter_func<F: Fn(&FlowBoxChild) -> bool + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(&FlowBoxChild) -> bool + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_flow_box_set_filter_func(GTK_FLOW_BOX(self.pointer),
+                                              Some(filter_trampoline),
+                                              transmute(f),
+                                              Some(destroy_filter_closure))
+        }
+    }
+
+    pub fn unset_filter_func(&self) {
+        unsafe {
+            ffi::gtk_flow_box_set_filter_func(GTK_FLOW_BOX(self.pointer), None, ptr::null_mut(), None)
+        }
+    }
+
+    pub fn invalidate_filter(&self) {
+        unsafe {
+            ffi::gtk_flow_box_invalidate_filter(GTK_FLOW_BOX(self.pointer))
+        }
+    }
+
+    /// The closure decides the order of two children. The children are sorted again when
+    /// `invalidate_sort` or `FlowBoxChild::changed` is called.
+    pub fn set_sort_func<F: Fn(&FlowBoxChild, &FlowBoxChild) -> Ordering + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(&FlowBoxChild, &FlowBoxChild) -> Ordering + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_flow_box_set_sort_func(GTK_FLOW_BOX(self.pointer),
+                                            Some(sort_trampoline),
+                                            transmute(f),
+                                            Some(destroy_sort_closure))
+        }
+    }
+
+    pub fn unset_sort_func(&self) {
+        unsafe {
+            ffi::gtk_flow_box_set_sort_func(GTK_FLOW_BOX(self.pointer), None, ptr::null_mut(), None)
+        }
+    }
+
+    pub fn invalidate_sort(&self) {
+        unsafe {
+            ffi::gtk_flow_box_invalidate_sort(GTK_FLOW_BOX(self.pointer))
+        }
+    }
+
     pub fn set_selection_mode(&self, mode: ::SelectionMode) {
         unsafe {
             ffi::gtk_flow_box_set_selection_mode(GTK_FLOW_BOX(self.pointer), mode)
@@ -164,6 +242,53 @@ impl FlowBox {
     }
 }
 
+extern "C" fn selected_foreach_trampoline(flow_box: *mut ffi::C_GtkFlowBox, child: *mut ffi::C_GtkFlowBoxChild,
+                                          data: ffi::gpointer) {
+    unsafe {
+        let f: &mut &mut FnMut(&FlowBox, &FlowBoxChild) = transmute(data);
+        let flow_box: FlowBox = FFIWidget::wrap_widget(flow_box as *mut ffi::C_GtkWidget);
+        let child: FlowBoxChild = FFIWidget::wrap_widget(child as *mut ffi::C_GtkWidget);
+
+        f(&flow_box, &child)
+    }
+}
+
+extern "C" fn filter_trampoline(child: *mut ffi::C_GtkFlowBoxChild, data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(&FlowBoxChild) -> bool + 'static> = transmute(data);
+        let child: FlowBoxChild = FFIWidget::wrap_widget(child as *mut ffi::C_GtkWidget);
+
+        to_gboolean(f(&child))
+    }
+}
+
+extern "C" fn destroy_filter_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(&FlowBoxChild) -> bool + 'static>> = transmute(data);
+    }
+}
+
+extern "C" fn sort_trampoline(child1: *mut ffi::C_GtkFlowBoxChild, child2: *mut ffi::C_GtkFlowBoxChild,
+                              data: ffi::gpointer) -> c_int {
+    unsafe {
+        let f: &Box<Fn(&FlowBoxChild, &FlowBoxChild) -> Ordering + 'static> = transmute(data);
+        let child1: FlowBoxChild = FFIWidget::wrap_widget(child1 as *mut ffi::C_GtkWidget);
+        let child2: FlowBoxChild = FFIWidget::wrap_widget(child2 as *mut ffi::C_GtkWidget);
+
+        match f(&child1, &child2) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1
+        }
+    }
+}
+
+extern "C" fn destroy_sort_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(&FlowBoxChild, &FlowBoxChild) -> Ordering + 'static>> = transmute(data);
+    }
+}
+
 impl_drop!(FlowBox);
 impl_TraitWidget!(FlowBox);
 
@@ -0,0 +1,134 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GMenu — A simple implementation of GMenuModel
+
+use glib::translate::ToGlibPtr;
+use ffi;
+use {GMenuItem, MenuModel};
+
+/// GMenu — A simple implementation of GMenuModel
+///
+/// Named after the C type to not be mistaken for the `Menu` widget. The items point at actions
+/// through detailed action names such as "app.quit" or "win.zoom(2)", the sections are shown
+/// separated in the same menu and the submenus as nested menus.
+///
+/// ```ignore
+/// let edit = GMenu::new();
+/// edit.append(Some("Copy"), Some("win.copy"));
+/// edit.append(Some("Paste"), Some("win.paste"));
+///
+/// let menubar = GMenu::new();
+/// menubar.append_submenu(Some("Edit"), &edit.to_menu_model());
+/// application.set_menubar(Some(&menubar.to_menu_model()));
+/// ```
+pub struct GMenu {
+    pointer: *mut ffi::C_GMenu
+}
+
+impl GMenu {
+    pub fn new() -> GMenu {
+        GMenu {
+            pointer: unsafe { ffi::g_menu_new() }
+        }
+    }
+
+    /// Prevents any further change, the menu can then be shared more efficiently.
+    pub fn freeze(&self) {
+        unsafe { ffi::g_menu_freeze(self.pointer) }
+    }
+
+    /// Appends an item with `label` which activates `detailed_action`.
+    pub fn append(&self, label: Option<&str>, detailed_action: Option<&str>) {
+        unsafe { ffi::g_menu_append(self.pointer, label.to_glib_none().0, detailed_action.to_glib_none().0) }
+    }
+
+    pub fn prepend(&self, label: Option<&str>, detailed_action: Option<&str>) {
+        unsafe { ffi::g_menu_prepend(self.pointer, label.to_glib_none().0, detailed_action.to_glib_none().0) }
+    }
+
+    /// Inserts an item at `position`, -1 appends it.
+    pub fn insert(&self, position: i32, label: Option<&str>, detailed_action: Option<&str>) {
+        unsafe {
+            ffi::g_menu_insert(self.pointer, position, label.to_glib_none().0, detailed_action.to_glib_none().0)
+        }
+    }
+
+    /// Appends a copy of `item`, changing `item` afterwards doesn't change the menu.
+    pub fn append_item(&self, item: &GMenuItem) {
+        unsafe { ffi::g_menu_append_item(self.pointer, item.unwrap_pointer()) }
+    }
+
+    pub fn prepend_item(&self, item: &GMenuItem) {
+        unsafe { ffi::g_menu_prepend_item(self.pointer, item.unwrap_pointer()) }
+    }
+
+    pub fn insert_item(&self, position: i32, item: &GMenuItem) {
+        unsafe { ffi::g_menu_insert_item(self.pointer, position, item.unwrap_pointer()) }
+    }
+
+    /// Appends the items of `section`, separated from the other ones and under `label` if any.
+    pub fn append_section(&self, label: Option<&str>, section: &MenuModel) {
+        unsafe { ffi::g_menu_append_section(self.pointer, label.to_glib_none().0, section.unwrap_pointer()) }
+    }
+
+    pub fn prepend_section(&self, label: Option<&str>, section: &MenuModel) {
+        unsafe { ffi::g_menu_prepend_section(self.pointer, label.to_glib_none().0, section.unwrap_pointer()) }
+    }
+
+    pub fn insert_section(&self, position: i32, label: Option<&str>, section: &MenuModel) {
+        unsafe {
+            ffi::g_menu_insert_section(self.pointer, position, label.to_glib_none().0, section.unwrap_pointer())
+        }
+    }
+
+    /// Appends an item with `label` opening `submenu`.
+    pub fn append_submenu(&self, label: Option<&str>, submenu: &MenuModel) {
+        unsafe { ffi::g_menu_append_submenu(self.pointer, label.to_glib_none().0, submenu.unwrap_pointer()) }
+    }
+
+    pub fn prepend_submenu(&self, label: Option<&str>, submenu: &MenuModel) {
+        unsafe { ffi::g_menu_prepend_submenu(self.pointer, label.to_glib_none().0, submenu.unwrap_pointer()) }
+    }
+
+    pub fn insert_submenu(&self, position: i32, label: Option<&str>, submenu: &MenuModel) {
+        unsafe {
+            ffi::g_menu_insert_submenu(self.pointer, position, label.to_glib_none().0, submenu.unwrap_pointer())
+        }
+    }
+
+    pub fn remove(&self, position: i32) {
+        unsafe { ffi::g_menu_remove(self.pointer, position) }
+    }
+
+    /// Returns the menu as a MenuModel, sharing its items.
+    pub fn to_menu_model(&self) -> MenuModel {
+        MenuModel::wrap_pointer(self.pointer as *mut ffi::C_GMenuModel)
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GMenu {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GMenu) -> GMenu {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        GMenu {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for GMenu {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for GMenu {
+    fn clone(&self) -> GMenu {
+        GMenu::wrap_pointer(self.pointer)
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GMenuItem — An entry in a menu
+
+use std::ptr;
+use glib::translate::ToGlibPtr;
+use ffi;
+use {MenuModel, Variant};
+
+/// GMenuItem — An entry in a menu
+///
+/// Named after the C type to not be mistaken for the `MenuItem` widget. An item is built
+/// before being added to a `GMenu`, which copies it.
+pub struct GMenuItem {
+    pointer: *mut ffi::C_GMenuItem
+}
+
+impl GMenuItem {
+    pub fn new(label: Option<&str>, detailed_action: Option<&str>) -> GMenuItem {
+        GMenuItem {
+            pointer: unsafe { ffi::g_menu_item_new(label.to_glib_none().0, detailed_action.to_glib_none().0) }
+        }
+    }
+
+    pub fn new_section(label: Option<&str>, section: &MenuModel) -> GMenuItem {
+        GMenuItem {
+            pointer: unsafe { ffi::g_menu_item_new_section(label.to_glib_none().0, section.unwrap_pointer()) }
+        }
+    }
+
+    pub fn new_submenu(label: Option<&str>, submenu: &MenuModel) -> GMenuItem {
+        GMenuItem {
+            pointer: unsafe { ffi::g_menu_item_new_submenu(label.to_glib_none().0, submenu.unwrap_pointer()) }
+        }
+    }
+
+    pub fn set_label(&self, label: Option<&str>) {
+        unsafe { ffi::g_menu_item_set_label(self.pointer, label.to_glib_none().0) }
+    }
+
+    /// Sets the action and target from a detailed action name, e.g. "win.zoom(2)".
+    pub fn set_detailed_action(&self, detailed_action: &str) {
+        unsafe { ffi::g_menu_item_set_detailed_action(self.pointer, detailed_action.to_glib_none().0) }
+    }
+
+    /// Sets the action activated by the item and the parameter it is activated with, for a
+    /// stateful action the item is shown as active when the state equals `target_value`.
+    pub fn set_action_and_target_value(&self, action: Option<&str>, target_value: Option<&Variant>) {
+        unsafe {
+            ffi::g_menu_item_set_action_and_target_value(self.pointer, action.to_glib_none().0,
+                                                         target_value.map_or(ptr::null_mut(), |v| v.unwrap_pointer()))
+        }
+    }
+
+    /// Sets an attribute such as "label", "action", "target" or "accel", None removes it.
+    pub fn set_attribute_value(&self, attribute: &str, value: Option<&Variant>) {
+        unsafe {
+            ffi::g_menu_item_set_attribute_value(self.pointer, attribute.to_glib_none().0,
+                                                 value.map_or(ptr::null_mut(), |v| v.unwrap_pointer()))
+        }
+    }
+
+    /// Returns the attribute, None if the item hasn't got it or if it hasn't got the type
+    /// `expected_type`, a type string. None too if `expected_type` isn't a valid type string.
+    pub fn get_attribute_value(&self, attribute: &str, expected_type: Option<&str>) -> Option<Variant> {
+        if !expected_type.map_or(true, Variant::is_valid_type_string) {
+            return None;
+        }
+        let tmp = unsafe {
+            ffi::g_menu_item_get_attribute_value(self.pointer, attribute.to_glib_none().0,
+                                                 expected_type.to_glib_none().0 as *const ffi::C_GVariantType)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Variant::wrap_pointer(tmp))
+        }
+    }
+
+    /// Sets a link such as "section" or "submenu", None removes it.
+    pub fn set_link(&self, link: &str, model: Option<&MenuModel>) {
+        unsafe {
+            ffi::g_menu_item_set_link(self.pointer, link.to_glib_none().0,
+                                      model.map_or(ptr::null_mut(), |m| m.unwrap_pointer()))
+        }
+    }
+
+    pub fn get_link(&self, link: &str) -> Option<MenuModel> {
+        let tmp = unsafe { ffi::g_menu_item_get_link(self.pointer, link.to_glib_none().0) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            let model = MenuModel::wrap_pointer(tmp);
+            unsafe { ::glib_ffi::g_object_unref(tmp as *mut _) }
+            Some(model)
+        }
+    }
+
+    pub fn set_section(&self, section: Option<&MenuModel>) {
+        unsafe { ffi::g_menu_item_set_section(self.pointer, section.map_or(ptr::null_mut(), |m| m.unwrap_pointer())) }
+    }
+
+    pub fn set_submenu(&self, submenu: Option<&MenuModel>) {
+        unsafe { ffi::g_menu_item_set_submenu(self.pointer, submenu.map_or(ptr::null_mut(), |m| m.unwrap_pointer())) }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GMenuItem {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GMenuItem) -> GMenuItem {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        GMenuItem {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for GMenuItem {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for GMenuItem {
+    fn clone(&self) -> GMenuItem {
+        GMenuItem::wrap_pointer(self.pointer)
+    }
+}
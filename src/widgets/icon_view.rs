@@ -2,9 +2,12 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::ptr;
 use cast::{GTK_ICON_VIEW, GTK_CELL_RENDERER};
 use ffi;
-use {TreeModel, TreePath};
+use glib::to_bool;
+use gdk;
+use {TargetEntry, TreeModel, TreePath};
 use glib_ffi;
 use libc::c_void;
 
@@ -258,6 +261,25 @@ impl IconView {
         unsafe { ffi::gtk_icon_view_get_item_column(GTK_ICON_VIEW(self.pointer), path.unwrap_pointer()) }
     }
 
+    pub fn enable_model_drag_source(&self, start_button_mask: gdk::ModifierType, targets: &[TargetEntry],
+                                    actions: gdk::DragAction) {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+
+        unsafe {
+            ffi::gtk_icon_view_enable_model_drag_source(GTK_ICON_VIEW(self.pointer), start_button_mask,
+                                                        c_targets.as_ptr(), c_targets.len() as i32, actions)
+        }
+    }
+
+    pub fn enable_model_drag_dest(&self, targets: &[TargetEntry], actions: gdk::DragAction) {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+
+        unsafe {
+            ffi::gtk_icon_view_enable_model_drag_dest(GTK_ICON_VIEW(self.pointer), c_targets.as_ptr(),
+                                                      c_targets.len() as i32, actions)
+        }
+    }
+
     pub fn unset_model_drag_source(&self) {
         unsafe { ffi::gtk_icon_view_unset_model_drag_source(GTK_ICON_VIEW(self.pointer)) }
     }
@@ -281,12 +303,31 @@ impl IconView {
         unsafe { ffi::gtk_icon_view_set_drag_dest_item(GTK_ICON_VIEW(self.pointer), path.unwrap_pointer(), pos) }
     }
 
-    pub fn get_drag_dest_item(&self, path: &TreePath, pos: &mut ::IconViewDropPosition) {
-        unsafe { ffi::gtk_icon_view_get_drag_dest_item(GTK_ICON_VIEW(self.pointer), &mut path.unwrap_pointer(), pos) }
+    pub fn get_drag_dest_item(&self) -> Option<(TreePath, ::IconViewDropPosition)> {
+        let mut path = ptr::null_mut();
+        let mut pos = ::IconViewDropPosition::NoDrop;
+
+        unsafe { ffi::gtk_icon_view_get_drag_dest_item(GTK_ICON_VIEW(self.pointer), &mut path, &mut pos) };
+        if path.is_null() {
+            None
+        } else {
+            Some((TreePath::wrap_pointer(path), pos))
+        }
     }
 
-    pub fn get_dest_item_at_pos(&self, drag_x: i32, drag_y: i32, path: &TreePath, pos: &mut ::IconViewDropPosition) {
-        unsafe { ffi::gtk_icon_view_get_dest_item_at_pos(GTK_ICON_VIEW(self.pointer), drag_x, drag_y, &mut path.unwrap_pointer(), pos) }
+    /// Determines the destination item for the given drag position, the coordinates are
+    /// relative to the widget.
+    pub fn get_dest_item_at_pos(&self, drag_x: i32, drag_y: i32) -> Option<(TreePath, ::IconViewDropPosition)> {
+        let mut path = ptr::null_mut();
+        let mut pos = ::IconViewDropPosition::NoDrop;
+
+        if to_bool(unsafe {
+            ffi::gtk_icon_view_get_dest_item_at_pos(GTK_ICON_VIEW(self.pointer), drag_x, drag_y, &mut path, &mut pos)
+        }) && !path.is_null() {
+            Some((TreePath::wrap_pointer(path), pos))
+        } else {
+            None
+        }
     }
 }
 
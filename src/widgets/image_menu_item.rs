@@ -0,0 +1,85 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkImageMenuItem — A menu item with an icon
+
+use std::ptr;
+use glib::translate::ToGlibPtr;
+use glib::{to_bool, to_gboolean};
+use ffi;
+use cast::GTK_IMAGE_MENU_ITEM;
+use {AccelGroup, FFIWidget, Widget};
+
+/// GtkImageMenuItem — A menu item with an icon
+///
+/// Deprecated since GTK 3.10, where a MenuItem holding a Box with an Image and a Label should
+/// be used instead.
+struct_Widget!(ImageMenuItem);
+
+impl ImageMenuItem {
+    pub fn new() -> Option<ImageMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_image_menu_item_new() };
+        check_pointer!(tmp_pointer, ImageMenuItem)
+    }
+
+    pub fn new_with_label(label: &str) -> Option<ImageMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_image_menu_item_new_with_label(label.to_glib_none().0) };
+        check_pointer!(tmp_pointer, ImageMenuItem)
+    }
+
+    pub fn new_with_mnemonic(mnemonic: &str) -> Option<ImageMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_image_menu_item_new_with_mnemonic(mnemonic.to_glib_none().0) };
+        check_pointer!(tmp_pointer, ImageMenuItem)
+    }
+
+    /// Creates an item with the label and icon of the stock item `stock_id`, its accelerator
+    /// is added to `accel_group` if any.
+    pub fn new_from_stock(stock_id: &str, accel_group: Option<&AccelGroup>) -> Option<ImageMenuItem> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_image_menu_item_new_from_stock(stock_id.to_glib_none().0,
+                                                    accel_group.map_or(ptr::null_mut(), |a| a.unwrap_pointer()))
+        };
+        check_pointer!(tmp_pointer, ImageMenuItem)
+    }
+
+    pub fn set_image<T: ::WidgetTrait>(&self, image: Option<&T>) {
+        unsafe {
+            ffi::gtk_image_menu_item_set_image(GTK_IMAGE_MENU_ITEM(self.pointer),
+                                               image.map_or(ptr::null_mut(), |i| i.unwrap_widget()))
+        }
+    }
+
+    pub fn get_image(&self) -> Option<Widget> {
+        let tmp = unsafe { ffi::gtk_image_menu_item_get_image(GTK_IMAGE_MENU_ITEM(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp))
+        }
+    }
+
+    /// Shows the image even if the `gtk-menu-images` setting hides the menu images.
+    pub fn set_always_show_image(&self, always_show: bool) {
+        unsafe {
+            ffi::gtk_image_menu_item_set_always_show_image(GTK_IMAGE_MENU_ITEM(self.pointer), to_gboolean(always_show))
+        }
+    }
+
+    pub fn get_always_show_image(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_image_menu_item_get_always_show_image(GTK_IMAGE_MENU_ITEM(self.pointer))) }
+    }
+
+    /// Adds the accelerator of the stock item to `accel_group`.
+    pub fn set_accel_group(&self, accel_group: &AccelGroup) {
+        unsafe { ffi::gtk_image_menu_item_set_accel_group(GTK_IMAGE_MENU_ITEM(self.pointer), accel_group.unwrap_pointer()) }
+    }
+}
+
+impl_drop!(ImageMenuItem);
+impl_TraitWidget!(ImageMenuItem);
+
+impl ::ContainerTrait for ImageMenuItem {}
+impl ::BinTrait for ImageMenuItem {}
+impl ::MenuItemTrait for ImageMenuItem {}
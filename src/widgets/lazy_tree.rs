@@ -0,0 +1,173 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Populates the children of a TreeStore row the first time it is expanded
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use signal::TreeViewSignals;
+use {TreeIter, TreeModel, TreeStore, TreeView};
+
+const REGULAR: &'static str = "";
+const PLACEHOLDER: &'static str = "lazy-tree-placeholder";
+const LOADING: &'static str = "lazy-tree-loading";
+
+/// LazyTree — Populates the children of a TreeStore row the first time it is expanded
+///
+/// The marker column is a string column of the store reserved to tell placeholder and
+/// loading rows apart from regular rows, which is why rows must be added with `append`
+/// and `append_lazy`.
+#[derive(Clone)]
+pub struct LazyTree {
+    inner: Rc<LazyTreeInner>
+}
+
+struct LazyTreeInner {
+    store: TreeStore,
+    marker_column: i32,
+    loading_label: RefCell<Option<(i32, String)>>,
+    unload_on_collapse: Cell<bool>,
+    populate: Box<Fn(&LazyTree, &TreeIter) -> bool + 'static>
+}
+
+impl LazyTree {
+    /// `populate` appends the children of the given row and returns true. If the children are
+    /// loaded asynchronously it returns false instead, a loading row is then displayed until
+    /// `finish_loading` is called.
+    pub fn new<F: Fn(&LazyTree, &TreeIter) -> bool + 'static>(tree_view: &TreeView, store: &TreeStore,
+                                                             marker_column: i32, populate: F) -> LazyTree {
+        let lazy = LazyTree {
+            inner: Rc::new(LazyTreeInner {
+                store: store.clone(),
+                marker_column: marker_column,
+                loading_label: RefCell::new(None),
+                unload_on_collapse: Cell::new(false),
+                populate: Box::new(populate)
+            })
+        };
+
+        let on_expand = lazy.clone();
+        tree_view.connect_test_expand_row(move |_, iter, _| {
+            on_expand.populate(iter);
+            false
+        });
+
+        let on_collapse = lazy.clone();
+        tree_view.connect_row_collapsed(move |_, iter, _| {
+            if on_collapse.inner.unload_on_collapse.get() {
+                on_collapse.unload(iter);
+            }
+        });
+
+        lazy
+    }
+
+    pub fn get_store(&self) -> TreeStore {
+        self.inner.store.clone()
+    }
+
+    /// If set, the children of a row are removed when it is collapsed and populated again
+    /// the next time it is expanded.
+    pub fn set_unload_on_collapse(&self, unload: bool) {
+        self.inner.unload_on_collapse.set(unload)
+    }
+
+    /// Sets the text displayed in `column` by the loading row.
+    pub fn set_loading_label(&self, column: i32, label: &str) {
+        *self.inner.loading_label.borrow_mut() = Some((column, label.to_owned()));
+    }
+
+    /// Appends a row which has no lazily populated children.
+    pub fn append(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) {
+        self.inner.store.append(iter, parent);
+        self.inner.store.set_string(iter, self.inner.marker_column, REGULAR);
+    }
+
+    /// Appends a row whose children are populated the first time it is expanded.
+    pub fn append_lazy(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) {
+        self.append(iter, parent);
+        self.add_placeholder(iter);
+    }
+
+    /// Removes the loading row of `parent`, to be called once its children have been
+    /// appended asynchronously.
+    pub fn finish_loading(&self, parent: &TreeIter) {
+        let model = self.get_model();
+        let mut child = TreeIter::new();
+        let mut valid = model.iter_children(&mut child, Some(parent)) == Ok(true);
+
+        while valid {
+            if self.get_marker(&model, &child) == LOADING {
+                valid = self.inner.store.remove(&child);
+            } else {
+                valid = model.iter_next(&mut child) == Ok(true);
+            }
+        }
+    }
+
+    /// Returns true while the children of `parent` are being loaded asynchronously, i.e.
+    /// until `finish_loading` is called.
+    pub fn is_loading(&self, parent: &TreeIter) -> bool {
+        let model = self.get_model();
+        let mut child = TreeIter::new();
+        let mut valid = model.iter_children(&mut child, Some(parent)) == Ok(true);
+
+        while valid {
+            if self.get_marker(&model, &child) == LOADING {
+                return true;
+            }
+            valid = model.iter_next(&mut child) == Ok(true);
+        }
+        false
+    }
+
+    /// Removes the children of `parent`, they will be populated again on the next expand.
+    /// A row whose children are still loading is left as is, the pending load then completes
+    /// into it instead of adding its children next to a new placeholder.
+    pub fn unload(&self, parent: &TreeIter) {
+        if self.is_loading(parent) {
+            return;
+        }
+
+        let model = self.get_model();
+        let mut child = TreeIter::new();
+
+        if model.iter_children(&mut child, Some(parent)) == Ok(true) {
+            while self.inner.store.remove(&child) {}
+        }
+        self.add_placeholder(parent);
+    }
+
+    fn populate(&self, parent: &TreeIter) {
+        let model = self.get_model();
+        let mut child = TreeIter::new();
+
+        if model.iter_children(&mut child, Some(parent)) != Ok(true) || self.get_marker(&model, &child) != PLACEHOLDER {
+            return;
+        }
+        self.inner.store.set_string(&child, self.inner.marker_column, LOADING);
+        if let Some((column, ref label)) = *self.inner.loading_label.borrow() {
+            self.inner.store.set_string(&child, column, label);
+        }
+
+        if (self.inner.populate)(self, parent) {
+            self.finish_loading(parent);
+        }
+    }
+
+    fn add_placeholder(&self, parent: &TreeIter) {
+        let mut placeholder = TreeIter::new();
+
+        self.inner.store.append(&mut placeholder, Some(parent));
+        self.inner.store.set_string(&placeholder, self.inner.marker_column, PLACEHOLDER);
+    }
+
+    fn get_model(&self) -> TreeModel {
+        self.inner.store.get_model().unwrap()
+    }
+
+    fn get_marker(&self, model: &TreeModel, iter: &TreeIter) -> String {
+        model.get_value(iter, self.inner.marker_column).map(|value| value.get::<String>()).unwrap_or(String::new())
+    }
+}
@@ -4,10 +4,16 @@
 
 //! A container that allows reflowing its children
 
+use std::cmp::Ordering;
+use std::mem::transmute;
+use std::ptr;
 use ffi;
 use cast::{GTK_LIST_BOX_ROW, GTK_LIST_BOX};
 use FFIWidget;
 use glib::{to_bool, to_gboolean};
+#[cfg(feature = "gtk_3_14")]
+use glib;
+use libc::c_int;
 
 /// GtkFlowBox — A container that allows reflowing its children
 struct_Widget!(ListBox);
@@ -73,6 +79,49 @@ impl ListBox {
         }
     }
 
+    #[cfg(feature = "gtk_3_14")]
+    pub fn unselect_row(&self, row: &ListBoxRow) {
+        unsafe {
+            ffi::gtk_list_box_unselect_row(GTK_LIST_BOX(self.pointer),
+                                           GTK_LIST_BOX_ROW(row.unwrap_widget()))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn select_all(&self) {
+        unsafe {
+            ffi::gtk_list_box_select_all(GTK_LIST_BOX(self.pointer))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn unselect_all(&self) {
+        unsafe {
+            ffi::gtk_list_box_unselect_all(GTK_LIST_BOX(self.pointer))
+        }
+    }
+
+    /// Calls `func` for each selected row, the selection must not be modified from it.
+    #[cfg(feature = "gtk_3_14")]
+    pub fn selected_foreach<F: FnMut(&ListBox, &ListBoxRow)>(&self, mut func: F) {
+        let mut f: &mut FnMut(&ListBox, &ListBoxRow) = &mut func;
+
+        unsafe {
+            ffi::gtk_list_box_selected_foreach(GTK_LIST_BOX(self.pointer),
+                                               Some(selected_foreach_trampoline),
+                                               &mut f as *mut _ as ffi::gpointer)
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn get_selected_rows(&self) -> Vec<ListBoxRow> {
+        let list: glib::List<*mut ffi::C_GtkListBoxRow> = glib::GlibContainer::wrap(unsafe {
+            ffi::gtk_list_box_get_selected_rows(GTK_LIST_BOX(self.pointer))
+        });
+
+        list.iter().map(|&row| ::FFIWidget::wrap_widget(row as *mut ffi::C_GtkWidget)).collect()
+    }
+
     pub fn set_placeholder<T: ::WidgetTrait>(&self, placeholder: &T) {
         unsafe {
             ffi::gtk_list_box_set_placeholder(GTK_LIST_BOX(self.pointer),
@@ -110,6 +159,72 @@ impl ListBox {
         }
     }
 
+    /// The closure decides the order of two rows. The rows are sorted again when
+    /// `invalidate_sort` or `ListBoxRow::changed` is called.
+    pub fn set_sort_func<F: Fn(&ListBoxRow, &ListBoxRow) -> Ordering + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(&ListBoxRow, &ListBoxRow) -> Ordering + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_list_box_set_sort_func(GTK_LIST_BOX(self.pointer),
+                                            Some(sort_trampoline),
+                                            transmute(f),
+                                            Some(destroy_sort_closure))
+        }
+    }
+
+    pub fn unset_sort_func(&self) {
+        unsafe {
+            ffi::gtk_list_box_set_sort_func(GTK_LIST_BOX(self.pointer), None, ptr::null_mut(), None)
+        }
+    }
+
+    /// The closure returns whether a row is visible. The rows are filtered again when
+    /// `invalidate_filter` or `ListBoxRow::changed` is called.
+    pub fn set_filter_func<F: Fn(&ListBoxRow) -> bool + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(&ListBoxRow) -> bool + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_list_box_set_filter_func(GTK_LIST_BOX(self.pointer),
+                                              Some(filter_trampoline),
+                                              transmute(f),
+                                              Some(destroy_filter_closure))
+        }
+    }
+
+    pub fn unset_filter_func(&self) {
+        unsafe {
+            ffi::gtk_list_box_set_filter_func(GTK_LIST_BOX(self.pointer), None, ptr::null_mut(), None)
+        }
+    }
+
+    /// The closure receives a row and the row before it, if any, and updates the header of
+    /// the row with `ListBoxRow::set_header`.
+    pub fn set_header_func<F: Fn(&ListBoxRow, Option<&ListBoxRow>) + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(&ListBoxRow, Option<&ListBoxRow>) + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_list_box_set_header_func(GTK_LIST_BOX(self.pointer),
+                                              Some(header_trampoline),
+                                              transmute(f),
+                                              Some(destroy_header_closure))
+        }
+    }
+
+    pub fn unset_header_func(&self) {
+        unsafe {
+            ffi::gtk_list_box_set_header_func(GTK_LIST_BOX(self.pointer), None, ptr::null_mut(), None)
+        }
+    }
+
+    pub fn invalidate_sort(&self) {
+        unsafe {
+            ffi::gtk_list_box_invalidate_sort(GTK_LIST_BOX(self.pointer))
+        }
+    }
+
+    pub fn invalidate_filter(&self) {
+        unsafe {
+            ffi::gtk_list_box_invalidate_filter(GTK_LIST_BOX(self.pointer))
+        }
+    }
+
     pub fn invalidate_header(&self) {
         unsafe {
             ffi::gtk_list_box_invalidate_headers(GTK_LIST_BOX(self.pointer))
@@ -143,6 +258,75 @@ impl ListBox {
     }
 }
 
+extern "C" fn sort_trampoline(row1: *mut ffi::C_GtkListBoxRow, row2: *mut ffi::C_GtkListBoxRow,
+                              data: ffi::gpointer) -> c_int {
+    unsafe {
+        let f: &Box<Fn(&ListBoxRow, &ListBoxRow) -> Ordering + 'static> = transmute(data);
+        let row1: ListBoxRow = FFIWidget::wrap_widget(row1 as *mut ffi::C_GtkWidget);
+        let row2: ListBoxRow = FFIWidget::wrap_widget(row2 as *mut ffi::C_GtkWidget);
+
+        match f(&row1, &row2) {
+            Ordering::Less => -1,
+            Ordering::Equal => 0,
+            Ordering::Greater => 1
+        }
+    }
+}
+
+extern "C" fn destroy_sort_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(&ListBoxRow, &ListBoxRow) -> Ordering + 'static>> = transmute(data);
+    }
+}
+
+extern "C" fn filter_trampoline(row: *mut ffi::C_GtkListBoxRow, data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(&ListBoxRow) -> bool + 'static> = transmute(data);
+        let row: ListBoxRow = FFIWidget::wrap_widget(row as *mut ffi::C_GtkWidget);
+
+        to_gboolean(f(&row))
+    }
+}
+
+extern "C" fn destroy_filter_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(&ListBoxRow) -> bool + 'static>> = transmute(data);
+    }
+}
+
+extern "C" fn header_trampoline(row: *mut ffi::C_GtkListBoxRow, before: *mut ffi::C_GtkListBoxRow,
+                                data: ffi::gpointer) {
+    unsafe {
+        let f: &Box<Fn(&ListBoxRow, Option<&ListBoxRow>) + 'static> = transmute(data);
+        let row: ListBoxRow = FFIWidget::wrap_widget(row as *mut ffi::C_GtkWidget);
+        let before: Option<ListBoxRow> = if before.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(before as *mut ffi::C_GtkWidget))
+        };
+
+        f(&row, before.as_ref())
+    }
+}
+
+extern "C" fn destroy_header_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(&ListBoxRow, Option<&ListBoxRow>) + 'static>> = transmute(data);
+    }
+}
+
+#[cfg(feature = "gtk_3_14")]
+extern "C" fn selected_foreach_trampoline(list_box: *mut ffi::C_GtkListBox, row: *mut ffi::C_GtkListBoxRow,
+                                          data: ffi::gpointer) {
+    unsafe {
+        let f: &mut &mut FnMut(&ListBox, &ListBoxRow) = transmute(data);
+        let list_box: ListBox = FFIWidget::wrap_widget(list_box as *mut ffi::C_GtkWidget);
+        let row: ListBoxRow = FFIWidget::wrap_widget(row as *mut ffi::C_GtkWidget);
+
+        f(&list_box, &row)
+    }
+}
+
 
 // pub fn gtk_list_box_drag_unhighlight_row         (list_box: *C_GtkListBox);
 // pub fn gtk_list_box_drag_highlight_row           (list_box: *C_GtkListBox, row: *C_GtkListBoxRow);
@@ -190,6 +374,41 @@ impl ListBoxRow {
             ffi::gtk_list_box_row_get_index(GTK_LIST_BOX_ROW(self.pointer))
         }
     }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn is_selected(&self) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_list_box_row_is_selected(GTK_LIST_BOX_ROW(self.pointer)))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn set_activatable(&self, activatable: bool) {
+        unsafe {
+            ffi::gtk_list_box_row_set_activatable(GTK_LIST_BOX_ROW(self.pointer), to_gboolean(activatable))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn get_activatable(&self) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_list_box_row_get_activatable(GTK_LIST_BOX_ROW(self.pointer)))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn set_selectable(&self, selectable: bool) {
+        unsafe {
+            ffi::gtk_list_box_row_set_selectable(GTK_LIST_BOX_ROW(self.pointer), to_gboolean(selectable))
+        }
+    }
+
+    #[cfg(feature = "gtk_3_14")]
+    pub fn get_selectable(&self) -> bool {
+        unsafe {
+            to_bool(ffi::gtk_list_box_row_get_selectable(GTK_LIST_BOX_ROW(self.pointer)))
+        }
+    }
 }
 
 impl_drop!(ListBoxRow);
@@ -32,29 +32,40 @@ impl ListStore {
         }
     }
 
+    /// Removes the row of `iter` and moves `iter` to the next row, returns false if there is none.
     pub fn remove(&self, iter: &TreeIter) -> bool {
-        unsafe { to_bool(ffi::gtk_list_store_remove(self.pointer, iter.unwrap_pointer())) }
+        let has_next = unsafe { to_bool(ffi::gtk_list_store_remove(self.pointer, iter.unwrap_pointer())) };
+
+        if has_next {
+            iter.update_deletion_count();
+        }
+        has_next
     }
 
     pub fn insert(&self, iter: &mut TreeIter, position: i32) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_LIST_STORE(self.pointer));
         unsafe { ffi::gtk_list_store_insert(self.pointer, iter.unwrap_pointer(), position) }
     }
 
     pub fn insert_before(&self, iter: &mut TreeIter, sibling: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_LIST_STORE(self.pointer));
         unsafe { ffi::gtk_list_store_insert_before(self.pointer, iter.unwrap_pointer(),
                                                    if sibling.is_none() { ::std::ptr::null_mut()} else { sibling.unwrap().unwrap_pointer() }) }
     }
 
     pub fn insert_after(&self, iter: &mut TreeIter, sibling: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_LIST_STORE(self.pointer));
         unsafe { ffi::gtk_list_store_insert_after(self.pointer, iter.unwrap_pointer(),
                                                   if sibling.is_none() { ::std::ptr::null_mut()} else { sibling.unwrap().unwrap_pointer() }) }
     }
 
     pub fn prepend(&self, iter: &mut TreeIter) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_LIST_STORE(self.pointer));
         unsafe { ffi::gtk_list_store_prepend(self.pointer, iter.unwrap_pointer()) }
     }
 
     pub fn append(&self, iter: &mut TreeIter) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_LIST_STORE(self.pointer));
         unsafe { ffi::gtk_list_store_append(self.pointer, iter.unwrap_pointer()) }
     }
 
@@ -0,0 +1,155 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkMenu — A menu widget
+
+use std::ptr;
+use glib::translate::{from_glib_none, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use ffi;
+use cast::GTK_MENU;
+use {AccelGroup, FFIWidget, MenuModel, Widget};
+
+/// GtkMenu — A menu widget
+///
+/// A menu is shown as a popup, either from a MenuItem as its submenu or explicitly, e.g. as a
+/// context menu from a `button-press-event` handler with the button and time of the event.
+struct_Widget!(Menu);
+
+impl Menu {
+    pub fn new() -> Option<Menu> {
+        let tmp_pointer = unsafe { ffi::gtk_menu_new() };
+        check_pointer!(tmp_pointer, Menu)
+    }
+
+    /// Creates a menu showing `model`, its actions are looked up from the widget the menu is
+    /// attached to.
+    pub fn new_from_model(model: &MenuModel) -> Option<Menu> {
+        let tmp_pointer = unsafe { ffi::gtk_menu_new_from_model(model.unwrap_pointer()) };
+        check_pointer!(tmp_pointer, Menu)
+    }
+
+    /// Pops the menu up at the pointer. `button` is the mouse button which triggered it, 0 if
+    /// none did, and `activate_time` the time of the triggering event.
+    pub fn popup_at_pointer(&self, button: u32, activate_time: u32) {
+        unsafe {
+            ffi::gtk_menu_popup(GTK_MENU(self.pointer), ptr::null_mut(), ptr::null_mut(), ptr::null_mut(),
+                                ptr::null_mut(), button, activate_time)
+        }
+    }
+
+    /// Pops the menu up below `widget`, which must be realized.
+    pub fn popup_at_widget<T: ::WidgetTrait>(&self, widget: &T, button: u32, activate_time: u32) {
+        unsafe { ffi::glue_menu_popup_at_widget(GTK_MENU(self.pointer), widget.unwrap_widget(), button, activate_time) }
+    }
+
+    pub fn popdown(&self) {
+        unsafe { ffi::gtk_menu_popdown(GTK_MENU(self.pointer)) }
+    }
+
+    /// Moves the menu back to its position, e.g. after its size changed while shown.
+    pub fn reposition(&self) {
+        unsafe { ffi::gtk_menu_reposition(GTK_MENU(self.pointer)) }
+    }
+
+    pub fn reorder_child<T: ::WidgetTrait>(&self, child: &T, position: i32) {
+        unsafe { ffi::gtk_menu_reorder_child(GTK_MENU(self.pointer), child.unwrap_widget(), position) }
+    }
+
+    /// Adds `child` in a grid spanning from the columns `left_attach` to `right_attach` and the
+    /// rows `top_attach` to `bottom_attach`, both excluded.
+    pub fn attach<T: ::WidgetTrait>(&self, child: &T, left_attach: u32, right_attach: u32, top_attach: u32,
+                                    bottom_attach: u32) {
+        unsafe {
+            ffi::gtk_menu_attach(GTK_MENU(self.pointer), child.unwrap_widget(), left_attach, right_attach,
+                                 top_attach, bottom_attach)
+        }
+    }
+
+    /// Attaches the menu to `attach_widget`, it then follows its screen and its action groups
+    /// and is destroyed with it.
+    pub fn attach_to_widget<T: ::WidgetTrait>(&self, attach_widget: &T) {
+        unsafe { ffi::gtk_menu_attach_to_widget(GTK_MENU(self.pointer), attach_widget.unwrap_widget(), ptr::null_mut()) }
+    }
+
+    pub fn detach(&self) {
+        unsafe { ffi::gtk_menu_detach(GTK_MENU(self.pointer)) }
+    }
+
+    pub fn get_attach_widget(&self) -> Option<Widget> {
+        let tmp = unsafe { ffi::gtk_menu_get_attach_widget(GTK_MENU(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp))
+        }
+    }
+
+    /// Sets the group the accelerators of the items are added to, it should be the one of the
+    /// window.
+    pub fn set_accel_group(&self, accel_group: Option<&AccelGroup>) {
+        unsafe {
+            ffi::gtk_menu_set_accel_group(GTK_MENU(self.pointer),
+                                          accel_group.map_or(ptr::null_mut(), |a| a.unwrap_pointer()))
+        }
+    }
+
+    pub fn get_accel_group(&self) -> Option<AccelGroup> {
+        let tmp = unsafe { ffi::gtk_menu_get_accel_group(GTK_MENU(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(AccelGroup::wrap_pointer(tmp))
+        }
+    }
+
+    /// Sets the prefix of the accel paths of the items, e.g. "<MyApp>/File", an item labelled
+    /// "Open" then gets "<MyApp>/File/Open". An accel group must be set.
+    pub fn set_accel_path(&self, accel_path: Option<&str>) {
+        unsafe { ffi::gtk_menu_set_accel_path(GTK_MENU(self.pointer), accel_path.to_glib_none().0) }
+    }
+
+    pub fn get_accel_path(&self) -> Option<String> {
+        unsafe { from_glib_none(ffi::gtk_menu_get_accel_path(GTK_MENU(self.pointer))) }
+    }
+
+    /// Selects the item at `index` as the one to be under the pointer when the menu pops up.
+    pub fn set_active(&self, index: u32) {
+        unsafe { ffi::gtk_menu_set_active(GTK_MENU(self.pointer), index) }
+    }
+
+    pub fn get_active(&self) -> Option<Widget> {
+        let tmp = unsafe { ffi::gtk_menu_get_active(GTK_MENU(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(FFIWidget::wrap_widget(tmp))
+        }
+    }
+
+    pub fn set_monitor(&self, monitor_num: i32) {
+        unsafe { ffi::gtk_menu_set_monitor(GTK_MENU(self.pointer), monitor_num) }
+    }
+
+    pub fn get_monitor(&self) -> i32 {
+        unsafe { ffi::gtk_menu_get_monitor(GTK_MENU(self.pointer)) }
+    }
+
+    pub fn set_reserve_toggle_size(&self, reserve_toggle_size: bool) {
+        unsafe { ffi::gtk_menu_set_reserve_toggle_size(GTK_MENU(self.pointer), to_gboolean(reserve_toggle_size)) }
+    }
+
+    pub fn get_reserve_toggle_size(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_menu_get_reserve_toggle_size(GTK_MENU(self.pointer))) }
+    }
+}
+
+impl_drop!(Menu);
+impl_TraitWidget!(Menu);
+
+impl ::ContainerTrait for Menu {}
+impl ::MenuShellTrait for Menu {}
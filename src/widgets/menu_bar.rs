@@ -0,0 +1,30 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkMenuBar — A subclass of GtkMenuShell which holds GtkMenuItem widgets
+
+use ffi;
+use MenuModel;
+
+/// GtkMenuBar — A subclass of GtkMenuShell which holds GtkMenuItem widgets
+struct_Widget!(MenuBar);
+
+impl MenuBar {
+    pub fn new() -> Option<MenuBar> {
+        let tmp_pointer = unsafe { ffi::gtk_menu_bar_new() };
+        check_pointer!(tmp_pointer, MenuBar)
+    }
+
+    /// Creates a menubar showing `model`, its actions are looked up from the widget hierarchy.
+    pub fn new_from_model(model: &MenuModel) -> Option<MenuBar> {
+        let tmp_pointer = unsafe { ffi::gtk_menu_bar_new_from_model(model.unwrap_pointer()) };
+        check_pointer!(tmp_pointer, MenuBar)
+    }
+}
+
+impl_drop!(MenuBar);
+impl_TraitWidget!(MenuBar);
+
+impl ::ContainerTrait for MenuBar {}
+impl ::MenuShellTrait for MenuBar {}
@@ -6,7 +6,7 @@
 
 use ffi;
 use cast::GTK_MENUBUTTON;
-use ArrowType;
+use {ArrowType, MenuModel};
 
 /// MenuButton — A widget that shows a menu when clicked on
 struct_Widget!(MenuButton);
@@ -23,6 +23,25 @@ impl MenuButton {
         }
     }
 
+    /// Builds the popup from `menu_model`, a popover instead of a menu if `use-popover` is set.
+    /// None removes it.
+    pub fn set_menu_model(&self, menu_model: Option<&MenuModel>) {
+        unsafe {
+            ffi::gtk_menu_button_set_menu_model(GTK_MENUBUTTON(self.pointer),
+                                                menu_model.map_or(::std::ptr::null_mut(), |m| m.unwrap_pointer()))
+        }
+    }
+
+    pub fn get_menu_model(&self) -> Option<MenuModel> {
+        let tmp = unsafe { ffi::gtk_menu_button_get_menu_model(GTK_MENUBUTTON(self.pointer)) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MenuModel::wrap_pointer(tmp))
+        }
+    }
+
     pub fn set_direction(&self, direction: ArrowType) -> () {
         unsafe {
             ffi::gtk_menu_button_set_direction(GTK_MENUBUTTON(self.pointer), direction);
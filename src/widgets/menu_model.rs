@@ -0,0 +1,90 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GMenuModel — An abstract class representing the contents of a menu
+
+use glib::translate::ToGlibPtr;
+use glib::to_bool;
+use ffi;
+use Variant;
+
+/// GMenuModel — An abstract class representing the contents of a menu
+///
+/// A menu is a list of items, each one having attributes such as "label", "action" and "target"
+/// and links to other menus, "section" and "submenu". Menus are built with `GMenu` or loaded
+/// from a `Builder` with `get_object`.
+pub struct MenuModel {
+    pointer: *mut ffi::C_GMenuModel
+}
+
+impl MenuModel {
+    /// Returns false if the menu never changes, e.g. once a GMenu is frozen.
+    pub fn is_mutable(&self) -> bool {
+        unsafe { to_bool(ffi::g_menu_model_is_mutable(self.pointer)) }
+    }
+
+    pub fn get_n_items(&self) -> i32 {
+        unsafe { ffi::g_menu_model_get_n_items(self.pointer) }
+    }
+
+    /// Returns the `attribute` of the item at `item_index`, None if it hasn't got it or if it
+    /// hasn't got the type `expected_type`, a type string. None too if `expected_type` isn't
+    /// a valid type string.
+    pub fn get_item_attribute_value(&self, item_index: i32, attribute: &str,
+                                    expected_type: Option<&str>) -> Option<Variant> {
+        if !expected_type.map_or(true, Variant::is_valid_type_string) {
+            return None;
+        }
+        let tmp = unsafe {
+            ffi::g_menu_model_get_item_attribute_value(self.pointer, item_index, attribute.to_glib_none().0,
+                                                       expected_type.to_glib_none().0 as *const ffi::C_GVariantType)
+        };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Variant::wrap_pointer(tmp))
+        }
+    }
+
+    /// Returns the menu linked as `link`, e.g. "section" or "submenu", from the item at `item_index`.
+    pub fn get_item_link(&self, item_index: i32, link: &str) -> Option<MenuModel> {
+        let tmp = unsafe { ffi::g_menu_model_get_item_link(self.pointer, item_index, link.to_glib_none().0) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(MenuModel {
+                pointer: tmp
+            })
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GMenuModel {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GMenuModel) -> MenuModel {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        MenuModel {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for MenuModel {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for MenuModel {
+    fn clone(&self) -> MenuModel {
+        MenuModel::wrap_pointer(self.pointer)
+    }
+}
+
+impl_TraitObject!(MenuModel, C_GMenuModel);
@@ -3,6 +3,7 @@
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
 pub use self::widget::Widget;
+pub use self::container::Container;
 pub use self::builder::Builder;
 pub use self::window::Window;
 pub use self::label::Label;
@@ -52,11 +53,13 @@ pub use self::separator_tool_item::SeparatorToolItem;
 pub use self::tool_button::ToolButton;
 pub use self::toggle_tool_button::ToggleToolButton;
 pub use self::menu_tool_button::MenuToolButton;
-pub use self::tree_path::TreePath;
-pub use self::tree_iter::TreeIter;
+pub use self::tree_path::{TreePath, ParseTreePathError};
+pub use self::tree_iter::{TreeIter, TreeIterError};
+pub use self::tree_row_reference::TreeRowReference;
 pub use self::tree_model::TreeModel;
 pub use self::list_store::ListStore;
 pub use self::tree_store::TreeStore;
+pub use self::lazy_tree::LazyTree;
 pub use self::dialog::Dialog;
 pub use self::about_dialog::AboutDialog;
 pub use self::message_dialog::MessageDialog;
@@ -101,9 +104,26 @@ pub use self::scrolled_window::ScrolledWindow;
 pub use self::radio_button::RadioButton;
 pub use self::tree_view::TreeView;
 pub use self::tree_view_column::TreeViewColumn;
+pub use self::tree_view_layout::{TreeViewLayout, ColumnState};
+pub use self::window_state_tracker::WindowStateTracker;
 pub use self::menu_item::MenuItem;
 pub use self::separator_menu_item::SeparatorMenuItem;
 pub use self::check_menu_item::CheckMenuItem;
+pub use self::radio_menu_item::RadioMenuItem;
+pub use self::image_menu_item::ImageMenuItem;
+pub use self::menu::Menu;
+pub use self::menu_bar::MenuBar;
+pub use self::accel_group::{
+    AccelGroup,
+    accel_groups_activate,
+    accelerator_parse,
+    accelerator_name,
+    accelerator_get_label,
+    accelerator_valid,
+    accelerator_get_default_mod_mask
+};
+pub use self::accel_map::AccelMap;
+pub use self::clipboard::Clipboard;
 pub use self::scrollbar::ScrollBar;
 pub use self::viewport::Viewport;
 pub use self::status_bar::StatusBar;
@@ -118,6 +138,7 @@ pub use self::combo_box::ComboBox;
 #[cfg(feature = "gtk_3_12")]
 pub use self::popover::Popover;
 pub use self::combo_box_text::ComboBoxText;
+pub use self::typed_combo_box::TypedComboBox;
 //pub use self::gtype::g_type;
 pub use self::text_mark::TextMark;
 pub use self::text_tag::TextTag;
@@ -136,8 +157,17 @@ pub use self::font_chooser_widget::FontChooserWidget;
 #[cfg(target_os = "linux")]
 pub use self::socket::Socket;
 pub use self::event_box::EventBox;
+pub use self::offscreen_window::OffscreenWindow;
+pub use self::menu_model::MenuModel;
+pub use self::gmenu::GMenu;
+pub use self::gmenu_item::GMenuItem;
+pub use self::application::Application;
+pub use self::application_window::ApplicationWindow;
+pub use self::simple_action::SimpleAction;
+pub use self::simple_action_group::SimpleActionGroup;
 
 mod widget;
+mod container;
 mod builder;
 mod window;
 mod label;
@@ -231,14 +261,25 @@ mod scrolled_window;
 mod radio_button;
 mod tree_view;
 mod tree_view_column;
+mod tree_view_layout;
+mod window_state_tracker;
 mod tree_path;
 mod tree_iter;
+mod tree_row_reference;
 mod tree_model;
 mod list_store;
 mod tree_store;
+mod lazy_tree;
 mod menu_item;
 mod separator_menu_item;
 mod check_menu_item;
+mod radio_menu_item;
+mod image_menu_item;
+mod menu;
+mod menu_bar;
+mod accel_group;
+mod accel_map;
+mod clipboard;
 mod scrollbar;
 mod viewport;
 mod status_bar;
@@ -253,6 +294,7 @@ mod combo_box;
 #[cfg(feature = "gtk_3_12")]
 mod popover;
 mod combo_box_text;
+mod typed_combo_box;
 //mod gtype;
 mod text_mark;
 mod text_tag;
@@ -271,3 +313,11 @@ mod font_chooser_widget;
 #[cfg(target_os = "linux")]
 mod socket;
 mod event_box;
+mod offscreen_window;
+mod menu_model;
+mod gmenu;
+mod gmenu_item;
+mod application;
+mod application_window;
+mod simple_action;
+mod simple_action_group;
@@ -351,6 +351,6 @@ impl NoteBook {
 }
 
 impl_drop!(NoteBook);
-impl_TraitWidget!(NoteBook);
+impl_TraitWidget!(NoteBook, "GtkNotebook");
 
 impl ::ContainerTrait for NoteBook {}
@@ -0,0 +1,27 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkOffscreenWindow — A toplevel to manage offscreen rendering of child widgets
+
+use ffi;
+
+/// GtkOffscreenWindow — A toplevel to manage offscreen rendering of child widgets
+///
+/// The window is never shown on screen, its children are drawn even without a display
+/// compositor, see `rendering::render_offscreen`.
+struct_Widget!(OffscreenWindow);
+
+impl OffscreenWindow {
+    pub fn new() -> Option<OffscreenWindow> {
+        let tmp_pointer = unsafe { ffi::gtk_offscreen_window_new() };
+        check_pointer!(tmp_pointer, OffscreenWindow)
+    }
+}
+
+impl_drop!(OffscreenWindow);
+impl_TraitWidget!(OffscreenWindow);
+
+impl ::ContainerTrait for OffscreenWindow {}
+impl ::BinTrait for OffscreenWindow {}
+impl ::WindowTrait for OffscreenWindow {}
@@ -6,7 +6,9 @@
 
 use ffi;
 use cast::GTK_POPOVER;
+use glib::translate::ToGlibPtr;
 use glib::{to_bool, to_gboolean};
+use MenuModel;
 // use std::string;
 
 struct_Widget!(Popover);
@@ -17,6 +19,22 @@ impl Popover {
         check_pointer!(tmp_pointer, Popover)
     }
 
+    /// Creates a popover showing `model`, its actions are looked up from `relative_to`.
+    pub fn new_from_model<T: ::WidgetTrait>(relative_to: &T, model: &MenuModel) -> Option<Popover> {
+        let tmp_pointer = unsafe { ffi::gtk_popover_new_from_model(relative_to.unwrap_widget(), model.unwrap_pointer()) };
+        check_pointer!(tmp_pointer, Popover)
+    }
+
+    /// Replaces the content of the popover with `model`, None empties it. The action names of
+    /// the items are prefixed with `action_namespace`, e.g. "win" for "copy" to be "win.copy".
+    pub fn bind_model(&self, model: Option<&MenuModel>, action_namespace: Option<&str>) {
+        unsafe {
+            ffi::gtk_popover_bind_model(GTK_POPOVER(self.pointer),
+                                        model.map_or(::std::ptr::null_mut(), |m| m.unwrap_pointer()),
+                                        action_namespace.to_glib_none().0)
+        }
+    }
+
     pub fn set_relative_to<T: ::WidgetTrait>(&self, relative_to: &T) {
         unsafe { ffi::gtk_popover_set_relative_to(GTK_POPOVER(self.pointer), relative_to.unwrap_widget()) }
     }
@@ -0,0 +1,64 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GtkRadioMenuItem — A choice from multiple check menu items
+
+use std::ptr;
+use glib::translate::ToGlibPtr;
+use ffi;
+use cast::GTK_RADIO_MENU_ITEM;
+
+/// GtkRadioMenuItem — A choice from multiple check menu items
+///
+/// Only one item of a group is active at a time, the `*_from_widget` constructors add the new
+/// item to the group of an existing one.
+struct_Widget!(RadioMenuItem);
+
+impl RadioMenuItem {
+    pub fn new() -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_radio_menu_item_new(ptr::null_mut()) };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+
+    pub fn new_with_label(label: &str) -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_radio_menu_item_new_with_label(ptr::null_mut(), label.to_glib_none().0) };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+
+    pub fn new_with_mnemonic(mnemonic: &str) -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_radio_menu_item_new_with_mnemonic(ptr::null_mut(), mnemonic.to_glib_none().0)
+        };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+
+    pub fn new_from_widget(group: &RadioMenuItem) -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe { ffi::gtk_radio_menu_item_new_from_widget(GTK_RADIO_MENU_ITEM(group.pointer)) };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+
+    pub fn new_with_label_from_widget(group: &RadioMenuItem, label: &str) -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_radio_menu_item_new_with_label_from_widget(GTK_RADIO_MENU_ITEM(group.pointer),
+                                                                label.to_glib_none().0)
+        };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+
+    pub fn new_with_mnemonic_from_widget(group: &RadioMenuItem, mnemonic: &str) -> Option<RadioMenuItem> {
+        let tmp_pointer = unsafe {
+            ffi::gtk_radio_menu_item_new_with_mnemonic_from_widget(GTK_RADIO_MENU_ITEM(group.pointer),
+                                                                   mnemonic.to_glib_none().0)
+        };
+        check_pointer!(tmp_pointer, RadioMenuItem)
+    }
+}
+
+impl_drop!(RadioMenuItem);
+impl_TraitWidget!(RadioMenuItem);
+
+impl ::ContainerTrait for RadioMenuItem {}
+impl ::BinTrait for RadioMenuItem {}
+impl ::MenuItemTrait for RadioMenuItem {}
+impl ::CheckMenuItemTrait for RadioMenuItem {}
@@ -17,7 +17,7 @@ impl ScrollBar {
 }
 
 impl_drop!(ScrollBar);
-impl_TraitWidget!(ScrollBar);
+impl_TraitWidget!(ScrollBar, "GtkScrollbar");
 
 impl ::RangeTrait for ScrollBar {}
 impl ::OrientableTrait for ScrollBar {}
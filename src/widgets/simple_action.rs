@@ -0,0 +1,177 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GSimpleAction — A simple GAction implementation
+
+use std::ptr;
+use glib::translate::{from_glib_full, from_glib_none, ToGlibPtr};
+use glib::{to_bool, to_gboolean};
+use ffi;
+use Variant;
+
+/// GSimpleAction — A simple GAction implementation
+///
+/// An action is activated through a group it has been added to, e.g. "app.quit" for an action
+/// named "quit" of an Application or "win.zoom" for one of an ApplicationWindow. Actions with a
+/// parameter type get a parameter of this type, e.g. "win.zoom(2)" for "i", and stateful actions
+/// drive the check or radio state of the buttons and menu items pointing at them.
+pub struct SimpleAction {
+    pointer: *mut ffi::C_GSimpleAction
+}
+
+impl SimpleAction {
+    /// `parameter_type` is the type string of the parameter, None if the action has none.
+    /// Returns None if it isn't a valid type string.
+    pub fn new(name: &str, parameter_type: Option<&str>) -> Option<SimpleAction> {
+        if !parameter_type.map_or(true, Variant::is_valid_type_string) {
+            return None;
+        }
+        let tmp_pointer = unsafe {
+            ffi::g_simple_action_new(name.to_glib_none().0,
+                                     parameter_type.to_glib_none().0 as *const ffi::C_GVariantType)
+        };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(SimpleAction {
+                pointer: tmp_pointer
+            })
+        }
+    }
+
+    /// Creates an action whose state has the type of `state`. Without a `change-state` handler,
+    /// the state changes to any value requested, a boolean state is toggled on activation.
+    /// Returns None if `parameter_type` isn't a valid type string.
+    pub fn new_stateful(name: &str, parameter_type: Option<&str>, state: &Variant) -> Option<SimpleAction> {
+        if !parameter_type.map_or(true, Variant::is_valid_type_string) {
+            return None;
+        }
+        let tmp_pointer = unsafe {
+            ffi::g_simple_action_new_stateful(name.to_glib_none().0,
+                                              parameter_type.to_glib_none().0 as *const ffi::C_GVariantType,
+                                              state.unwrap_pointer())
+        };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(SimpleAction {
+                pointer: tmp_pointer
+            })
+        }
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        unsafe { from_glib_none(ffi::g_action_get_name(self.unwrap_action())) }
+    }
+
+    /// Returns the type string of the parameter, None if the action takes no parameter.
+    pub fn get_parameter_type(&self) -> Option<String> {
+        unsafe {
+            let tmp = ffi::g_action_get_parameter_type(self.unwrap_action());
+
+            if tmp.is_null() {
+                None
+            } else {
+                from_glib_full(ffi::g_variant_type_dup_string(tmp))
+            }
+        }
+    }
+
+    /// Returns the type string of the state, None if the action is stateless.
+    pub fn get_state_type(&self) -> Option<String> {
+        unsafe {
+            let tmp = ffi::g_action_get_state_type(self.unwrap_action());
+
+            if tmp.is_null() {
+                None
+            } else {
+                from_glib_full(ffi::g_variant_type_dup_string(tmp))
+            }
+        }
+    }
+
+    /// A disabled action can't be activated, the widgets pointing at it are insensitive.
+    pub fn set_enabled(&self, enabled: bool) {
+        unsafe { ffi::g_simple_action_set_enabled(self.pointer, to_gboolean(enabled)) }
+    }
+
+    pub fn get_enabled(&self) -> bool {
+        unsafe { to_bool(ffi::g_action_get_enabled(self.unwrap_action())) }
+    }
+
+    /// Sets the state without emitting `change-state`, to be called from its handler.
+    pub fn set_state(&self, value: &Variant) {
+        unsafe { ffi::g_simple_action_set_state(self.pointer, value.unwrap_pointer()) }
+    }
+
+    /// Returns the state, None if the action is stateless.
+    pub fn get_state(&self) -> Option<Variant> {
+        let tmp = unsafe { ffi::g_action_get_state(self.unwrap_action()) };
+
+        if tmp.is_null() {
+            None
+        } else {
+            Some(Variant::wrap_pointer(tmp))
+        }
+    }
+
+    /// Requests a change of the state, `value` must have the state type.
+    pub fn change_state(&self, value: &Variant) {
+        unsafe { ffi::g_action_change_state(self.unwrap_action(), value.unwrap_pointer()) }
+    }
+
+    /// `parameter` must have the parameter type, or be None if the action takes no parameter.
+    pub fn activate(&self, parameter: Option<&Variant>) {
+        unsafe {
+            ffi::g_action_activate(self.unwrap_action(), parameter.map_or(ptr::null_mut(), |p| p.unwrap_pointer()))
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_action(&self) -> *mut ffi::C_GAction {
+        self.pointer as *mut ffi::C_GAction
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GSimpleAction {
+        self.pointer
+    }
+
+    /// Returns None if `action` is null or another implementation of GAction.
+    #[doc(hidden)]
+    pub fn wrap_action(action: *mut ffi::C_GAction) -> Option<SimpleAction> {
+        let is_simple = !action.is_null() && unsafe {
+            to_bool(ffi::g_type_is_a(ffi::glue_object_type(action as *mut ffi::C_GObject),
+                                     ffi::g_simple_action_get_type()))
+        };
+
+        if is_simple {
+            Some(SimpleAction::wrap_pointer(action as *mut ffi::C_GSimpleAction))
+        } else {
+            None
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GSimpleAction) -> SimpleAction {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        SimpleAction {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for SimpleAction {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for SimpleAction {
+    fn clone(&self) -> SimpleAction {
+        SimpleAction::wrap_pointer(self.pointer)
+    }
+}
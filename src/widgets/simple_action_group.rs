@@ -0,0 +1,109 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! GSimpleActionGroup — A simple GActionGroup implementation
+
+use std::ptr;
+use glib::translate::{FromGlibPtrContainer, ToGlibPtr};
+use glib::to_bool;
+use ffi;
+use libc::c_char;
+use {SimpleAction, Variant};
+
+/// GSimpleActionGroup — A simple GActionGroup implementation
+///
+/// The group is inserted in a widget with `WidgetTrait::insert_action_group`, its actions are
+/// then available to the widget and its descendants under the prefix the group is inserted with.
+pub struct SimpleActionGroup {
+    pointer: *mut ffi::C_GSimpleActionGroup
+}
+
+impl SimpleActionGroup {
+    pub fn new() -> Option<SimpleActionGroup> {
+        let tmp_pointer = unsafe { ffi::g_simple_action_group_new() };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(SimpleActionGroup {
+                pointer: tmp_pointer
+            })
+        }
+    }
+
+    /// Adds `action`, replacing the action of the same name.
+    pub fn add_action(&self, action: &SimpleAction) {
+        unsafe { ffi::g_action_map_add_action(self.unwrap_action_map(), action.unwrap_action()) }
+    }
+
+    pub fn remove_action(&self, action_name: &str) {
+        unsafe { ffi::g_action_map_remove_action(self.unwrap_action_map(), action_name.to_glib_none().0) }
+    }
+
+    /// Returns None if there is no action named `action_name` or if it isn't a SimpleAction.
+    pub fn lookup_action(&self, action_name: &str) -> Option<SimpleAction> {
+        let tmp = unsafe { ffi::g_action_map_lookup_action(self.unwrap_action_map(), action_name.to_glib_none().0) };
+
+        SimpleAction::wrap_action(tmp)
+    }
+
+    pub fn has_action(&self, action_name: &str) -> bool {
+        unsafe { to_bool(ffi::g_action_group_has_action(self.unwrap_action_group(), action_name.to_glib_none().0)) }
+    }
+
+    pub fn list_actions(&self) -> Vec<String> {
+        unsafe {
+            FromGlibPtrContainer::from_glib_full(
+                ffi::g_action_group_list_actions(self.unwrap_action_group()) as *const *const c_char)
+        }
+    }
+
+    pub fn activate_action(&self, action_name: &str, parameter: Option<&Variant>) {
+        unsafe {
+            ffi::g_action_group_activate_action(self.unwrap_action_group(), action_name.to_glib_none().0,
+                                                parameter.map_or(ptr::null_mut(), |p| p.unwrap_pointer()))
+        }
+    }
+
+    pub fn change_action_state(&self, action_name: &str, value: &Variant) {
+        unsafe {
+            ffi::g_action_group_change_action_state(self.unwrap_action_group(), action_name.to_glib_none().0,
+                                                    value.unwrap_pointer())
+        }
+    }
+
+    fn unwrap_action_map(&self) -> *mut ffi::C_GActionMap {
+        self.pointer as *mut ffi::C_GActionMap
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_action_group(&self) -> *mut ffi::C_GActionGroup {
+        self.pointer as *mut ffi::C_GActionGroup
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GSimpleActionGroup {
+        self.pointer
+    }
+
+    #[doc(hidden)]
+    pub fn wrap_pointer(pointer: *mut ffi::C_GSimpleActionGroup) -> SimpleActionGroup {
+        unsafe { ::glib_ffi::g_object_ref(pointer as *mut _); }
+        SimpleActionGroup {
+            pointer: pointer
+        }
+    }
+}
+
+impl Drop for SimpleActionGroup {
+    fn drop(&mut self) {
+        unsafe { ::glib_ffi::g_object_unref(self.pointer as *mut _) }
+    }
+}
+
+impl Clone for SimpleActionGroup {
+    fn clone(&self) -> SimpleActionGroup {
+        SimpleActionGroup::wrap_pointer(self.pointer)
+    }
+}
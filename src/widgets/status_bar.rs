@@ -52,7 +52,7 @@ impl StatusBar {
 }
 
 impl_drop!(StatusBar);
-impl_TraitWidget!(StatusBar);
+impl_TraitWidget!(StatusBar, "GtkStatusbar");
 
 impl ::ContainerTrait for StatusBar {}
 impl ::BoxTrait for StatusBar {}
@@ -4,10 +4,43 @@
 
 use ffi;
 use std;
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
 
+/// The reasons a TreeIter can't be used with a TreeModel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeIterError {
+    /// The iter was filled by another model.
+    WrongModel,
+    /// The row of the iter has been removed or the model has changed since the iter was filled.
+    Stale
+}
+
+impl fmt::Display for TreeIterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl Error for TreeIterError {
+    fn description(&self) -> &str {
+        match *self {
+            TreeIterError::WrongModel => "the iter belongs to another model",
+            TreeIterError::Stale => "the iter is no longer valid"
+        }
+    }
+}
+
+/// TreeIter — Points to a row of a TreeModel
+///
+/// An iter is bound to the model which filled it, see `TreeModel::check_iter`.
 pub struct TreeIter {
     data: ffi::C_GtkTreeIter,
     pointer: *mut ffi::C_GtkTreeIter,
+    model: *mut ffi::C_GtkTreeModel,
+    /// The rows deleted from `model` when the iter was filled.
+    deletions: Cell<u64>,
     is_owned: bool,
     is_true_pointer: bool
 }
@@ -22,6 +55,8 @@ impl TreeIter {
                 user_data3: std::ptr::null_mut()
             },
             pointer: ::std::ptr::null_mut(),
+            model: ::std::ptr::null_mut(),
+            deletions: Cell::new(0),
             is_owned: false,
             is_true_pointer: false
         };
@@ -40,6 +75,8 @@ impl TreeIter {
                 Some(TreeIter {
                     data: std::mem::uninitialized(),
                     pointer: tmp_pointer,
+                    model: self.model,
+                    deletions: self.deletions.clone(),
                     is_owned: true,
                     is_true_pointer: true
                 })
@@ -47,6 +84,33 @@ impl TreeIter {
         }
     }
 
+    pub fn get_stamp(&self) -> i32 {
+        unsafe { (*self.unwrap_pointer()).stamp }
+    }
+
+    #[doc(hidden)]
+    pub fn get_model_pointer(&self) -> *mut ffi::C_GtkTreeModel {
+        self.model
+    }
+
+    #[doc(hidden)]
+    pub fn set_model_pointer(&mut self, model: *mut ffi::C_GtkTreeModel) {
+        self.model = model;
+        self.update_deletion_count();
+    }
+
+    #[doc(hidden)]
+    pub fn get_deletion_count(&self) -> u64 {
+        self.deletions.get()
+    }
+
+    /// To be called once the row of the iter is known to still exist, e.g. after the iter has
+    /// been moved to the next row by a removal.
+    #[doc(hidden)]
+    pub fn update_deletion_count(&self) {
+        self.deletions.set(super::tree_model::get_deletion_count(self.model));
+    }
+
     #[doc(hidden)]
     pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTreeIter {
         if self.is_true_pointer {
@@ -62,6 +126,8 @@ impl TreeIter {
             TreeIter {
                 data: std::mem::uninitialized(),
                 pointer: c_treeiter,
+                model: ::std::ptr::null_mut(),
+                deletions: Cell::new(0),
                 is_owned: false,
                 is_true_pointer: true
             }
@@ -2,11 +2,15 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::cell::Cell;
+use std::mem::transmute;
 use glib::{Value, Type};
 use glib::translate::{from_glib_full, ToGlibPtr, from_glib};
 use ffi;
-use {TreeIter, TreePath};
-use libc::c_void;
+use cast::{GTK_IS_LIST_STORE, GTK_IS_TREE_STORE, GTK_LIST_STORE_FROM_TREE_MODEL, GTK_TREE_STORE_FROM_TREE_MODEL};
+use {TreeIter, TreeIterError, TreePath};
+use libc::{c_char, c_void};
+use glib_ffi;
 use glib;
 
 pub struct TreeModel {
@@ -27,6 +31,7 @@ impl TreeModel {
     }
 
     pub fn get_iter(&self, iter: &mut TreeIter, path: &TreePath) -> bool {
+        iter.set_model_pointer(self.pointer);
         match unsafe { ffi::gtk_tree_model_get_iter(self.pointer, iter.unwrap_pointer(), path.unwrap_pointer()) } {
             0 => false,
             _ => true
@@ -34,6 +39,7 @@ impl TreeModel {
     }
 
     pub fn get_iter_from_string(&self, iter: &mut TreeIter, path_string: &str) -> bool {
+        iter.set_model_pointer(self.pointer);
         match unsafe { ffi::gtk_tree_model_get_iter_from_string(self.pointer, iter.unwrap_pointer(), path_string.to_glib_none().0) } {
                 0 => false,
                 _ => true
@@ -41,71 +47,133 @@ impl TreeModel {
     }
 
     pub fn get_iter_first(&self, iter: &mut TreeIter) -> bool {
+        iter.set_model_pointer(self.pointer);
         match unsafe { ffi::gtk_tree_model_get_iter_first(self.pointer, iter.unwrap_pointer()) } {
             0 => false,
             _ => true
         }
     }
 
-    pub fn get_path(&self, iter: &TreeIter) -> Option<TreePath> {
+    /// Checks that `iter` can be used with this model: it must have been filled by this model,
+    /// its stamp must match the current one and its row mustn't have been deleted. The methods
+    /// taking an iter do this check, which only looks the row up in a ListStore or a TreeStore
+    /// when rows have been deleted since the iter was filled. An iter got from a raw pointer,
+    /// which has no model, only gets the stamp check.
+    pub fn check_iter(&self, iter: &TreeIter) -> Result<(), TreeIterError> {
+        let model = iter.get_model_pointer();
+
+        if !model.is_null() && model != self.pointer {
+            return Err(TreeIterError::WrongModel);
+        }
+
+        let mut first = TreeIter::new();
+        if !self.get_iter_first(&mut first) || first.get_stamp() != iter.get_stamp() {
+            return Err(TreeIterError::Stale);
+        }
+        if model.is_null() || iter.get_deletion_count() == get_deletion_count(self.pointer) {
+            return Ok(());
+        }
+
+        // The stores keep their stamp when a row is deleted, the other models have changed theirs
+        let valid = unsafe {
+            if GTK_IS_LIST_STORE(self.pointer) {
+                glib::to_bool(ffi::gtk_list_store_iter_is_valid(GTK_LIST_STORE_FROM_TREE_MODEL(self.pointer),
+                                                                iter.unwrap_pointer()))
+            } else if GTK_IS_TREE_STORE(self.pointer) {
+                glib::to_bool(ffi::gtk_tree_store_iter_is_valid(GTK_TREE_STORE_FROM_TREE_MODEL(self.pointer),
+                                                                iter.unwrap_pointer()))
+            } else {
+                false
+            }
+        };
+        if valid {
+            iter.update_deletion_count();
+            Ok(())
+        } else {
+            Err(TreeIterError::Stale)
+        }
+    }
+
+    pub fn get_path(&self, iter: &TreeIter) -> Result<TreePath, TreeIterError> {
+        try!(self.check_iter(iter));
+
         let tmp_pointer = unsafe { ffi::gtk_tree_model_get_path(self.pointer, iter.unwrap_pointer()) };
 
         if tmp_pointer.is_null() {
-            None
+            Err(TreeIterError::Stale)
         } else {
-            Some(TreePath::wrap_pointer(tmp_pointer))
+            Ok(TreePath::wrap_pointer(tmp_pointer))
         }
     }
 
-    pub fn get_value(&self, iter: &TreeIter, column: i32) -> Value {
+    pub fn get_value(&self, iter: &TreeIter, column: i32) -> Result<Value, TreeIterError> {
+        try!(self.check_iter(iter));
+
         unsafe {
             let mut value = Value::new();
             ffi::gtk_tree_model_get_value(self.pointer, iter.unwrap_pointer(), column,
                 value.as_mut_ptr());
-            value
+            Ok(value)
         }
     }
 
-    pub fn iter_next(&self, iter: &mut TreeIter) -> bool {
+    /// Moves `iter` to the next row, returns Ok(false) if there is none.
+    pub fn iter_next(&self, iter: &mut TreeIter) -> Result<bool, TreeIterError> {
+        try!(self.check_iter(iter));
         match unsafe { ffi::gtk_tree_model_iter_next(self.pointer, iter.unwrap_pointer()) } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn iter_previous(&self, iter: &mut TreeIter) -> bool {
+    pub fn iter_previous(&self, iter: &mut TreeIter) -> Result<bool, TreeIterError> {
+        try!(self.check_iter(iter));
         match unsafe { ffi::gtk_tree_model_iter_previous(self.pointer, iter.unwrap_pointer()) } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn iter_children(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) -> bool {
+    /// Sets `iter` to the first child of `parent`, or to the first row if `parent` is None.
+    /// Returns Ok(false) if there is none.
+    pub fn iter_children(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) -> Result<bool, TreeIterError> {
+        if let Some(parent) = parent {
+            try!(self.check_iter(parent));
+        }
+        iter.set_model_pointer(self.pointer);
         match unsafe {
             ffi::gtk_tree_model_iter_children(self.pointer,
                                               iter.unwrap_pointer(),
                                               if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() })
         } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn iter_has_child(&self, iter: &TreeIter) -> bool {
+    pub fn iter_has_child(&self, iter: &TreeIter) -> Result<bool, TreeIterError> {
+        try!(self.check_iter(iter));
         match unsafe { ffi::gtk_tree_model_iter_has_child(self.pointer, iter.unwrap_pointer()) } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn iter_n_children(&self, iter: Option<&TreeIter>) -> i32 {
-        unsafe { ffi::gtk_tree_model_iter_n_children(self.pointer, match iter {
+    pub fn iter_n_children(&self, iter: Option<&TreeIter>) -> Result<i32, TreeIterError> {
+        if let Some(iter) = iter {
+            try!(self.check_iter(iter));
+        }
+        Ok(unsafe { ffi::gtk_tree_model_iter_n_children(self.pointer, match iter {
                 Some(i) => i.unwrap_pointer(),
                 None => ::std::ptr::null_mut()
-            }) }
+            }) })
     }
 
-    pub fn iter_nth_child(&self, iter: &mut TreeIter, parent: Option<&TreeIter>, n: i32) -> bool {
+    pub fn iter_nth_child(&self, iter: &mut TreeIter, parent: Option<&TreeIter>, n: i32) -> Result<bool, TreeIterError> {
+        if let Some(parent) = parent {
+            try!(self.check_iter(parent));
+        }
+        iter.set_model_pointer(self.pointer);
         match unsafe {
             ffi::gtk_tree_model_iter_nth_child(self.pointer,
                 iter.unwrap_pointer(),
@@ -115,24 +183,29 @@ impl TreeModel {
                 },
                 n)
         } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn iter_parent(&self, iter: &mut TreeIter, child: &TreeIter) -> bool {
+    pub fn iter_parent(&self, iter: &mut TreeIter, child: &TreeIter) -> Result<bool, TreeIterError> {
+        try!(self.check_iter(child));
+        iter.set_model_pointer(self.pointer);
         match unsafe { ffi::gtk_tree_model_iter_parent(self.pointer, iter.unwrap_pointer(), child.unwrap_pointer()) } {
-            0 => false,
-            _ => true
+            0 => Ok(false),
+            _ => Ok(true)
         }
     }
 
-    pub fn get_string_from_iter(&self, iter: &TreeIter) -> Option<String> {
-        unsafe {
+    pub fn get_string_from_iter(&self, iter: &TreeIter) -> Result<String, TreeIterError> {
+        try!(self.check_iter(iter));
+
+        let string: Option<String> = unsafe {
             from_glib_full(
                 ffi::gtk_tree_model_get_string_from_iter(self.pointer,
                                                          iter.unwrap_pointer()))
-        }
+        };
+        string.ok_or(TreeIterError::Stale)
     }
 
     pub fn row_changed(&self, path: &TreePath, iter: &TreeIter) {
@@ -188,9 +261,54 @@ impl TreeModel {
     }
 }
 
+const DELETION_COUNT_KEY: &'static [u8] = b"rgtk-deletion-count\0";
+
+/// Returns the number of rows deleted from `model` since the first call, which connects the
+/// `row-deleted` handler keeping the count.
+#[doc(hidden)]
+pub fn get_deletion_count(model: *mut ffi::C_GtkTreeModel) -> u64 {
+    if model.is_null() {
+        return 0;
+    }
+
+    unsafe {
+        let key = DELETION_COUNT_KEY.as_ptr() as *const c_char;
+        let count = ffi::g_object_get_data(model as *mut ffi::C_GObject, key);
+
+        if count.is_null() {
+            let count: ffi::gpointer = transmute(Box::new(Cell::new(0u64)));
+
+            ffi::g_object_set_data_full(model as *mut ffi::C_GObject, key, count, Some(free_deletion_count));
+            ffi::g_signal_connect_data(model as ffi::gpointer, b"row-deleted\0".as_ptr() as *const c_char,
+                Some(transmute(row_deleted_trampoline)), count, None, 0);
+            0
+        } else {
+            let count: &Cell<u64> = transmute(count);
+            count.get()
+        }
+    }
+}
+
+extern "C" fn row_deleted_trampoline(_: *mut ffi::C_GtkTreeModel, _: *mut ffi::C_GtkTreePath, count: ffi::gpointer) {
+    unsafe {
+        let count: &Cell<u64> = transmute(count);
+        count.set(count.get() + 1);
+    }
+}
+
+extern "C" fn free_deletion_count(count: ffi::gpointer) {
+    unsafe {
+        let _: Box<Cell<u64>> = transmute(count);
+    }
+}
+
 fn my_fn(model: *mut ffi::C_GtkTreeModel, path: *mut ffi::C_GtkTreePath, iter: *mut ffi::C_GtkTreeIter,
     data: &mut (fn(&mut TreeModel, &mut TreePath, &mut TreeIter, data: *mut c_void) -> bool, &mut c_void)) -> ffi::Gboolean {
-    glib::to_gboolean(data.0(&mut TreeModel::wrap_pointer(model), &mut TreePath::wrap_pointer(path), &mut TreeIter::wrap_pointer(iter), data.1))
+    let mut iter = TreeIter::wrap_pointer(iter);
+
+    iter.set_model_pointer(model);
+    unsafe { glib_ffi::g_object_ref(model as *mut c_void) };
+    glib::to_gboolean(data.0(&mut TreeModel::wrap_pointer(model), &mut TreePath::wrap_borrowed_pointer(path), &mut iter, data.1))
 }
 
 impl_drop!(TreeModel, GTK_TREE_MODEL);
\ No newline at end of file
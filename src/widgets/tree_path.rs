@@ -2,7 +2,11 @@
 // See the COPYRIGHT file at the top-level directory of this distribution.
 // Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
 
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
 use std::slice;
+use std::str::FromStr;
 use ffi;
 use glib::translate::{from_glib_full, ToGlibPtr};
 
@@ -62,13 +66,6 @@ impl TreePath {
         }
     }
 
-    #[allow(unused_variables)]
-    pub fn to_string(&self) -> Option<String> {
-        unsafe {
-            from_glib_full(ffi::gtk_tree_path_to_string(self.pointer))
-        }
-    }
-
     pub fn append_index(&self, index_: i32) {
         unsafe { ffi::gtk_tree_path_append_index(self.pointer, index_) }
     }
@@ -140,20 +137,99 @@ impl TreePath {
         }
     }
 
-    pub fn drop(&mut self) {
-        unsafe { ffi::gtk_tree_path_free(self.pointer as *mut ffi::C_GtkTreePath) }
-        self.pointer = ::std::ptr::null_mut();
-    }
-
     #[doc(hidden)]
     pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTreePath {
         self.pointer
     }
 
+    /// Takes ownership of `c_treepath`.
     #[doc(hidden)]
     pub fn wrap_pointer(c_treepath: *mut ffi::C_GtkTreePath) -> TreePath {
         TreePath {
             pointer: c_treepath
         }
     }
+
+    /// Copies `c_treepath`, which is still owned by the caller.
+    #[doc(hidden)]
+    pub fn wrap_borrowed_pointer(c_treepath: *mut ffi::C_GtkTreePath) -> TreePath {
+        TreePath {
+            pointer: unsafe { ffi::gtk_tree_path_copy(c_treepath) }
+        }
+    }
+}
+
+impl Drop for TreePath {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::gtk_tree_path_free(self.pointer) }
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
+
+impl Clone for TreePath {
+    fn clone(&self) -> TreePath {
+        TreePath::wrap_borrowed_pointer(self.pointer)
+    }
+}
+
+impl PartialEq for TreePath {
+    fn eq(&self, other: &TreePath) -> bool {
+        self.compare(other) == 0
+    }
+}
+
+impl Eq for TreePath {}
+
+impl PartialOrd for TreePath {
+    fn partial_cmp(&self, other: &TreePath) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TreePath {
+    fn cmp(&self, other: &TreePath) -> Ordering {
+        self.compare(other).cmp(&0)
+    }
+}
+
+/// Formats the path as a list of indices separated by colons, like "10:4:0". The root
+/// path is formatted as an empty string.
+impl fmt::Display for TreePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let string: Option<String> = unsafe { from_glib_full(ffi::gtk_tree_path_to_string(self.pointer)) };
+
+        f.write_str(string.as_ref().map(|s| &s[..]).unwrap_or(""))
+    }
+}
+
+impl fmt::Debug for TreePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TreePath({:?})", self.to_string())
+    }
+}
+
+/// The string given to `TreePath::from_str` isn't a list of indices separated by colons.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseTreePathError;
+
+impl fmt::Display for ParseTreePathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.description())
+    }
+}
+
+impl Error for ParseTreePathError {
+    fn description(&self) -> &str {
+        "invalid tree path"
+    }
+}
+
+impl FromStr for TreePath {
+    type Err = ParseTreePathError;
+
+    fn from_str(s: &str) -> Result<TreePath, ParseTreePathError> {
+        TreePath::new_from_string(s).ok_or(ParseTreePathError)
+    }
 }
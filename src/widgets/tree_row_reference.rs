@@ -0,0 +1,103 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+use ffi;
+use glib::to_bool;
+use glib_ffi;
+use libc::c_void;
+use {TreeIter, TreeModel, TreePath};
+
+/// TreeRowReference — A handle to a row which follows it when the model changes
+///
+/// The reference is updated when rows are inserted, deleted or reordered in the model and
+/// stops being valid once its row is removed.
+pub struct TreeRowReference {
+    pointer: *mut ffi::C_GtkTreeRowReference
+}
+
+impl TreeRowReference {
+    /// Returns None if `path` isn't a valid path in `model`.
+    pub fn new(model: &TreeModel, path: &TreePath) -> Option<TreeRowReference> {
+        let tmp_pointer = unsafe { ffi::gtk_tree_row_reference_new(model.unwrap_pointer(), path.unwrap_pointer()) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(TreeRowReference::wrap_pointer(tmp_pointer))
+        }
+    }
+
+    /// Returns None if `iter` isn't valid for `model`.
+    pub fn new_from_iter(model: &TreeModel, iter: &TreeIter) -> Option<TreeRowReference> {
+        match model.get_path(iter) {
+            Ok(path) => TreeRowReference::new(model, &path),
+            Err(_) => None
+        }
+    }
+
+    pub fn get_model(&self) -> TreeModel {
+        unsafe {
+            let tmp_pointer = ffi::gtk_tree_row_reference_get_model(self.pointer);
+
+            glib_ffi::g_object_ref(tmp_pointer as *mut c_void);
+            TreeModel::wrap_pointer(tmp_pointer)
+        }
+    }
+
+    /// Returns the current path of the row, or None once the row is gone.
+    pub fn get_path(&self) -> Option<TreePath> {
+        let tmp_pointer = unsafe { ffi::gtk_tree_row_reference_get_path(self.pointer) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(TreePath::wrap_pointer(tmp_pointer))
+        }
+    }
+
+    /// Fills `iter` with the current row, returns false once the row is gone.
+    pub fn get_iter(&self, iter: &mut TreeIter) -> bool {
+        match self.get_path() {
+            Some(path) => self.get_model().get_iter(iter, &path),
+            None => false
+        }
+    }
+
+    pub fn valid(&self) -> bool {
+        unsafe { to_bool(ffi::gtk_tree_row_reference_valid(self.pointer)) }
+    }
+
+    /// Returns a new reference to the same row, or None once the row is gone.
+    pub fn copy(&self) -> Option<TreeRowReference> {
+        let tmp_pointer = unsafe { ffi::gtk_tree_row_reference_copy(self.pointer) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(TreeRowReference::wrap_pointer(tmp_pointer))
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTreeRowReference {
+        self.pointer
+    }
+
+    /// Takes ownership of `c_reference`.
+    #[doc(hidden)]
+    pub fn wrap_pointer(c_reference: *mut ffi::C_GtkTreeRowReference) -> TreeRowReference {
+        TreeRowReference {
+            pointer: c_reference
+        }
+    }
+}
+
+impl Drop for TreeRowReference {
+    fn drop(&mut self) {
+        if !self.pointer.is_null() {
+            unsafe { ffi::gtk_tree_row_reference_free(self.pointer) };
+            self.pointer = ::std::ptr::null_mut();
+        }
+    }
+}
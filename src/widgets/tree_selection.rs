@@ -36,6 +36,7 @@ impl TreeSelection {
     }
 
     pub fn get_selected(&self, model: &::TreeModel, iter: &mut ::TreeIter) -> bool {
+        iter.set_model_pointer(model.unwrap_pointer());
         match unsafe { ffi::gtk_tree_selection_get_selected(self.pointer, &mut model.unwrap_pointer(),
             iter.unwrap_pointer()) } {
             0 => false,
@@ -30,11 +30,18 @@ impl TreeStore {
         unsafe { ffi::gtk_tree_store_set(self.pointer, iter.unwrap_pointer(), column, text.to_glib_none().0, -1) }
     }
 
+    /// Removes the row of `iter` and moves `iter` to the next sibling, returns false if there is none.
     pub fn remove(&self, iter: &TreeIter) -> bool {
-        unsafe { to_bool(ffi::gtk_tree_store_remove(self.pointer, iter.unwrap_pointer())) }
+        let has_next = unsafe { to_bool(ffi::gtk_tree_store_remove(self.pointer, iter.unwrap_pointer())) };
+
+        if has_next {
+            iter.update_deletion_count();
+        }
+        has_next
     }
 
     pub fn insert(&self, iter: &mut TreeIter, parent: Option<&TreeIter>, position: i32) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_TREE_STORE(self.pointer));
         unsafe { ffi::gtk_tree_store_insert(self.pointer,
                                             iter.unwrap_pointer(),
                                             if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() },
@@ -42,6 +49,7 @@ impl TreeStore {
     }
 
     pub fn insert_before(&self, iter: &mut TreeIter, parent: Option<&TreeIter>, sibling: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_TREE_STORE(self.pointer));
         unsafe { ffi::gtk_tree_store_insert_before(self.pointer,
                                                    iter.unwrap_pointer(),
                                                    if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() },
@@ -49,6 +57,7 @@ impl TreeStore {
     }
 
     pub fn insert_after(&self, iter: &mut TreeIter, parent: Option<&TreeIter>, sibling: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_TREE_STORE(self.pointer));
         unsafe { ffi::gtk_tree_store_insert_after(self.pointer,
                                                   iter.unwrap_pointer(),
                                                   if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() },
@@ -56,11 +65,13 @@ impl TreeStore {
     }
 
     pub fn prepend(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_TREE_STORE(self.pointer));
         unsafe { ffi::gtk_tree_store_prepend(self.pointer, iter.unwrap_pointer(),
                                              if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() }) }
     }
 
     pub fn append(&self, iter: &mut TreeIter, parent: Option<&TreeIter>) {
+        iter.set_model_pointer(::cast::GTK_TREE_MODEL_FROM_TREE_STORE(self.pointer));
         unsafe { ffi::gtk_tree_store_append(self.pointer, iter.unwrap_pointer(),
                                             if parent.is_none() { ::std::ptr::null_mut() } else { parent.unwrap().unwrap_pointer() }) }
     }
@@ -4,13 +4,19 @@
 
 //! A widget for displaying both trees and lists
 
+use std::mem::transmute;
+use std::ptr;
 use FFIWidget;
 use ffi;
-use cast::GTK_TREE_VIEW;
-use widgets::{TreePath, TreeSelection, TreeViewColumn};
-use glib::{to_bool, to_gboolean};
+use gdk;
+use cast::{GTK_TREE_VIEW, GTK_CELL_RENDERER};
+use widgets::{TreeIter, TreeModel, TreePath, TreeSelection, TreeViewColumn};
+use glib::{to_bool, to_gboolean, Type, Value};
+use glib::translate::{from_glib_full, from_glib_none, ToGlib};
+use glib;
 use glib_ffi;
-use libc::c_void;
+use libc::{c_char, c_int, c_void};
+use {TargetEntry, Tooltip, TreeViewDropPosition};
 
 /// TreeView — A widget for displaying both trees and lists
 struct_Widget!(TreeView);
@@ -135,6 +141,64 @@ impl TreeView {
         }
     }
 
+    pub fn enable_model_drag_source(&self, start_button_mask: gdk::ModifierType, targets: &[TargetEntry],
+                                    actions: gdk::DragAction) {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+
+        unsafe {
+            ffi::gtk_tree_view_enable_model_drag_source(GTK_TREE_VIEW(self.pointer), start_button_mask,
+                                                        c_targets.as_ptr(), c_targets.len() as c_int, actions)
+        }
+    }
+
+    pub fn enable_model_drag_dest(&self, targets: &[TargetEntry], actions: gdk::DragAction) {
+        let c_targets: Vec<ffi::C_GtkTargetEntry> = targets.iter().map(|t| t.to_c_entry()).collect();
+
+        unsafe {
+            ffi::gtk_tree_view_enable_model_drag_dest(GTK_TREE_VIEW(self.pointer), c_targets.as_ptr(),
+                                                      c_targets.len() as c_int, actions)
+        }
+    }
+
+    /// Sets the row that is highlighted for feedback, None unsets it.
+    pub fn set_drag_dest_row(&self, path: Option<&TreePath>, pos: TreeViewDropPosition) {
+        unsafe {
+            ffi::gtk_tree_view_set_drag_dest_row(GTK_TREE_VIEW(self.pointer),
+                                                 match path {
+                                                     Some(p) => p.unwrap_pointer(),
+                                                     None => ptr::null_mut()
+                                                 },
+                                                 pos)
+        }
+    }
+
+    pub fn get_drag_dest_row(&self) -> Option<(TreePath, TreeViewDropPosition)> {
+        let mut path = ptr::null_mut();
+        let mut pos = TreeViewDropPosition::Before;
+
+        unsafe { ffi::gtk_tree_view_get_drag_dest_row(GTK_TREE_VIEW(self.pointer), &mut path, &mut pos) };
+        if path.is_null() {
+            None
+        } else {
+            Some((TreePath::wrap_pointer(path), pos))
+        }
+    }
+
+    /// Determines the destination row for the given drag position, the coordinates are
+    /// relative to the widget.
+    pub fn get_dest_row_at_pos(&self, drag_x: i32, drag_y: i32) -> Option<(TreePath, TreeViewDropPosition)> {
+        let mut path = ptr::null_mut();
+        let mut pos = TreeViewDropPosition::Before;
+
+        if to_bool(unsafe {
+            ffi::gtk_tree_view_get_dest_row_at_pos(GTK_TREE_VIEW(self.pointer), drag_x, drag_y, &mut path, &mut pos)
+        }) && !path.is_null() {
+            Some((TreePath::wrap_pointer(path), pos))
+        } else {
+            None
+        }
+    }
+
     pub fn get_enable_search(&self) -> bool {
         unsafe {
             to_bool(ffi::gtk_tree_view_get_enable_search(GTK_TREE_VIEW(self.pointer)))
@@ -424,6 +488,304 @@ impl TreeView {
         unsafe { ffi::gtk_tree_view_append_column(GTK_TREE_VIEW(self.pointer),
                                                   column.unwrap_pointer()) }
     }
+
+    pub fn insert_column(&self, column: &TreeViewColumn, position: i32) -> i32 {
+        unsafe { ffi::gtk_tree_view_insert_column(GTK_TREE_VIEW(self.pointer),
+                                                  column.unwrap_pointer(), position) }
+    }
+
+    pub fn remove_column(&self, column: &TreeViewColumn) -> i32 {
+        unsafe { ffi::gtk_tree_view_remove_column(GTK_TREE_VIEW(self.pointer),
+                                                  column.unwrap_pointer()) }
+    }
+
+    /// Moves `column` right after `base_column`, or to the first position if `base_column` is None.
+    pub fn move_column_after(&self, column: &TreeViewColumn, base_column: Option<&TreeViewColumn>) {
+        unsafe {
+            ffi::gtk_tree_view_move_column_after(GTK_TREE_VIEW(self.pointer),
+                                                 column.unwrap_pointer(),
+                                                 match base_column {
+                                                     Some(b) => b.unwrap_pointer(),
+                                                     None => ptr::null_mut()
+                                                 })
+        }
+    }
+
+    pub fn get_column(&self, n: i32) -> Option<TreeViewColumn> {
+        let tmp_pointer = unsafe { ffi::gtk_tree_view_get_column(GTK_TREE_VIEW(self.pointer), n) };
+
+        if tmp_pointer.is_null() {
+            None
+        } else {
+            Some(TreeViewColumn::wrap_pointer(tmp_pointer))
+        }
+    }
+
+    pub fn get_columns(&self) -> Vec<TreeViewColumn> {
+        let tmp = unsafe { ffi::gtk_tree_view_get_columns(GTK_TREE_VIEW(self.pointer)) };
+
+        if tmp.is_null() {
+            Vec::new()
+        } else {
+            let old_list : glib::List<*mut ffi::C_GtkTreeViewColumn> = glib::GlibContainer::wrap(tmp);
+
+            old_list.iter().map(|it| TreeViewColumn::wrap_pointer(*it)).collect()
+        }
+    }
+
+    pub fn scroll_to_cell(&self, path: Option<&TreePath>, column: Option<&TreeViewColumn>, use_align: bool,
+                          row_align: f32, col_align: f32) {
+        unsafe {
+            ffi::gtk_tree_view_scroll_to_cell(GTK_TREE_VIEW(self.pointer),
+                                              match path {
+                                                  Some(p) => p.unwrap_pointer(),
+                                                  None => ptr::null_mut()
+                                              },
+                                              match column {
+                                                  Some(c) => c.unwrap_pointer(),
+                                                  None => ptr::null_mut()
+                                              },
+                                              to_gboolean(use_align),
+                                              row_align,
+                                              col_align)
+        }
+    }
+
+    /// Returns the path, column and cell relative coordinates at the given bin window
+    /// coordinates, or None if there is no row at that position.
+    pub fn get_path_at_pos(&self, x: i32, y: i32) -> Option<(TreePath, Option<TreeViewColumn>, i32, i32)> {
+        let mut path = ptr::null_mut();
+        let mut column = ptr::null_mut();
+        let mut cell_x = 0;
+        let mut cell_y = 0;
+
+        if to_bool(unsafe {
+            ffi::gtk_tree_view_get_path_at_pos(GTK_TREE_VIEW(self.pointer), x, y, &mut path, &mut column,
+                                               &mut cell_x, &mut cell_y)
+        }) && !path.is_null() {
+            Some((TreePath::wrap_pointer(path),
+                  if column.is_null() { None } else { Some(TreeViewColumn::wrap_pointer(column)) },
+                  cell_x, cell_y))
+        } else {
+            None
+        }
+    }
+
+    /// Returns None if there is a cell at the given position. Otherwise the path, column and
+    /// cell relative coordinates found nearest to the blank area are returned.
+    pub fn is_blank_at_pos(&self, x: i32, y: i32) -> Option<(Option<TreePath>, Option<TreeViewColumn>, i32, i32)> {
+        let mut path = ptr::null_mut();
+        let mut column = ptr::null_mut();
+        let mut cell_x = 0;
+        let mut cell_y = 0;
+
+        let blank = to_bool(unsafe {
+            ffi::gtk_tree_view_is_blank_at_pos(GTK_TREE_VIEW(self.pointer), x, y, &mut path, &mut column,
+                                               &mut cell_x, &mut cell_y)
+        });
+        let path = if path.is_null() { None } else { Some(TreePath::wrap_pointer(path)) };
+
+        if blank {
+            Some((path,
+                  if column.is_null() { None } else { Some(TreeViewColumn::wrap_pointer(column)) },
+                  cell_x, cell_y))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_cell_area(&self, path: Option<&TreePath>, column: Option<&TreeViewColumn>) -> gdk::Rectangle {
+        let mut rect = gdk::Rectangle { x: 0, y: 0, width: 0, height: 0 };
+
+        unsafe {
+            ffi::gtk_tree_view_get_cell_area(GTK_TREE_VIEW(self.pointer),
+                                             match path {
+                                                 Some(p) => p.unwrap_pointer(),
+                                                 None => ptr::null_mut()
+                                             },
+                                             match column {
+                                                 Some(c) => c.unwrap_pointer(),
+                                                 None => ptr::null_mut()
+                                             },
+                                             &mut rect as *mut _ as *mut _)
+        }
+        rect
+    }
+
+    pub fn get_background_area(&self, path: Option<&TreePath>, column: Option<&TreeViewColumn>) -> gdk::Rectangle {
+        let mut rect = gdk::Rectangle { x: 0, y: 0, width: 0, height: 0 };
+
+        unsafe {
+            ffi::gtk_tree_view_get_background_area(GTK_TREE_VIEW(self.pointer),
+                                                   match path {
+                                                       Some(p) => p.unwrap_pointer(),
+                                                       None => ptr::null_mut()
+                                                   },
+                                                   match column {
+                                                       Some(c) => c.unwrap_pointer(),
+                                                       None => ptr::null_mut()
+                                                   },
+                                                   &mut rect as *mut _ as *mut _)
+        }
+        rect
+    }
+
+    /// Returns the first and the last visible path.
+    pub fn get_visible_range(&self) -> Option<(TreePath, TreePath)> {
+        let mut start_path = ptr::null_mut();
+        let mut end_path = ptr::null_mut();
+
+        if to_bool(unsafe {
+            ffi::gtk_tree_view_get_visible_range(GTK_TREE_VIEW(self.pointer), &mut start_path, &mut end_path)
+        }) {
+            Some((TreePath::wrap_pointer(start_path), TreePath::wrap_pointer(end_path)))
+        } else {
+            None
+        }
+    }
+
+    pub fn set_tooltip_row(&self, tooltip: &Tooltip, path: &TreePath) {
+        unsafe {
+            ffi::gtk_tree_view_set_tooltip_row(GTK_TREE_VIEW(self.pointer), tooltip.unwrap_pointer(),
+                                               path.unwrap_pointer())
+        }
+    }
+
+    pub fn set_tooltip_cell<T: ::CellRendererTrait>(&self, tooltip: &Tooltip, path: Option<&TreePath>,
+                                                    column: Option<&TreeViewColumn>, cell: Option<&T>) {
+        unsafe {
+            ffi::gtk_tree_view_set_tooltip_cell(GTK_TREE_VIEW(self.pointer),
+                                                tooltip.unwrap_pointer(),
+                                                match path {
+                                                    Some(p) => p.unwrap_pointer(),
+                                                    None => ptr::null_mut()
+                                                },
+                                                match column {
+                                                    Some(c) => c.unwrap_pointer(),
+                                                    None => ptr::null_mut()
+                                                },
+                                                match cell {
+                                                    Some(c) => GTK_CELL_RENDERER(c.unwrap_widget()),
+                                                    None => ptr::null_mut()
+                                                })
+        }
+    }
+
+    /// The closure returns true for the rows that should be drawn as separators.
+    pub fn set_row_separator_func<F: Fn(TreeModel, &TreeIter) -> bool + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, &TreeIter) -> bool + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_tree_view_set_row_separator_func(GTK_TREE_VIEW(self.pointer),
+                                                      Some(row_separator_trampoline),
+                                                      transmute(f),
+                                                      Some(destroy_row_separator_closure))
+        }
+    }
+
+    pub fn unset_row_separator_func(&self) {
+        unsafe {
+            ffi::gtk_tree_view_set_row_separator_func(GTK_TREE_VIEW(self.pointer), None,
+                                                      ptr::null_mut(), None)
+        }
+    }
+
+    /// As in GTK, the closure receives the model, the search column, the key and the row, and
+    /// returns false if the row matches the key.
+    pub fn set_search_equal_func<F: Fn(TreeModel, i32, &str, &TreeIter) -> bool + 'static>(&self, func: F) {
+        unsafe {
+            let f: Box<Box<Fn(TreeModel, i32, &str, &TreeIter) -> bool + 'static>> = Box::new(Box::new(func));
+            ffi::gtk_tree_view_set_search_equal_func(GTK_TREE_VIEW(self.pointer),
+                                                     Some(search_equal_trampoline),
+                                                     transmute(f),
+                                                     Some(destroy_search_equal_closure))
+        }
+    }
+
+    /// Restores the default comparison, which matches the rows whose value in the search
+    /// column starts with the key, ignoring the case.
+    pub fn unset_search_equal_func(&self) {
+        // GTK doesn't accept a NULL function here and its default one isn't exported
+        unsafe {
+            ffi::gtk_tree_view_set_search_equal_func(GTK_TREE_VIEW(self.pointer),
+                                                     Some(default_search_equal_trampoline),
+                                                     ptr::null_mut(), None)
+        }
+    }
+}
+
+extern "C" fn row_separator_trampoline(model: *mut ffi::C_GtkTreeModel, iter: *mut ffi::C_GtkTreeIter,
+                                       data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(TreeModel, &TreeIter) -> bool + 'static> = transmute(data);
+        let mut iter = TreeIter::wrap_pointer(iter);
+
+        iter.set_model_pointer(model);
+        glib_ffi::g_object_ref(model as *mut c_void);
+        to_gboolean(f(TreeModel::wrap_pointer(model), &iter))
+    }
+}
+
+extern "C" fn destroy_row_separator_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(TreeModel, &TreeIter) -> bool + 'static>> = transmute(data);
+    }
+}
+
+extern "C" fn search_equal_trampoline(model: *mut ffi::C_GtkTreeModel, column: c_int, key: *const c_char,
+                                      iter: *mut ffi::C_GtkTreeIter, data: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let f: &Box<Fn(TreeModel, i32, &str, &TreeIter) -> bool + 'static> = transmute(data);
+        let key: String = from_glib_none(key);
+        let mut iter = TreeIter::wrap_pointer(iter);
+
+        iter.set_model_pointer(model);
+        glib_ffi::g_object_ref(model as *mut c_void);
+        to_gboolean(f(TreeModel::wrap_pointer(model), column, &key, &iter))
+    }
+}
+
+// The comparison of gtk_tree_view_search_equal_func: the value is transformed to a string
+// and compared with the key once both are normalized and case folded.
+extern "C" fn default_search_equal_trampoline(model: *mut ffi::C_GtkTreeModel, column: c_int,
+                                              key: *const c_char, iter: *mut ffi::C_GtkTreeIter,
+                                              _: ffi::gpointer) -> ffi::Gboolean {
+    unsafe {
+        let mut value = Value::new();
+        let mut transformed = Value::new();
+
+        ffi::gtk_tree_model_get_value(model, iter, column, value.as_mut_ptr());
+        glib_ffi::g_value_init(transformed.as_mut_ptr(), Type::String.to_glib());
+        if !to_bool(ffi::g_value_transform(value.as_ptr(), transformed.as_mut_ptr())) {
+            return ffi::GTRUE;
+        }
+        let string = ffi::g_value_get_string(transformed.as_ptr());
+
+        match (fold(string), fold(key)) {
+            (Some(string), Some(key)) => to_gboolean(!string.starts_with(&key)),
+            _ => ffi::GTRUE
+        }
+    }
+}
+
+/// Returns `s` normalized (G_NORMALIZE_ALL) and case folded, None if it isn't valid UTF-8.
+unsafe fn fold(s: *const c_char) -> Option<String> {
+    if s.is_null() {
+        return None;
+    }
+    let normalized = ffi::g_utf8_normalize(s, -1, 2);
+
+    if normalized.is_null() {
+        return None;
+    }
+    let folded = from_glib_full(ffi::g_utf8_casefold(normalized, -1));
+    glib_ffi::g_free(normalized as *mut c_void);
+    folded
+}
+
+extern "C" fn destroy_search_equal_closure(data: ffi::gpointer) {
+    unsafe {
+        let _: Box<Box<Fn(TreeModel, i32, &str, &TreeIter) -> bool + 'static>> = transmute(data);
+    }
 }
 
 impl_drop!(TreeView);
@@ -288,6 +288,19 @@ impl TreeViewColumn {
                                                     to_gboolean(expand)) }
     }
 
+    /// Sets the buildable name of the column, which is also the id used by `TreeViewLayout`.
+    pub fn set_name(&self, name: &str) {
+        unsafe {
+            ffi::gtk_buildable_set_name(self.pointer as *mut ffi::C_GtkBuildable, name.to_glib_none().0)
+        }
+    }
+
+    pub fn get_name(&self) -> Option<String> {
+        unsafe {
+            from_glib_none(ffi::gtk_buildable_get_name(self.pointer as *mut ffi::C_GtkBuildable))
+        }
+    }
+
     #[doc(hidden)]
     pub fn unwrap_pointer(&self) -> *mut ffi::C_GtkTreeViewColumn {
         self.pointer
@@ -0,0 +1,216 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Snapshot of the column layout of a TreeView
+
+use std::ffi::NulError;
+use ffi;
+use cast::{GTK_IS_TREE_SORTABLE, GTK_TREE_SORTABLE};
+use {KeyFile, SortType, TreeView, TreeViewColumn, TreeViewColumnSizing};
+
+/// The state of a single column, the id is the name of the column (see
+/// `TreeViewColumn::set_name`). The width is -1 for the columns sized from their content,
+/// only the fixed and resizable columns keep theirs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnState {
+    pub id: String,
+    pub width: i32,
+    pub visible: bool
+}
+
+/// TreeViewLayout — The order, widths, visibility and sorting of the columns of a TreeView
+///
+/// Only the columns having a name are part of the layout. Names are used in key file keys, so
+/// they shouldn't contain '=', '[' or ']'.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeViewLayout {
+    pub columns: Vec<ColumnState>,
+    pub sort_column: Option<String>,
+    pub sort_order: SortType
+}
+
+impl TreeViewLayout {
+    pub fn capture(tree_view: &TreeView) -> TreeViewLayout {
+        let mut layout = TreeViewLayout {
+            columns: Vec::new(),
+            sort_column: None,
+            sort_order: SortType::Ascending
+        };
+
+        for column in tree_view.get_columns() {
+            let id = match column.get_name() {
+                Some(id) => id,
+                None => continue
+            };
+
+            if column.get_sort_indicator() {
+                layout.sort_column = Some(id.clone());
+                layout.sort_order = column.get_sort_order();
+            }
+            layout.columns.push(ColumnState {
+                id: id,
+                width: if has_restorable_width(&column) { column.get_width() } else { -1 },
+                visible: column.get_visible()
+            });
+        }
+        layout
+    }
+
+    /// Columns of `tree_view` which aren't part of the layout are left after the ones which are.
+    pub fn apply(&self, tree_view: &TreeView) {
+        let columns = tree_view.get_columns();
+        let mut previous: Option<&TreeViewColumn> = None;
+
+        for state in self.columns.iter() {
+            if let Some(column) = find_column(&columns, &state.id) {
+                tree_view.move_column_after(column, previous);
+                if state.width > 0 && has_restorable_width(column) {
+                    column.set_fixed_width(state.width);
+                }
+                column.set_visible(state.visible);
+                previous = Some(column);
+            }
+        }
+
+        for column in columns.iter() {
+            column.set_sort_indicator(false);
+        }
+        if let Some(column) = self.sort_column.as_ref().and_then(|id| find_column(&columns, id)) {
+            column.set_sort_indicator(true);
+            column.set_sort_order(self.sort_order);
+
+            let sort_column_id = column.get_sort_column_id();
+            if let Some(model) = tree_view.get_model() {
+                if sort_column_id >= 0 && GTK_IS_TREE_SORTABLE(model.unwrap_pointer()) {
+                    unsafe {
+                        ffi::gtk_tree_sortable_set_sort_column_id(GTK_TREE_SORTABLE(model.unwrap_pointer()),
+                                                                  sort_column_id, self.sort_order)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Fails if a column id contains a nul byte.
+    pub fn save_to_key_file(&self, key_file: &KeyFile, group: &str) -> Result<(), NulError> {
+        key_file.remove_group(group);
+
+        let ids: Vec<&str> = self.columns.iter().map(|c| &c.id[..]).collect();
+        try!(key_file.set_string_list(group, "columns", &ids));
+        for state in self.columns.iter() {
+            key_file.set_integer(group, &format!("{}.width", state.id), state.width);
+            key_file.set_boolean(group, &format!("{}.visible", state.id), state.visible);
+        }
+        if let Some(ref id) = self.sort_column {
+            key_file.set_string(group, "sort-column", id);
+            key_file.set_string(group, "sort-order", match self.sort_order {
+                SortType::Ascending => "ascending",
+                SortType::Descending => "descending"
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns None if `group` isn't in `key_file`.
+    pub fn load_from_key_file(key_file: &KeyFile, group: &str) -> Option<TreeViewLayout> {
+        let ids = match key_file.get_string_list(group, "columns") {
+            Some(ids) => ids,
+            None => return None
+        };
+
+        Some(TreeViewLayout {
+            columns: ids.iter().filter(|id| !id.is_empty()).map(|id| ColumnState {
+                id: id.clone(),
+                width: key_file.get_integer(group, &format!("{}.width", id)).unwrap_or(-1),
+                visible: key_file.get_boolean(group, &format!("{}.visible", id)).unwrap_or(true)
+            }).collect(),
+            sort_column: key_file.get_string(group, "sort-column"),
+            sort_order: match key_file.get_string(group, "sort-order") {
+                Some(ref order) if order == "descending" => SortType::Descending,
+                _ => SortType::Ascending
+            }
+        })
+    }
+
+    /// Serializes the layout in the key file format, under `group`. Fails if a column id
+    /// contains a nul byte.
+    pub fn to_data(&self, group: &str) -> Result<String, NulError> {
+        let key_file = KeyFile::new();
+
+        try!(self.save_to_key_file(&key_file, group));
+        Ok(key_file.to_data())
+    }
+
+    pub fn from_data(data: &str, group: &str) -> Option<TreeViewLayout> {
+        let key_file = KeyFile::new();
+
+        match key_file.load_from_data(data) {
+            Ok(_) => TreeViewLayout::load_from_key_file(&key_file, group),
+            Err(_) => None
+        }
+    }
+}
+
+/// The width set by the user on the fixed and resizable columns is kept, the other columns
+/// are sized from their content.
+fn has_restorable_width(column: &TreeViewColumn) -> bool {
+    column.get_sizing() == TreeViewColumnSizing::Fixed || column.get_resizable()
+}
+
+fn find_column<'a>(columns: &'a [TreeViewColumn], id: &str) -> Option<&'a TreeViewColumn> {
+    columns.iter().find(|c| c.get_name().map_or(false, |name| name == id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnState, TreeViewLayout};
+    use SortType;
+
+    fn column(id: &str, width: i32, visible: bool) -> ColumnState {
+        ColumnState {
+            id: id.to_owned(),
+            width: width,
+            visible: visible
+        }
+    }
+
+    #[test]
+    fn data_round_trip() {
+        let layouts = [
+            TreeViewLayout {
+                columns: vec![column("name", 120, true), column("size", -1, false), column("date", 80, true)],
+                sort_column: Some("date".to_owned()),
+                sort_order: SortType::Descending
+            },
+            TreeViewLayout {
+                columns: vec![column("name", -1, true)],
+                sort_column: None,
+                sort_order: SortType::Ascending
+            },
+            TreeViewLayout {
+                columns: Vec::new(),
+                sort_column: None,
+                sort_order: SortType::Ascending
+            }
+        ];
+
+        for layout in layouts.iter() {
+            let data = layout.to_data("files").unwrap();
+            assert_eq!(TreeViewLayout::from_data(&data, "files").as_ref(), Some(layout));
+            assert_eq!(TreeViewLayout::from_data(&data, "other"), None);
+        }
+    }
+
+    #[test]
+    fn invalid_data() {
+        let layout = TreeViewLayout {
+            columns: vec![column("na\0me", 120, true)],
+            sort_column: None,
+            sort_order: SortType::Ascending
+        };
+
+        assert!(layout.to_data("files").is_err());
+        assert_eq!(TreeViewLayout::from_data("[files\ncolumns=name\n", "files"), None);
+    }
+}
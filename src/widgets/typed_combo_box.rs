@@ -0,0 +1,85 @@
+// Copyright 2013-2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! A combo box choosing between Rust values
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use signal::ComboBoxSignals;
+use {ComboBoxText, ComboBoxTrait};
+
+/// TypedComboBox — A combo box choosing between Rust values
+///
+/// Each row displays a label and is mapped to a value, e.g. an enum variant, which is
+/// returned instead of the row index.
+pub struct TypedComboBox<T> {
+    combo_box: ComboBoxText,
+    values: Rc<RefCell<Vec<T>>>
+}
+
+impl<T: Clone + PartialEq + 'static> TypedComboBox<T> {
+    pub fn new() -> Option<TypedComboBox<T>> {
+        ComboBoxText::new().map(|combo_box| TypedComboBox {
+            combo_box: combo_box,
+            values: Rc::new(RefCell::new(Vec::new()))
+        })
+    }
+
+    /// The underlying widget, to be packed in a container. Rows must only be added or removed
+    /// through the TypedComboBox.
+    pub fn get_combo_box(&self) -> &ComboBoxText {
+        &self.combo_box
+    }
+
+    pub fn append(&self, value: T, label: &str) {
+        self.values.borrow_mut().push(value);
+        self.combo_box.append_text(label);
+    }
+
+    pub fn clear(&self) {
+        self.values.borrow_mut().clear();
+        self.combo_box.remove_all();
+    }
+
+    pub fn get_active(&self) -> Option<T> {
+        get_value(&self.values, self.combo_box.get_active())
+    }
+
+    /// Selects the first row mapped to `value`, returns false if there is none.
+    pub fn set_active(&self, value: &T) -> bool {
+        let position = self.values.borrow().iter().position(|v| v == value);
+
+        match position {
+            Some(index) => {
+                self.combo_box.set_active(index as i32);
+                true
+            }
+            None => false
+        }
+    }
+
+    /// The closure receives the value of the selected row, or None if no row is selected.
+    pub fn connect_changed<F: Fn(Option<T>) + 'static>(&self, f: F) -> u64 {
+        let values = self.values.clone();
+
+        self.combo_box.connect_changed(move |combo_box| f(get_value(&values, combo_box.get_active())))
+    }
+}
+
+impl<T> Clone for TypedComboBox<T> {
+    fn clone(&self) -> TypedComboBox<T> {
+        TypedComboBox {
+            combo_box: self.combo_box.clone(),
+            values: self.values.clone()
+        }
+    }
+}
+
+fn get_value<T: Clone>(values: &RefCell<Vec<T>>, index: i32) -> Option<T> {
+    if index < 0 {
+        None
+    } else {
+        values.borrow().get(index as usize).cloned()
+    }
+}
@@ -11,3 +11,10 @@ struct_Widget!(Widget);
 
 impl_drop!(Widget);
 impl_TraitWidget!(Widget);
+
+impl Widget {
+    /// Returns `widget` as a plain Widget.
+    pub fn upcast<T: ::WidgetTrait>(widget: &T) -> Widget {
+        ::FFIWidget::wrap_widget(widget.unwrap_widget())
+    }
+}
@@ -0,0 +1,170 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Remembers the geometry of a window across runs
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use gdk::{self, WindowState};
+use gdk_ffi::enums::window_state::{FULLSCREEN, MAXIMIZED};
+use ffi;
+use signal::{Inhibit, WidgetSignals};
+use {FFIWidget, KeyFile, NoteBook, Paned, Widget, Window, WindowTrait};
+
+struct Geometry {
+    size: Cell<Option<(i32, i32)>>,
+    position: Cell<Option<(i32, i32)>>,
+    maximized: Cell<bool>,
+    fullscreen: Cell<bool>
+}
+
+/// WindowStateTracker — Saves and restores the size, position and state of a window
+///
+/// The size and position are those of the window when it was last neither maximized nor
+/// fullscreen, so that unmaximizing a restored window gives back its previous geometry.
+/// The positions of the registered Paneds and the current pages of the registered NoteBooks
+/// are saved along, the ids they are registered with are used as key file keys.
+pub struct WindowStateTracker {
+    window: Window,
+    geometry: Rc<Geometry>,
+    paneds: RefCell<Vec<(String, Paned)>>,
+    note_books: RefCell<Vec<(String, NoteBook)>>
+}
+
+impl WindowStateTracker {
+    pub fn new<T: WindowTrait>(window: &T) -> WindowStateTracker {
+        let tracker = WindowStateTracker {
+            window: FFIWidget::wrap_widget(window.unwrap_widget()),
+            geometry: Rc::new(Geometry {
+                size: Cell::new(None),
+                position: Cell::new(None),
+                maximized: Cell::new(false),
+                fullscreen: Cell::new(false)
+            }),
+            paneds: RefCell::new(Vec::new()),
+            note_books: RefCell::new(Vec::new())
+        };
+
+        let geometry = tracker.geometry.clone();
+        tracker.window.connect_configure_event(move |widget, _| {
+            // The window-state-event of a maximization may only come after its configure-event
+            if !get_window_state(&widget).intersects(MAXIMIZED | FULLSCREEN) {
+                let window: Window = FFIWidget::wrap_widget(widget.unwrap_widget());
+                geometry.size.set(Some(window.get_size()));
+                geometry.position.set(Some(window.get_position()));
+            }
+            Inhibit(false)
+        });
+
+        let geometry = tracker.geometry.clone();
+        tracker.window.connect_window_state_event(move |_, event| {
+            let state = event.get_new_window_state();
+            geometry.maximized.set(state.contains(MAXIMIZED));
+            geometry.fullscreen.set(state.contains(FULLSCREEN));
+            Inhibit(false)
+        });
+
+        tracker
+    }
+
+    pub fn track_paned(&self, id: &str, paned: &Paned) {
+        self.paneds.borrow_mut().push((id.to_owned(), paned.clone()));
+    }
+
+    /// The pages must have been added to `note_book` before the state is restored.
+    pub fn track_note_book(&self, id: &str, note_book: &NoteBook) {
+        self.note_books.borrow_mut().push((id.to_owned(), note_book.clone()));
+    }
+
+    pub fn save_to_key_file(&self, key_file: &KeyFile, group: &str) {
+        key_file.remove_group(group);
+
+        let size = self.geometry.size.get().unwrap_or_else(|| self.window.get_size());
+        key_file.set_integer(group, "width", size.0);
+        key_file.set_integer(group, "height", size.1);
+        if let Some((x, y)) = self.geometry.position.get() {
+            key_file.set_integer(group, "x", x);
+            key_file.set_integer(group, "y", y);
+        }
+        key_file.set_boolean(group, "maximized", self.geometry.maximized.get());
+        key_file.set_boolean(group, "fullscreen", self.geometry.fullscreen.get());
+
+        for &(ref id, ref paned) in self.paneds.borrow().iter() {
+            key_file.set_integer(group, &format!("{}.position", id), paned.get_position());
+        }
+        for &(ref id, ref note_book) in self.note_books.borrow().iter() {
+            key_file.set_integer(group, &format!("{}.page", id), note_book.get_current_page());
+        }
+    }
+
+    /// Applies the saved state, to be called before the window is shown. Returns false if
+    /// `group` isn't in `key_file`.
+    pub fn restore_from_key_file(&self, key_file: &KeyFile, group: &str) -> bool {
+        if !key_file.has_group(group) {
+            return false;
+        }
+
+        if let (Some(width), Some(height)) = (key_file.get_integer(group, "width"),
+                                              key_file.get_integer(group, "height")) {
+            if width > 0 && height > 0 {
+                self.window.set_default_size(width, height);
+                self.geometry.size.set(Some((width, height)));
+            }
+        }
+        if let (Some(x), Some(y)) = (key_file.get_integer(group, "x"), key_file.get_integer(group, "y")) {
+            self.window.move_(x, y);
+            self.geometry.position.set(Some((x, y)));
+        }
+        if key_file.get_boolean(group, "maximized").unwrap_or(false) {
+            self.window.maximize();
+        }
+        if key_file.get_boolean(group, "fullscreen").unwrap_or(false) {
+            self.window.fullscreen();
+        }
+
+        for &(ref id, ref paned) in self.paneds.borrow().iter() {
+            if let Some(position) = key_file.get_integer(group, &format!("{}.position", id)) {
+                paned.set_position(position);
+            }
+        }
+        for &(ref id, ref note_book) in self.note_books.borrow().iter() {
+            if let Some(page) = key_file.get_integer(group, &format!("{}.page", id)) {
+                if page >= 0 && page < note_book.get_n_page() {
+                    note_book.set_current_page(page);
+                }
+            }
+        }
+        true
+    }
+
+    /// Saves the state to the key file `file`, keeping its other groups.
+    pub fn save_to_file(&self, file: &str, group: &str) -> ::std::io::Result<()> {
+        let key_file = KeyFile::new();
+
+        // a missing or invalid file is replaced
+        let _ = key_file.load_from_file(file);
+        self.save_to_key_file(&key_file, group);
+        key_file.save_to_file(file)
+    }
+
+    /// Returns false if `file` can't be loaded or hasn't got `group`.
+    pub fn restore_from_file(&self, file: &str, group: &str) -> bool {
+        let key_file = KeyFile::new();
+
+        match key_file.load_from_file(file) {
+            Ok(_) => self.restore_from_key_file(&key_file, group),
+            Err(_) => false
+        }
+    }
+}
+
+fn get_window_state(widget: &Widget) -> WindowState {
+    let window = unsafe { ffi::gtk_widget_get_window(widget.unwrap_widget()) };
+
+    if window.is_null() {
+        WindowState::empty()
+    } else {
+        gdk::Window::wrap_pointer(window).get_state()
+    }
+}
@@ -0,0 +1,41 @@
+// Copyright 2015, The Rust-GNOME Project Developers.
+// See the COPYRIGHT file at the top-level directory of this distribution.
+// Licensed under the MIT license, see the LICENSE file or <http://opensource.org/licenses/MIT>
+
+//! Needs a display, run with `cargo test -- --ignored` under e.g. Xvfb.
+
+extern crate gtk;
+
+use gtk::testing;
+use gtk::{ContainerTrait, Entry, EntryTrait, Label, LabelTrait, Orientation, WidgetTrait, Window, WindowType};
+
+#[test]
+#[ignore]
+fn find_label_and_sibling() {
+    gtk::init();
+
+    let window = Window::new(WindowType::TopLevel).unwrap();
+    let form = gtk::Box::new(Orientation::Horizontal, 0).unwrap();
+    let label = Label::new("Name:").unwrap();
+    let entry = Entry::new().unwrap();
+
+    form.add(&label);
+    form.add(&entry);
+    window.add(&form);
+    window.show_all();
+    testing::run_until_idle();
+
+    let found = testing::find_label(&window, "Name*").unwrap();
+    assert_eq!(testing::text_get(&found), Some("Name:".to_owned()));
+    assert!(testing::find_label(&window, "Address*").is_none());
+
+    let sibling: Entry = testing::find_sibling(&found).unwrap();
+    testing::text_set(&sibling, "Rust");
+    assert_eq!(entry.get_text(), Some("Rust".to_owned()));
+
+    let field: Entry = testing::find_widget(&window, "Name*").unwrap();
+    assert_eq!(field.get_text(), Some("Rust".to_owned()));
+    assert_eq!(label.get_text(), Some("Name:".to_owned()));
+
+    window.destroy();
+}